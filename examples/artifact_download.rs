@@ -10,9 +10,9 @@
 //! - `JENKINS_JOB` (default: `core`)
 //! - `JENKINS_BUILD` (default: `lastSuccessfulBuild`)
 //! - `JENKINS_ARTIFACT` (required, e.g. `target/app.tar.gz` or `a/b c.txt`)
-//! - `OUTPUT_PATH` (optional, write the downloaded bytes to this path)
+//! - `OUTPUT_PATH` (optional, stream the artifact into this file; resumes a partial file)
 
-use jenkins_sdk::Client;
+use jenkins_sdk::{Client, DownloadConfig};
 use std::{path::PathBuf, time::Duration};
 
 #[tokio::main]
@@ -34,16 +34,32 @@ async fn main() -> anyhow::Result<()> {
 
     let client = builder.build()?;
 
-    let bytes = client
-        .jobs()
-        .download_artifact(job.as_str(), build.as_str(), artifact)
-        .await?;
-
     if let Some(output) = env_opt("OUTPUT_PATH") {
         let path = PathBuf::from(output);
-        std::fs::write(&path, &bytes)?;
-        println!("wrote {} bytes to {}", bytes.len(), path.display());
+        let partial = std::fs::metadata(&path).map(|m| m.len()).unwrap_or(0);
+        let config = DownloadConfig::new()
+            .resume_from(partial)
+            .on_progress(|p| match p.total {
+                Some(total) => eprint!("\r{}/{} bytes", p.downloaded, total),
+                None => eprint!("\r{} bytes", p.downloaded),
+            });
+
+        let summary = client
+            .jobs()
+            .download_artifact_to_path(job.as_str(), build.as_str(), artifact, &path, config)
+            .await?;
+        eprintln!();
+        println!(
+            "wrote {} bytes to {} (md5: {})",
+            summary.total_bytes,
+            path.display(),
+            summary.md5.as_deref().unwrap_or("n/a")
+        );
     } else {
+        let bytes = client
+            .jobs()
+            .download_artifact(job.as_str(), build.as_str(), artifact)
+            .await?;
         println!("downloaded {} bytes", bytes.len());
     }
 
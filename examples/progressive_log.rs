@@ -10,8 +10,9 @@
 //! - `JENKINS_JOB` (default: `core`)
 //! - `JENKINS_BUILD` (default: `lastBuild`)
 
-use jenkins_sdk::Client;
-use std::time::Duration;
+use futures_util::StreamExt;
+use jenkins_sdk::{Client, ConsoleFollowConfig};
+use std::{pin::pin, time::Duration};
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
@@ -27,25 +28,13 @@ async fn main() -> anyhow::Result<()> {
 
     let client = builder.build()?;
 
-    let mut start = 0u64;
-    for _ in 0..10 {
-        let chunk = client
-            .jobs()
-            .progressive_console_text(job.as_str(), build.as_str(), start)
-            .await?;
-
-        if !chunk.text.is_empty() {
-            print!("{}", chunk.text);
-        }
-
-        if !chunk.more_data {
-            break;
-        }
-
-        start = match chunk.next_start {
-            Some(next) => next,
-            None => break,
-        };
+    let mut lines = pin!(client.jobs().follow_console_lines(
+        job.as_str(),
+        build.as_str(),
+        ConsoleFollowConfig::default(),
+    ));
+    while let Some(line) = lines.next().await {
+        println!("{}", line?);
     }
 
     Ok(())
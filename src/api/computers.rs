@@ -1,6 +1,38 @@
+use super::system::{RUN_SCRIPTS, script_request};
 use crate::transport::request::{Request, RequestBody};
-use crate::{ComputerInfo, ComputerList, ComputerName, Error, ExecutorsInfo};
+use crate::util::xml;
+use crate::{
+    ComputerInfo, ComputerList, ComputerName, Error, ExecutorsInfo, JnlpLaunchInfo, NodeSpec,
+    SecretString,
+};
 use http::HeaderValue;
+use http::{Method, StatusCode};
+
+/// JNLP descriptor of an inbound agent; older controllers only serve `slave-agent.jnlp`.
+const JNLP_FILES: [&str; 2] = ["jenkins-agent.jnlp", "slave-agent.jnlp"];
+
+fn jnlp_request(name: &ComputerName, file: &str) -> Request {
+    Request::get(["computer", name.as_str(), file])
+}
+
+fn parse_jnlp(name: &ComputerName, file: &str, jnlp: &str) -> Result<JnlpLaunchInfo, Error> {
+    JnlpLaunchInfo::from_arguments(&xml::element_texts(jnlp, "argument")).ok_or_else(|| {
+        Error::Decode {
+            status: StatusCode::OK,
+            method: Method::GET,
+            path: format!("/computer/{}/{file}", name.as_str()).into_boxed_str(),
+            request_id: None,
+            body_snippet: None,
+            source: "JNLP descriptor lacks the secret and node name arguments \
+                     (not an inbound agent?)"
+                .into(),
+        }
+    })
+}
+
+fn create_request(spec: &NodeSpec) -> Request {
+    Request::post(["computer", "doCreateItem"]).form_pairs(spec.to_form())
+}
 
 /// Jenkins computers/nodes (core) APIs.
 #[derive(Clone)]
@@ -43,6 +75,11 @@ impl ComputersService {
         self.client.send_unit(req).await
     }
 
+    /// `POST /computer/doCreateItem` with the permanent agent form built from `spec`.
+    pub async fn create(&self, spec: &NodeSpec) -> Result<(), Error> {
+        self.client.send_unit(create_request(spec)).await
+    }
+
     /// `POST /computer/doCreateItem?name=<to>&mode=copy&from=<from>`
     pub async fn copy(
         &self,
@@ -144,6 +181,29 @@ impl ComputersService {
             .await
     }
 
+    /// Launch parameters of inbound agent `name` from `GET /computer/<name>/jenkins-agent.jnlp`
+    /// (falling back to `slave-agent.jnlp`); needs the `Agent/Connect` permission.
+    pub async fn launch_info(
+        &self,
+        name: impl Into<ComputerName>,
+    ) -> Result<JnlpLaunchInfo, Error> {
+        let name = name.into();
+        let mut file = JNLP_FILES[0];
+        let jnlp = match self.client.send_text(jnlp_request(&name, file)).await {
+            Err(Error::NotFound(_)) => {
+                file = JNLP_FILES[1];
+                self.client.send_text(jnlp_request(&name, file)).await?
+            }
+            other => other?,
+        };
+        parse_jnlp(&name, file, &jnlp)
+    }
+
+    /// Secret an inbound agent `name` connects with (see [`Self::launch_info`]).
+    pub async fn agent_secret(&self, name: impl Into<ComputerName>) -> Result<SecretString, Error> {
+        Ok(self.launch_info(name).await?.secret)
+    }
+
     /// `POST /computer/<name>/launchSlaveAgent`
     pub async fn launch_slave_agent(&self, name: impl Into<ComputerName>) -> Result<(), Error> {
         let name = name.into();
@@ -155,6 +215,24 @@ impl ComputersService {
             ]))
             .await
     }
+
+    /// `POST /computer/<name>/scriptText`: run Groovy on that agent's JVM.
+    ///
+    /// See [`crate::SystemService::run_script`].
+    pub async fn run_script(
+        &self,
+        name: impl Into<ComputerName>,
+        script: impl Into<String>,
+    ) -> Result<String, Error> {
+        let name = name.into();
+        self.client
+            .send_text(script_request(
+                ["computer", name.as_str(), "scriptText"],
+                script.into(),
+            ))
+            .await
+            .map_err(|err| err.require_permission(RUN_SCRIPTS))
+    }
 }
 
 /// Jenkins computers/nodes (core) APIs (blocking).
@@ -198,6 +276,11 @@ impl BlockingComputersService {
         self.client.send_unit(req)
     }
 
+    /// `POST /computer/doCreateItem` with the permanent agent form built from `spec`.
+    pub fn create(&self, spec: &NodeSpec) -> Result<(), Error> {
+        self.client.send_unit(create_request(spec))
+    }
+
     /// `POST /computer/doCreateItem?name=<to>&mode=copy&from=<from>`
     pub fn copy(
         &self,
@@ -292,6 +375,26 @@ impl BlockingComputersService {
             .send_unit(Request::post(["computer", name.as_str(), "disconnect"]))
     }
 
+    /// Launch parameters of inbound agent `name` from `GET /computer/<name>/jenkins-agent.jnlp`
+    /// (falling back to `slave-agent.jnlp`); needs the `Agent/Connect` permission.
+    pub fn launch_info(&self, name: impl Into<ComputerName>) -> Result<JnlpLaunchInfo, Error> {
+        let name = name.into();
+        let mut file = JNLP_FILES[0];
+        let jnlp = match self.client.send_text(jnlp_request(&name, file)) {
+            Err(Error::NotFound(_)) => {
+                file = JNLP_FILES[1];
+                self.client.send_text(jnlp_request(&name, file))?
+            }
+            other => other?,
+        };
+        parse_jnlp(&name, file, &jnlp)
+    }
+
+    /// Secret an inbound agent `name` connects with (see [`Self::launch_info`]).
+    pub fn agent_secret(&self, name: impl Into<ComputerName>) -> Result<SecretString, Error> {
+        Ok(self.launch_info(name)?.secret)
+    }
+
     /// `POST /computer/<name>/launchSlaveAgent`
     pub fn launch_slave_agent(&self, name: impl Into<ComputerName>) -> Result<(), Error> {
         let name = name.into();
@@ -301,4 +404,21 @@ impl BlockingComputersService {
             "launchSlaveAgent",
         ]))
     }
+
+    /// `POST /computer/<name>/scriptText`: run Groovy on that agent's JVM.
+    ///
+    /// See [`crate::BlockingSystemService::run_script`].
+    pub fn run_script(
+        &self,
+        name: impl Into<ComputerName>,
+        script: impl Into<String>,
+    ) -> Result<String, Error> {
+        let name = name.into();
+        self.client
+            .send_text(script_request(
+                ["computer", name.as_str(), "scriptText"],
+                script.into(),
+            ))
+            .map_err(|err| err.require_permission(RUN_SCRIPTS))
+    }
 }
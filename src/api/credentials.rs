@@ -0,0 +1,142 @@
+use crate::transport::request::{Request, RequestBody};
+use crate::{CredentialInfo, CredentialSpec, CredentialStore, Error};
+use http::HeaderValue;
+use serde::Deserialize;
+
+const CREDENTIALS_TREE: &str = "credentials[id,displayName,description,typeName,fullName]";
+
+#[derive(Deserialize)]
+struct CredentialList {
+    #[serde(default)]
+    credentials: Vec<CredentialInfo>,
+}
+
+fn store_segments<const N: usize>(store: &CredentialStore, tail: [&str; N]) -> Vec<String> {
+    let mut segments = store.url_segments();
+    segments.extend(tail.map(str::to_owned));
+    segments
+}
+
+fn credential_segments(store: &CredentialStore, id: &str, action: &str) -> Vec<String> {
+    store_segments(store, ["credential", id, action])
+}
+
+fn list_request(store: &CredentialStore) -> Request {
+    Request::get(store_segments(store, ["api", "json"])).query_pair("tree", CREDENTIALS_TREE)
+}
+
+fn xml_body(xml: Vec<u8>) -> RequestBody {
+    RequestBody::bytes_with_content_type(xml, HeaderValue::from_static("application/xml"))
+}
+
+fn create_request(store: &CredentialStore, spec: &CredentialSpec) -> Request {
+    Request::post(store_segments(store, ["createCredentials"])).body(xml_body(spec.to_xml()))
+}
+
+fn update_request(store: &CredentialStore, spec: &CredentialSpec) -> Request {
+    Request::post(credential_segments(store, spec.id(), "config.xml")).body(xml_body(spec.to_xml()))
+}
+
+/// Credentials plugin stores (system store and folder stores).
+#[derive(Clone)]
+#[cfg(feature = "async")]
+pub struct CredentialsService {
+    client: crate::Client,
+}
+
+#[cfg(feature = "async")]
+impl CredentialsService {
+    pub(crate) fn new(client: crate::Client) -> Self {
+        Self { client }
+    }
+}
+
+#[cfg(feature = "async")]
+impl CredentialsService {
+    /// `GET .../domain/<domain>/api/json`: ids, descriptions and types only.
+    pub async fn list(&self, store: &CredentialStore) -> Result<Vec<CredentialInfo>, Error> {
+        let list: CredentialList = self.client.send_json(list_request(store)).await?;
+        Ok(list.credentials)
+    }
+
+    /// `GET .../credential/<id>/config.xml` (Jenkins masks or encrypts secret fields).
+    pub async fn get_config_xml(
+        &self,
+        store: &CredentialStore,
+        id: &str,
+    ) -> Result<Vec<u8>, Error> {
+        self.client
+            .send_bytes(Request::get(credential_segments(store, id, "config.xml")))
+            .await
+    }
+
+    /// `POST .../domain/<domain>/createCredentials` with the credential's XML.
+    pub async fn create(
+        &self,
+        store: &CredentialStore,
+        spec: &CredentialSpec,
+    ) -> Result<(), Error> {
+        self.client.send_unit(create_request(store, spec)).await
+    }
+
+    /// `POST .../credential/<id>/config.xml`: replace credential `spec.id()`.
+    pub async fn update(
+        &self,
+        store: &CredentialStore,
+        spec: &CredentialSpec,
+    ) -> Result<(), Error> {
+        self.client.send_unit(update_request(store, spec)).await
+    }
+
+    /// `POST .../credential/<id>/doDelete`
+    pub async fn delete(&self, store: &CredentialStore, id: &str) -> Result<(), Error> {
+        self.client
+            .send_unit(Request::post(credential_segments(store, id, "doDelete")))
+            .await
+    }
+}
+
+/// Credentials plugin stores (system store and folder stores).
+#[derive(Clone)]
+#[cfg(feature = "blocking")]
+pub struct BlockingCredentialsService {
+    client: crate::BlockingClient,
+}
+
+#[cfg(feature = "blocking")]
+impl BlockingCredentialsService {
+    pub(crate) fn new(client: crate::BlockingClient) -> Self {
+        Self { client }
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl BlockingCredentialsService {
+    /// `GET .../domain/<domain>/api/json`: ids, descriptions and types only.
+    pub fn list(&self, store: &CredentialStore) -> Result<Vec<CredentialInfo>, Error> {
+        let list: CredentialList = self.client.send_json(list_request(store))?;
+        Ok(list.credentials)
+    }
+
+    /// `GET .../credential/<id>/config.xml` (Jenkins masks or encrypts secret fields).
+    pub fn get_config_xml(&self, store: &CredentialStore, id: &str) -> Result<Vec<u8>, Error> {
+        self.client
+            .send_bytes(Request::get(credential_segments(store, id, "config.xml")))
+    }
+
+    /// `POST .../domain/<domain>/createCredentials` with the credential's XML.
+    pub fn create(&self, store: &CredentialStore, spec: &CredentialSpec) -> Result<(), Error> {
+        self.client.send_unit(create_request(store, spec))
+    }
+
+    /// `POST .../credential/<id>/config.xml`: replace credential `spec.id()`.
+    pub fn update(&self, store: &CredentialStore, spec: &CredentialSpec) -> Result<(), Error> {
+        self.client.send_unit(update_request(store, spec))
+    }
+
+    /// `POST .../credential/<id>/doDelete`
+    pub fn delete(&self, store: &CredentialStore, id: &str) -> Result<(), Error> {
+        self.client
+            .send_unit(Request::post(credential_segments(store, id, "doDelete")))
+    }
+}
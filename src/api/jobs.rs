@@ -1,11 +1,45 @@
-use crate::transport::request::{Request, RequestBody, Response};
+use crate::transport::request::{Multipart, Request, RequestBody, Response};
+use crate::util::diagnostics;
+use crate::util::download::{self, DownloadTracker, io_error};
+use crate::util::glob::glob_match_any;
+use crate::util::xml;
 use crate::{
-    ArtifactPath, BuildInfo, BuildNumber, Error, JobInfo, JobList, JobName, JobPath,
-    ProgressiveText, QueueItemId, TriggeredBuild,
+    Artifact, ArtifactPath, BuildInfo, BuildNumber, BuildOutcome, BuildQuery, ConsoleFollowConfig,
+    DownloadConfig, DownloadSummary, Error, FileParameter, FileSource, Fingerprint, JobInfo,
+    JobList, JobName, JobPath, JobSummary, JobWalkConfig, MirroredArtifact, ParameterDefinition,
+    ProgressiveText, QueueItem, QueueItemId, TestReport, TestReportDiff, TriggeredBuild,
+    WaitConfig,
 };
-use http::HeaderValue;
+#[cfg(feature = "async")]
+use futures_core::Stream;
+#[cfg(feature = "async")]
+use futures_util::StreamExt;
+use http::{HeaderValue, Method};
+use serde::Deserialize;
+use std::collections::VecDeque;
+use std::io::SeekFrom;
+#[cfg(feature = "blocking")]
+use std::io::{Seek, Write};
+use std::path::Path;
+use std::time::{Duration, Instant};
+#[cfg(feature = "async")]
+use tokio::io::{AsyncSeekExt, AsyncWrite, AsyncWriteExt};
+
+/// `tree` used while polling a running build; the full payload is fetched once it finishes.
+const WAIT_BUILD_TREE: &str = "number,building,result";
 
-fn job_segments(job: &JobPath) -> Vec<String> {
+const FINGERPRINT_TREE: &str = "fingerprint[fileName,hash]";
+const ARTIFACTS_TREE: &str = "artifacts[fileName,relativePath,displayPath]";
+/// Jenkins serves every artifact of a build as one zip under this path.
+const ARTIFACTS_ZIP: &str = "*zip*/archive.zip";
+
+#[derive(Deserialize)]
+struct FingerprintList {
+    #[serde(default)]
+    fingerprint: Vec<Fingerprint>,
+}
+
+pub(crate) fn job_segments(job: &JobPath) -> Vec<String> {
     job.url_segments().map(ToOwned::to_owned).collect()
 }
 
@@ -21,6 +55,37 @@ fn build_selector_request(job: &JobPath, selector: &str, tree: Option<&str>) ->
     req
 }
 
+fn test_report_request(job: &JobPath, selector: &str, tree: Option<&str>) -> Request {
+    let mut segments = job_segments(job);
+    segments.push(selector.to_owned());
+    segments.extend(["testReport", "api", "json"].map(str::to_owned));
+
+    let mut req = Request::get(segments);
+    if let Some(tree) = tree {
+        req = req.query_pair("tree", tree);
+    }
+    req
+}
+
+fn artifact_request(
+    job: &JobPath,
+    build: &BuildNumber,
+    path: &ArtifactPath,
+    resume_from: Option<u64>,
+) -> Request {
+    let mut segments = job_segments(job);
+    segments.push(build.as_str().to_owned());
+    segments.push("artifact".to_owned());
+    segments.extend(path.url_segments().map(ToOwned::to_owned));
+
+    let mut req = Request::get(segments);
+    if let Some(offset) = resume_from.filter(|offset| *offset > 0) {
+        req.headers
+            .insert(http::header::RANGE, download::range_header(offset));
+    }
+    req
+}
+
 fn parse_progressive_text(resp: Response) -> ProgressiveText {
     let next_start = resp
         .headers
@@ -47,6 +112,37 @@ fn parse_queue_item_id_from_location(location: &str) -> Option<QueueItemId> {
     Some(QueueItemId::new(*id))
 }
 
+const FOLDER_CLASS: &str = "com.cloudbees.hudson.plugins.folder.Folder";
+
+/// Split `path` into the folder holding it and the item's own name.
+fn split_item_path(path: &JobPath) -> (JobPath, String) {
+    let trimmed = path.as_str().trim_matches('/');
+    match trimmed.rsplit_once('/') {
+        Some((parent, name)) => (JobPath::new(parent), name.to_owned()),
+        None => (JobPath::new(""), trimmed.to_owned()),
+    }
+}
+
+/// `POST /job/<parent>/createItem?name=<name>` for the item at `path`.
+fn create_item_request(path: &JobPath) -> (Request, JobPath) {
+    let (parent, name) = split_item_path(path);
+    let mut segments = job_segments(&parent);
+    segments.push("createItem".to_owned());
+    (Request::post(segments).query_pair("name", name), parent)
+}
+
+fn xml_body(xml: Vec<u8>) -> RequestBody {
+    RequestBody::bytes_with_content_type(xml, HeaderValue::from_static("application/xml"))
+}
+
+fn folder_xml(description: Option<&str>) -> Vec<u8> {
+    let description = description.map(xml::escape).unwrap_or_default();
+    format!(
+        "<?xml version='1.1' encoding='UTF-8'?>\n<{FOLDER_CLASS}>\n  <description>{description}</description>\n</{FOLDER_CLASS}>\n"
+    )
+    .into_bytes()
+}
+
 fn triggered_build_from_response(resp: &Response) -> TriggeredBuild {
     let location = resp
         .headers
@@ -62,6 +158,373 @@ fn triggered_build_from_response(resp: &Response) -> TriggeredBuild {
     }
 }
 
+/// Ordinary parameters as text parts; files are added by the caller once loaded.
+fn multipart_parameters<I, K, V>(params: I) -> Multipart
+where
+    I: IntoIterator<Item = (K, V)>,
+    K: Into<String>,
+    V: Into<String>,
+{
+    params
+        .into_iter()
+        .fold(Multipart::new(), |form, (name, value)| {
+            form.text(name, value)
+        })
+}
+
+fn file_parameter_context(file: &FileParameter) -> String {
+    format!("reading file parameter `{}`", file.name)
+}
+
+const PARAMETERS_TREE: &str = "property[parameterDefinitions[name,type,description,choices,defaultParameterValue[name,value]]]";
+
+/// Reject parameters the job does not declare and values Jenkins would not accept, instead of
+/// letting Jenkins silently fall back to defaults.
+fn validate_parameters(
+    job: &JobPath,
+    definitions: &[ParameterDefinition],
+    params: &[(String, String)],
+) -> Result<(), Error> {
+    let problems: Vec<String> = params
+        .iter()
+        .filter_map(
+            |(name, value)| match definitions.iter().find(|def| &def.name == name) {
+                Some(definition) => definition.check_value(value),
+                None => Some(format!("unknown parameter `{name}`")),
+            },
+        )
+        .collect();
+    if problems.is_empty() {
+        return Ok(());
+    }
+
+    let known: Vec<&str> = definitions.iter().map(|def| def.name.as_str()).collect();
+    Err(Error::InvalidConfig {
+        message: format!(
+            "invalid parameters for job `{}`: {} (declared: {})",
+            job.as_str(),
+            problems.join("; "),
+            if known.is_empty() {
+                "none".to_owned()
+            } else {
+                known.join(", ")
+            }
+        )
+        .into_boxed_str(),
+        source: None,
+    })
+}
+
+const REBUILD_TREE: &str = "actions[parameters[name,value]]";
+
+/// Form values for re-submitting `info`'s parameters; `None` when it had none.
+fn rebuild_parameters(
+    build: &BuildNumber,
+    info: &BuildInfo,
+) -> Result<Option<Vec<(String, String)>>, Error> {
+    let parameters = info.parameters();
+    if parameters.is_empty() {
+        return Ok(None);
+    }
+    parameters
+        .into_iter()
+        .map(|parameter| match parameter.form_value() {
+            Some(value) => Ok((parameter.name, value)),
+            None => Err(Error::InvalidConfig {
+                message: format!(
+                    "cannot rebuild #{}: parameter `{}` has no value that can be resubmitted \
+                     (password, file or other non-scalar parameter)",
+                    build.as_str(),
+                    parameter.name
+                )
+                .into_boxed_str(),
+                source: None,
+            }),
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .map(Some)
+}
+
+fn triggered_queue_item(segments: &[String], resp: &Response) -> Result<QueueItemId, Error> {
+    triggered_build_from_response(resp)
+        .queue_item_id
+        .ok_or_else(|| Error::Decode {
+            status: resp.status,
+            method: Method::POST,
+            path: format!("/{}", segments.join("/")).into_boxed_str(),
+            request_id: diagnostics::request_id(&resp.headers),
+            body_snippet: None,
+            source: "missing queue item in `Location` header".into(),
+        })
+}
+
+fn queue_item_build(item: &QueueItem) -> Option<BuildNumber> {
+    item.executable
+        .as_ref()?
+        .number
+        .as_deref()
+        .map(BuildNumber::from)
+}
+
+/// Overall deadline of a wait loop.
+pub(crate) struct WaitClock {
+    started: Instant,
+    poll_interval: Duration,
+    timeout: Option<Duration>,
+}
+
+impl WaitClock {
+    pub(crate) fn start(config: &WaitConfig) -> Self {
+        Self {
+            started: Instant::now(),
+            poll_interval: config.poll_interval,
+            timeout: config.timeout,
+        }
+    }
+
+    /// Delay before the next poll, or `Error::WaitTimeout` once the deadline has passed.
+    pub(crate) fn next_delay(&self, waiting_for: &str) -> Result<Duration, Error> {
+        let elapsed = self.started.elapsed();
+        match self.timeout {
+            Some(timeout) if elapsed >= timeout => Err(Error::WaitTimeout {
+                waiting_for: waiting_for.into(),
+                elapsed,
+            }),
+            Some(timeout) => Ok(self.poll_interval.min(timeout - elapsed)),
+            None => Ok(self.poll_interval),
+        }
+    }
+}
+
+/// Offset and idle back-off bookkeeping for console followers.
+struct ConsoleCursor {
+    offset: u64,
+    next_delay: Duration,
+    poll_interval: Duration,
+    max_poll_interval: Duration,
+    done: bool,
+}
+
+impl ConsoleCursor {
+    fn new(config: &ConsoleFollowConfig) -> Self {
+        Self {
+            offset: config.start,
+            next_delay: Duration::ZERO,
+            poll_interval: config.poll_interval,
+            max_poll_interval: config.max_poll_interval,
+            done: false,
+        }
+    }
+
+    /// Record a fetched chunk and return its text when non-empty.
+    fn advance(&mut self, chunk: ProgressiveText) -> Option<String> {
+        match chunk.next_start {
+            Some(next) if chunk.more_data => self.offset = next,
+            _ => self.done = true,
+        }
+        self.next_delay = if chunk.text.is_empty() {
+            self.next_delay
+                .saturating_mul(2)
+                .max(self.poll_interval)
+                .min(self.max_poll_interval)
+        } else {
+            self.poll_interval
+        };
+        (!chunk.text.is_empty()).then_some(chunk.text)
+    }
+}
+
+const BUILDS_TREE: &str = "number,url,result,building,displayName,fullDisplayName,timestamp,duration,actions[parameters[name,value]]";
+
+#[derive(Deserialize)]
+struct BuildPage {
+    #[serde(default, rename = "allBuilds")]
+    all_builds: Vec<BuildInfo>,
+}
+
+/// Paging and filtering state for build history walkers.
+struct BuildPager {
+    query: BuildQuery,
+    next_from: usize,
+    buffered: VecDeque<BuildInfo>,
+    yielded: usize,
+    exhausted: bool,
+}
+
+impl BuildPager {
+    fn new(query: BuildQuery) -> Self {
+        Self {
+            query,
+            next_from: 0,
+            buffered: VecDeque::new(),
+            yielded: 0,
+            exhausted: false,
+        }
+    }
+
+    fn page_size(&self) -> usize {
+        self.query.page_size.max(1)
+    }
+
+    fn limit_reached(&self) -> bool {
+        self.query.limit.is_some_and(|limit| self.yielded >= limit)
+    }
+
+    /// Next matching build among the fetched ones.
+    fn pop(&mut self) -> Option<BuildInfo> {
+        if self.limit_reached() {
+            return None;
+        }
+        while let Some(build) = self.buffered.pop_front() {
+            if self.query.is_before_window(&build) {
+                self.finish();
+                return None;
+            }
+            if self.query.matches(&build) {
+                self.yielded += 1;
+                return Some(build);
+            }
+        }
+        None
+    }
+
+    /// Whether nothing more can be yielded.
+    fn done(&self) -> bool {
+        self.limit_reached() || (self.exhausted && self.buffered.is_empty())
+    }
+
+    /// `{from, to}` of the next page to fetch.
+    fn next_range(&mut self) -> (usize, usize) {
+        let from = self.next_from;
+        self.next_from += self.page_size();
+        (from, self.next_from)
+    }
+
+    fn accept(&mut self, page: Vec<BuildInfo>) {
+        if page.len() < self.page_size() {
+            self.exhausted = true;
+        }
+        self.buffered.extend(page);
+    }
+
+    fn finish(&mut self) {
+        self.exhausted = true;
+        self.buffered.clear();
+    }
+}
+
+/// Nested `jobs[...]` tree covering `levels` folder levels.
+fn jobs_tree(levels: usize) -> String {
+    let mut tree = "name,url,color".to_owned();
+    for _ in 1..levels {
+        tree = format!("name,url,color,jobs[{tree}]");
+    }
+    format!("jobs[{tree}]")
+}
+
+/// Traversal state for job tree walkers: folders still to fetch and jobs ready to yield.
+struct JobWalker {
+    config: JobWalkConfig,
+    /// Containers whose children were not part of a previous response, with their depth.
+    pending: VecDeque<(Option<JobPath>, usize)>,
+    ready: VecDeque<(JobPath, JobSummary)>,
+}
+
+impl JobWalker {
+    fn new(config: JobWalkConfig) -> Self {
+        let root = config.root.clone();
+        Self {
+            config,
+            pending: VecDeque::from([(root, 0)]),
+            ready: VecDeque::new(),
+        }
+    }
+
+    fn pop(&mut self) -> Option<(JobPath, JobSummary)> {
+        self.ready.pop_front()
+    }
+
+    /// Next container to list, with the request fetching as many levels as allowed.
+    fn next_request(&mut self) -> Option<(Option<JobPath>, usize, Request)> {
+        let (container, depth) = self.pending.pop_front()?;
+        let mut levels = self.config.levels_per_request.max(1);
+        if let Some(max_depth) = self.config.max_depth {
+            levels = levels.min(max_depth.saturating_sub(depth).max(1));
+        }
+        let mut segments = container.as_ref().map(job_segments).unwrap_or_default();
+        segments.extend(["api", "json"].map(str::to_owned));
+        let req = Request::get(segments).query_pair("tree", jobs_tree(levels));
+        Some((container, depth, req))
+    }
+
+    /// Queue the listed `jobs` of `parent` (at `depth`), descending into nested listings.
+    fn accept(&mut self, parent: Option<&JobPath>, depth: usize, jobs: Vec<JobSummary>) {
+        let depth = depth + 1;
+        for mut job in jobs {
+            let path = JobPath::new(match parent {
+                Some(parent) => format!("{}/{}", parent.as_str(), job.name),
+                None => job.name.clone(),
+            });
+            if !self.config.exclude.is_empty()
+                && glob_match_any(&self.config.exclude, path.as_str())
+            {
+                continue;
+            }
+
+            let nested = job.extra.remove("jobs");
+            let descend = (job.kind().is_container() || nested.is_some())
+                && self.config.max_depth.is_none_or(|max| depth < max);
+            if glob_match_any(&self.config.include, path.as_str()) {
+                self.ready.push_back((path.clone(), job));
+            }
+            if !descend {
+                continue;
+            }
+            match nested.map(serde_json::from_value::<Vec<JobSummary>>) {
+                Some(Ok(children)) => self.accept(Some(&path), depth, children),
+                _ => self.pending.push_back((Some(path), depth)),
+            }
+        }
+    }
+
+    fn finish(&mut self) {
+        self.pending.clear();
+        self.ready.clear();
+    }
+}
+
+/// Splits console chunks into complete lines (without `\n` / `\r\n`).
+#[derive(Default)]
+struct LineBuffer {
+    partial: String,
+    ready: VecDeque<String>,
+}
+
+impl LineBuffer {
+    fn push(&mut self, text: &str) {
+        self.partial.push_str(text);
+        while let Some(pos) = self.partial.find('\n') {
+            let mut line: String = self.partial.drain(..=pos).collect();
+            line.pop();
+            if line.ends_with('\r') {
+                line.pop();
+            }
+            self.ready.push_back(line);
+        }
+    }
+
+    /// Flush the trailing line that was not terminated by a newline.
+    fn finish(&mut self) {
+        if !self.partial.is_empty() {
+            self.ready.push_back(std::mem::take(&mut self.partial));
+        }
+    }
+
+    fn pop(&mut self) -> Option<String> {
+        self.ready.pop_front()
+    }
+}
+
 /// Jenkins jobs/builds (core) APIs.
 #[derive(Clone)]
 #[cfg(feature = "async")]
@@ -85,6 +548,33 @@ impl JobsService {
             .await
     }
 
+    /// Walk the job tree below `config.root`, descending into folders, organization folders
+    /// and multibranch projects (detected through `_class`).
+    ///
+    /// Each request lists up to `config.levels_per_request` levels through a nested
+    /// `jobs[...]` tree; containers are yielded before their children.
+    pub fn walk_jobs(
+        &self,
+        config: JobWalkConfig,
+    ) -> impl Stream<Item = Result<(JobPath, JobSummary), Error>> + Send + 'static {
+        let state = (self.clone(), JobWalker::new(config));
+        futures_util::stream::unfold(state, |(service, mut walker)| async move {
+            loop {
+                if let Some(item) = walker.pop() {
+                    return Some((Ok(item), (service, walker)));
+                }
+                let (container, depth, req) = walker.next_request()?;
+                match service.client.send_json::<JobList>(req).await {
+                    Ok(list) => walker.accept(container.as_ref(), depth, list.jobs),
+                    Err(err) => {
+                        walker.finish();
+                        return Some((Err(err), (service, walker)));
+                    }
+                }
+            }
+        })
+    }
+
     /// `GET /job/<name>/api/json`
     pub async fn get(&self, job: impl Into<JobPath>, tree: Option<&str>) -> Result<JobInfo, Error> {
         let job = job.into();
@@ -206,6 +696,122 @@ impl JobsService {
         Ok(parse_progressive_text(resp))
     }
 
+    /// Follow `GET /job/<name>/<build>/logText/progressiveText` as a stream of appended chunks.
+    ///
+    /// Polls from `config.start`, backs off while the build produces no output and ends once
+    /// Jenkins reports `X-More-Data: false`. The stream ends after yielding an error.
+    pub fn follow_console(
+        &self,
+        job: impl Into<JobPath>,
+        build: impl Into<BuildNumber>,
+        config: ConsoleFollowConfig,
+    ) -> impl Stream<Item = Result<String, Error>> + Send + 'static {
+        let state = (
+            self.clone(),
+            job.into(),
+            build.into(),
+            ConsoleCursor::new(&config),
+        );
+        futures_util::stream::unfold(state, |(service, job, build, mut cursor)| async move {
+            loop {
+                if cursor.done {
+                    return None;
+                }
+                if !cursor.next_delay.is_zero() {
+                    tokio::time::sleep(cursor.next_delay).await;
+                }
+                let item = match service
+                    .progressive_console_text(job.clone(), build.clone(), cursor.offset)
+                    .await
+                {
+                    Ok(chunk) => match cursor.advance(chunk) {
+                        Some(text) => Ok(text),
+                        None => continue,
+                    },
+                    Err(err) => {
+                        cursor.done = true;
+                        Err(err)
+                    }
+                };
+                return Some((item, (service, job, build, cursor)));
+            }
+        })
+    }
+
+    /// Like [`Self::follow_console`], but yields complete console lines.
+    pub fn follow_console_lines(
+        &self,
+        job: impl Into<JobPath>,
+        build: impl Into<BuildNumber>,
+        config: ConsoleFollowConfig,
+    ) -> impl Stream<Item = Result<String, Error>> + Send + 'static {
+        let chunks = Box::pin(self.follow_console(job, build, config));
+        let state = (chunks, LineBuffer::default(), false);
+        futures_util::stream::unfold(state, |(mut chunks, mut lines, mut finished)| async move {
+            loop {
+                if let Some(line) = lines.pop() {
+                    return Some((Ok(line), (chunks, lines, finished)));
+                }
+                if finished {
+                    return None;
+                }
+                match chunks.next().await {
+                    Some(Ok(text)) => lines.push(&text),
+                    Some(Err(err)) => return Some((Err(err), (chunks, lines, finished))),
+                    None => {
+                        lines.finish();
+                        finished = true;
+                    }
+                }
+            }
+        })
+    }
+
+    /// `GET /job/<name>/api/json?tree=allBuilds[...]{from,to}`: builds `from..to` (newest
+    /// first, `to` exclusive).
+    pub async fn build_range(
+        &self,
+        job: impl Into<JobPath>,
+        from: usize,
+        to: usize,
+    ) -> Result<Vec<BuildInfo>, Error> {
+        let job = job.into();
+        let mut segments = job_segments(&job);
+        segments.extend(["api", "json"].map(str::to_owned));
+        let req = Request::get(segments)
+            .query_pair("tree", format!("allBuilds[{BUILDS_TREE}]{{{from},{to}}}"));
+        let page: BuildPage = self.client.send_json(req).await?;
+        Ok(page.all_builds)
+    }
+
+    /// Walk the build history page by page ([`Self::build_range`]), yielding the builds
+    /// matching `query`.
+    pub fn builds(
+        &self,
+        job: impl Into<JobPath>,
+        query: BuildQuery,
+    ) -> impl Stream<Item = Result<BuildInfo, Error>> + Send + 'static {
+        let state = (self.clone(), job.into(), BuildPager::new(query));
+        futures_util::stream::unfold(state, |(service, job, mut pager)| async move {
+            loop {
+                if let Some(build) = pager.pop() {
+                    return Some((Ok(build), (service, job, pager)));
+                }
+                if pager.done() {
+                    return None;
+                }
+                let (from, to) = pager.next_range();
+                match service.build_range(job.clone(), from, to).await {
+                    Ok(page) => pager.accept(page),
+                    Err(err) => {
+                        pager.finish();
+                        return Some((Err(err), (service, job, pager)));
+                    }
+                }
+            }
+        })
+    }
+
     /// `GET /job/<name>/<build>/api/json`
     pub async fn build_info(
         &self,
@@ -249,6 +855,45 @@ impl JobsService {
         self.client.send_text(Request::get(segments)).await
     }
 
+    /// `GET /job/<name>/<build>/testReport/api/json`
+    pub async fn test_report(
+        &self,
+        job: impl Into<JobPath>,
+        build: impl Into<BuildNumber>,
+        tree: Option<&str>,
+    ) -> Result<TestReport, Error> {
+        let job = job.into();
+        let build = build.into();
+        self.client
+            .send_json(test_report_request(&job, build.as_str(), tree))
+            .await
+    }
+
+    /// `GET /job/<name>/lastCompletedBuild/testReport/api/json`
+    pub async fn last_completed_test_report(
+        &self,
+        job: impl Into<JobPath>,
+        tree: Option<&str>,
+    ) -> Result<TestReport, Error> {
+        let job = job.into();
+        self.client
+            .send_json(test_report_request(&job, "lastCompletedBuild", tree))
+            .await
+    }
+
+    /// Fetch the test reports of `previous` and `current` and compare them.
+    pub async fn test_report_diff(
+        &self,
+        job: impl Into<JobPath>,
+        previous: impl Into<BuildNumber>,
+        current: impl Into<BuildNumber>,
+    ) -> Result<TestReportDiff, Error> {
+        let job = job.into();
+        let previous = self.test_report(job.clone(), previous, None).await?;
+        let current = self.test_report(job, current, None).await?;
+        Ok(TestReportDiff::between(&previous, &current))
+    }
+
     /// `GET /job/<name>/<build>/artifact/<path>`
     pub async fn download_artifact(
         &self,
@@ -256,15 +901,236 @@ impl JobsService {
         build: impl Into<BuildNumber>,
         path: impl Into<ArtifactPath>,
     ) -> Result<Vec<u8>, Error> {
+        let req = artifact_request(&job.into(), &build.into(), &path.into(), None);
+        self.client.send_bytes(req).await
+    }
+
+    /// Stream `GET /job/<name>/<build>/artifact/<path>` into `writer` without buffering it.
+    ///
+    /// With [`DownloadConfig::resume_from`], `writer` is expected to already hold that many
+    /// bytes; an MD5 can then only be checked if the server ignores the `Range` request,
+    /// and a partial response fails before anything is written.
+    pub async fn download_artifact_to_writer<W>(
+        &self,
+        job: impl Into<JobPath>,
+        build: impl Into<BuildNumber>,
+        path: impl Into<ArtifactPath>,
+        writer: &mut W,
+        config: DownloadConfig,
+    ) -> Result<DownloadSummary, Error>
+    where
+        W: AsyncWrite + Unpin + Send + ?Sized,
+    {
+        self.stream_artifact(
+            &job.into(),
+            &build.into(),
+            &path.into(),
+            writer,
+            None,
+            config,
+        )
+        .await
+    }
+
+    /// Stream `GET /job/<name>/<build>/artifact/<path>` into the file at `dest`.
+    ///
+    /// Without [`DownloadConfig::resume_from`] the file is truncated; with it, the first
+    /// `offset` bytes of the existing file are kept and the rest is requested via `Range`.
+    pub async fn download_artifact_to_path(
+        &self,
+        job: impl Into<JobPath>,
+        build: impl Into<BuildNumber>,
+        path: impl Into<ArtifactPath>,
+        dest: impl AsRef<Path>,
+        config: DownloadConfig,
+    ) -> Result<DownloadSummary, Error> {
+        let context = "writing the download destination";
+        let offset = config.resume_from.unwrap_or(0);
+        let mut file = tokio::fs::OpenOptions::new()
+            .create(true)
+            .read(true)
+            .write(true)
+            .truncate(false)
+            .open(dest.as_ref())
+            .await
+            .map_err(io_error(context))?;
+        let existing = file.metadata().await.map_err(io_error(context))?.len();
+        download::check_partial_len(existing, offset)?;
+        file.set_len(offset).await.map_err(io_error(context))?;
+
+        let prefix = if offset > 0 && download::wants_checksum(&config) {
+            Some(download::hash_file_async(&mut file).await?)
+        } else {
+            None
+        };
+        file.seek(SeekFrom::Start(offset))
+            .await
+            .map_err(io_error(context))?;
+
+        let summary = self
+            .stream_artifact(
+                &job.into(),
+                &build.into(),
+                &path.into(),
+                &mut file,
+                prefix,
+                config,
+            )
+            .await?;
+        file.sync_all().await.map_err(io_error(context))?;
+        Ok(summary)
+    }
+
+    /// `GET /job/<name>/<build>/api/json?tree=fingerprint[fileName,hash]`
+    pub async fn fingerprints(
+        &self,
+        job: impl Into<JobPath>,
+        build: impl Into<BuildNumber>,
+    ) -> Result<Vec<Fingerprint>, Error> {
         let job = job.into();
         let build = build.into();
-        let path = path.into();
+        let req = build_selector_request(&job, build.as_str(), Some(FINGERPRINT_TREE));
+        let list: FingerprintList = self.client.send_json(req).await?;
+        Ok(list.fingerprint)
+    }
 
-        let mut segments = job_segments(&job);
-        segments.push(build.as_str().to_owned());
-        segments.push("artifact".to_owned());
-        segments.extend(path.url_segments().map(ToOwned::to_owned));
-        self.client.send_bytes(Request::get(segments)).await
+    /// `GET /job/<name>/<build>/api/json?tree=artifacts[fileName,relativePath,displayPath]`
+    pub async fn list_artifacts(
+        &self,
+        job: impl Into<JobPath>,
+        build: impl Into<BuildNumber>,
+    ) -> Result<Vec<Artifact>, Error> {
+        let job = job.into();
+        let build = build.into();
+        let req = build_selector_request(&job, build.as_str(), Some(ARTIFACTS_TREE));
+        let info: BuildInfo = self.client.send_json(req).await?;
+        Ok(info.artifacts)
+    }
+
+    /// Artifacts whose relative path matches any of the Ant-style `patterns` (`**/*.jar`).
+    pub async fn list_artifacts_matching<S: AsRef<str>>(
+        &self,
+        job: impl Into<JobPath>,
+        build: impl Into<BuildNumber>,
+        patterns: &[S],
+    ) -> Result<Vec<Artifact>, Error> {
+        let mut artifacts = self.list_artifacts(job, build).await?;
+        artifacts.retain(|artifact| glob_match_any(patterns, &artifact.relative_path));
+        Ok(artifacts)
+    }
+
+    /// Stream `GET /job/<name>/<build>/artifact/*zip*/archive.zip` into `writer`.
+    pub async fn download_artifacts_zip_to_writer<W>(
+        &self,
+        job: impl Into<JobPath>,
+        build: impl Into<BuildNumber>,
+        writer: &mut W,
+        config: DownloadConfig,
+    ) -> Result<DownloadSummary, Error>
+    where
+        W: AsyncWrite + Unpin + Send + ?Sized,
+    {
+        self.download_artifact_to_writer(job, build, ARTIFACTS_ZIP, writer, config)
+            .await
+    }
+
+    /// Stream `GET /job/<name>/<build>/artifact/*zip*/archive.zip` into the file at `dest`.
+    pub async fn download_artifacts_zip_to_path(
+        &self,
+        job: impl Into<JobPath>,
+        build: impl Into<BuildNumber>,
+        dest: impl AsRef<Path>,
+        config: DownloadConfig,
+    ) -> Result<DownloadSummary, Error> {
+        self.download_artifact_to_path(job, build, ARTIFACTS_ZIP, dest, config)
+            .await
+    }
+
+    /// Download the artifacts matching `patterns` (all when empty) into `dest_dir`,
+    /// keeping their relative paths.
+    ///
+    /// `config.resume_from` and `config.expected_md5` are ignored; with
+    /// `verify_fingerprint` every file is checked against its own fingerprint.
+    pub async fn mirror_artifacts<S: AsRef<str>>(
+        &self,
+        job: impl Into<JobPath>,
+        build: impl Into<BuildNumber>,
+        dest_dir: impl AsRef<Path>,
+        patterns: &[S],
+        config: DownloadConfig,
+    ) -> Result<Vec<MirroredArtifact>, Error> {
+        let job = job.into();
+        let build = build.into();
+        let artifacts = self
+            .list_artifacts_matching(job.clone(), build.clone(), patterns)
+            .await?;
+        let fingerprints = if config.verify_fingerprint {
+            self.fingerprints(job.clone(), build.clone()).await?
+        } else {
+            Vec::new()
+        };
+
+        let mut mirrored = Vec::with_capacity(artifacts.len());
+        for artifact in artifacts {
+            let path = download::mirror_path(dest_dir.as_ref(), &artifact)?;
+            if let Some(parent) = path.parent() {
+                tokio::fs::create_dir_all(parent)
+                    .await
+                    .map_err(io_error("creating the mirror directory"))?;
+            }
+            let file_config = download::mirror_config(&config, &fingerprints, &artifact)?;
+            let summary = self
+                .download_artifact_to_path(
+                    job.clone(),
+                    build.clone(),
+                    artifact.path(),
+                    &path,
+                    file_config,
+                )
+                .await?;
+            mirrored.push(MirroredArtifact {
+                artifact,
+                path,
+                summary,
+            });
+        }
+        Ok(mirrored)
+    }
+
+    async fn stream_artifact<W>(
+        &self,
+        job: &JobPath,
+        build: &BuildNumber,
+        path: &ArtifactPath,
+        writer: &mut W,
+        prefix: Option<md5::Context>,
+        config: DownloadConfig,
+    ) -> Result<DownloadSummary, Error>
+    where
+        W: AsyncWrite + Unpin + Send + ?Sized,
+    {
+        let context = "writing the downloaded artifact";
+        let expected_md5 = match &config.expected_md5 {
+            Some(md5) => Some(md5.clone()),
+            None if config.verify_fingerprint => {
+                let fingerprints = self.fingerprints(job.clone(), build.clone()).await?;
+                Some(download::fingerprint_md5(&fingerprints, path)?)
+            }
+            None => None,
+        };
+
+        let req = artifact_request(job, build, path, config.resume_from);
+        let mut resp = self.client.send_stream(req).await?;
+        let mut tracker = DownloadTracker::start(&config, resp.status, &resp.headers, prefix);
+        tracker.ensure_verifiable(expected_md5.as_deref())?;
+        while let Some(chunk) = resp.body.next().await {
+            let chunk = chunk?;
+            let data = tracker.accept(&chunk);
+            writer.write_all(data).await.map_err(io_error(context))?;
+            tracker.written(data.len());
+        }
+        writer.flush().await.map_err(io_error(context))?;
+        tracker.finish(path, expected_md5.as_deref())
     }
 
     /// `POST /job/<name>/<build>/stop`
@@ -361,32 +1227,199 @@ impl JobsService {
     pub async fn set_job_description(
         &self,
         job: impl Into<JobPath>,
-        description: impl Into<String>,
-    ) -> Result<(), Error> {
+        description: impl Into<String>,
+    ) -> Result<(), Error> {
+        let job = job.into();
+        let description = description.into();
+
+        let mut segments = job_segments(&job);
+        segments.push("submitDescription".to_owned());
+        let req = Request::post(segments).form_pairs([("description", description)]);
+        self.client.send_unit(req).await
+    }
+
+    /// `POST /job/<name>/build`
+    pub async fn build(&self, job: impl Into<JobPath>) -> Result<TriggeredBuild, Error> {
+        let job = job.into();
+        let mut segments = job_segments(&job);
+        segments.push("build".to_owned());
+        let resp = self.client.send_response(Request::post(segments)).await?;
+        Ok(triggered_build_from_response(&resp))
+    }
+
+    /// `POST /job/<name>/buildWithParameters`
+    pub async fn build_with_parameters<I, K, V>(
+        &self,
+        job: impl Into<JobPath>,
+        params: I,
+    ) -> Result<TriggeredBuild, Error>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        let job = job.into();
+        let mut segments = job_segments(&job);
+        segments.push("buildWithParameters".to_owned());
+        let req = Request::post(segments).form_pairs(params);
+        let resp = self.client.send_response(req).await?;
+        Ok(triggered_build_from_response(&resp))
+    }
+
+    /// `GET /job/<name>/api/json?tree=property[parameterDefinitions[...]]`
+    pub async fn parameter_definitions(
+        &self,
+        job: impl Into<JobPath>,
+    ) -> Result<Vec<ParameterDefinition>, Error> {
+        let info = self.get(job, Some(PARAMETERS_TREE)).await?;
+        Ok(info.parameter_definitions())
+    }
+
+    /// [`Self::build_with_parameters`] after checking `params` against
+    /// [`Self::parameter_definitions`]: unknown names, values outside a choice list and
+    /// non-boolean values for boolean parameters fail with [`Error::InvalidConfig`] before
+    /// anything is triggered.
+    pub async fn build_with_validated_parameters<I, K, V>(
+        &self,
+        job: impl Into<JobPath>,
+        params: I,
+    ) -> Result<TriggeredBuild, Error>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        let job = job.into();
+        let params: Vec<(String, String)> = params
+            .into_iter()
+            .map(|(name, value)| (name.into(), value.into()))
+            .collect();
+        let definitions = self.parameter_definitions(job.clone()).await?;
+        validate_parameters(&job, &definitions, &params)?;
+        self.build_with_parameters(job, params).await
+    }
+
+    /// Trigger `job` again with the parameters recorded on `build` (its `ParametersAction`).
+    ///
+    /// Uses `POST /job/<name>/build` when the build had no parameters, otherwise
+    /// `POST /job/<name>/buildWithParameters`. Fails for password and file parameters, whose
+    /// values Jenkins does not export.
+    pub async fn rebuild(
+        &self,
+        job: impl Into<JobPath>,
+        build: impl Into<BuildNumber>,
+    ) -> Result<TriggeredBuild, Error> {
+        let job = job.into();
+        let build = build.into();
+        let info = self
+            .build_info(job.clone(), build.clone(), Some(REBUILD_TREE))
+            .await?;
+        match rebuild_parameters(&build, &info)? {
+            Some(params) => self.build_with_parameters(job, params).await,
+            None => self.build(job).await,
+        }
+    }
+
+    /// `POST /job/<name>/buildWithParameters` as `multipart/form-data`, uploading `files`
+    /// for the job's file parameters alongside the ordinary `params`.
+    pub async fn build_with_files<I, K, V, F>(
+        &self,
+        job: impl Into<JobPath>,
+        params: I,
+        files: F,
+    ) -> Result<TriggeredBuild, Error>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+        F: IntoIterator<Item = FileParameter>,
+    {
+        let mut form = multipart_parameters(params);
+        for file in files {
+            let context = file_parameter_context(&file);
+            let bytes = match file.source {
+                FileSource::Bytes(bytes) => bytes,
+                FileSource::Path(path) => {
+                    tokio::fs::read(&path).await.map_err(io_error(&context))?
+                }
+            };
+            form = form.file(file.name, file.file_name, bytes);
+        }
+
+        let job = job.into();
+        let mut segments = job_segments(&job);
+        segments.push("buildWithParameters".to_owned());
+        let req = Request::post(segments).body(RequestBody::multipart(form));
+        let resp = self.client.send_response(req).await?;
+        Ok(triggered_build_from_response(&resp))
+    }
+
+    /// Follow a queue item until its build finishes.
+    ///
+    /// Polls `GET /queue/item/<id>/api/json` until `executable` appears (or the item is
+    /// cancelled), then polls `GET /job/<name>/<build>/api/json` until `building == false`.
+    pub async fn wait_for_build(
+        &self,
+        job: impl Into<JobPath>,
+        queue_item: impl Into<QueueItemId>,
+        config: WaitConfig,
+    ) -> Result<BuildOutcome, Error> {
         let job = job.into();
-        let description = description.into();
+        let queue_item = queue_item.into();
+        let clock = WaitClock::start(&config);
+        let queue = self.client.queue();
+
+        let build = loop {
+            let item = queue.item(queue_item.clone(), None).await?;
+            if let Some(build) = queue_item_build(&item) {
+                break build;
+            }
+            if item.cancelled == Some(true) {
+                return Ok(BuildOutcome::Cancelled(item));
+            }
+            tokio::time::sleep(clock.next_delay("queue item to start a build")?).await;
+        };
+
+        loop {
+            let info = self
+                .build_info(job.clone(), build.clone(), Some(WAIT_BUILD_TREE))
+                .await?;
+            if info.building != Some(true) {
+                break;
+            }
+            tokio::time::sleep(clock.next_delay("build to finish")?).await;
+        }
 
-        let mut segments = job_segments(&job);
-        segments.push("submitDescription".to_owned());
-        let req = Request::post(segments).form_pairs([("description", description)]);
-        self.client.send_unit(req).await
+        self.build_info(job, build, None)
+            .await
+            .map(BuildOutcome::Completed)
     }
 
-    /// `POST /job/<name>/build`
-    pub async fn build(&self, job: impl Into<JobPath>) -> Result<TriggeredBuild, Error> {
+    /// `POST /job/<name>/build`, then [`Self::wait_for_build`] on the created queue item.
+    pub async fn trigger_and_wait(
+        &self,
+        job: impl Into<JobPath>,
+        config: WaitConfig,
+    ) -> Result<BuildOutcome, Error> {
         let job = job.into();
         let mut segments = job_segments(&job);
         segments.push("build".to_owned());
-        let resp = self.client.send_response(Request::post(segments)).await?;
-        Ok(triggered_build_from_response(&resp))
+        let resp = self
+            .client
+            .send_response(Request::post(segments.clone()))
+            .await?;
+        let queue_item = triggered_queue_item(&segments, &resp)?;
+        self.wait_for_build(job, queue_item, config).await
     }
 
-    /// `POST /job/<name>/buildWithParameters`
-    pub async fn build_with_parameters<I, K, V>(
+    /// `POST /job/<name>/buildWithParameters`, then [`Self::wait_for_build`] on the created
+    /// queue item.
+    pub async fn trigger_with_parameters_and_wait<I, K, V>(
         &self,
         job: impl Into<JobPath>,
         params: I,
-    ) -> Result<TriggeredBuild, Error>
+        config: WaitConfig,
+    ) -> Result<BuildOutcome, Error>
     where
         I: IntoIterator<Item = (K, V)>,
         K: Into<String>,
@@ -395,9 +1428,10 @@ impl JobsService {
         let job = job.into();
         let mut segments = job_segments(&job);
         segments.push("buildWithParameters".to_owned());
-        let req = Request::post(segments).form_pairs(params);
+        let req = Request::post(segments.clone()).form_pairs(params);
         let resp = self.client.send_response(req).await?;
-        Ok(triggered_build_from_response(&resp))
+        let queue_item = triggered_queue_item(&segments, &resp)?;
+        self.wait_for_build(job, queue_item, config).await
     }
 
     /// `GET /job/<name>/config.xml`
@@ -425,36 +1459,78 @@ impl JobsService {
     }
 
     /// `POST /createItem?name=<name>` with XML body.
+    ///
+    /// `name` may be a folder path (`team/app`); the job is then created through that
+    /// folder's `createItem`.
     pub async fn create_from_xml(
         &self,
-        name: impl Into<JobName>,
+        name: impl Into<JobPath>,
         xml: impl Into<Vec<u8>>,
     ) -> Result<(), Error> {
-        let name = name.into();
-        let req = Request::post(["createItem"])
-            .query_pair("name", name.as_str())
-            .body(RequestBody::bytes_with_content_type(
-                xml.into(),
-                HeaderValue::from_static("application/xml"),
-            ));
-        self.client.send_unit(req).await
+        let (req, _) = create_item_request(&name.into());
+        self.client.send_unit(req.body(xml_body(xml.into()))).await
     }
 
     /// `POST /createItem?name=<new>&mode=copy&from=<from>`
+    ///
+    /// `to` may be a folder path (`team/app-copy`) to create the copy inside that folder.
     pub async fn copy(
         &self,
         from: impl Into<JobPath>,
-        to: impl Into<JobName>,
+        to: impl Into<JobPath>,
     ) -> Result<(), Error> {
         let from = from.into();
-        let to = to.into();
-        let req = Request::post(["createItem"])
-            .query_pair("name", to.as_str())
-            .query_pair("mode", "copy")
-            .query_pair("from", from.as_str());
+        let (req, parent) = create_item_request(&to.into());
+        // `from` is resolved relative to the target folder unless it is absolute.
+        let from = if parent.as_str().is_empty() {
+            from.as_str().to_owned()
+        } else {
+            format!("/{}", from.as_str().trim_start_matches('/'))
+        };
+        let req = req.query_pair("mode", "copy").query_pair("from", from);
         self.client.send_unit(req).await
     }
 
+    /// `POST /job/<parent>/createItem?name=<name>` with a `Folder` config.xml; an empty
+    /// `parent` creates the folder at the root.
+    pub async fn create_folder(
+        &self,
+        parent: impl Into<JobPath>,
+        name: impl Into<JobName>,
+        description: Option<&str>,
+    ) -> Result<(), Error> {
+        let parent = parent.into();
+        let name = name.into();
+        let path = JobPath::new(format!("{}/{}", parent.as_str(), name.as_str()));
+        let (req, _) = create_item_request(&path);
+        self.client
+            .send_unit(req.body(xml_body(folder_xml(description))))
+            .await
+    }
+
+    /// `POST /job/<name>/move/move` with `destination=/<folder>`; an empty
+    /// `destination_folder` moves the job to the root. Returns the job's new path.
+    pub async fn move_job(
+        &self,
+        job: impl Into<JobPath>,
+        destination_folder: impl Into<JobPath>,
+    ) -> Result<JobPath, Error> {
+        let job = job.into();
+        let destination = destination_folder.into();
+        let destination = destination.as_str().trim_matches('/');
+        let mut segments = job_segments(&job);
+        segments.extend(["move", "move"].map(str::to_owned));
+        let req = Request::post(segments).form_pairs([("destination", format!("/{destination}"))]);
+        self.client.send_unit(req).await?;
+
+        let (_, name) = split_item_path(&job);
+        Ok(JobPath::new(if destination.is_empty() {
+            name
+        } else {
+            format!("{destination}/{name}")
+        }))
+    }
+
     /// `POST /job/<name>/doDelete`
     pub async fn delete(&self, job: impl Into<JobPath>) -> Result<(), Error> {
         let job = job.into();
@@ -516,6 +1592,18 @@ impl BlockingJobsService {
             .send_json(Request::get(["api", "json"]).query_pair("tree", "jobs[name,url,color]"))
     }
 
+    /// Walk the job tree below `config.root`, descending into folders, organization folders
+    /// and multibranch projects (detected through `_class`).
+    ///
+    /// Each request lists up to `config.levels_per_request` levels through a nested
+    /// `jobs[...]` tree; containers are yielded before their children.
+    pub fn walk_jobs(&self, config: JobWalkConfig) -> BlockingJobWalk {
+        BlockingJobWalk {
+            service: self.clone(),
+            walker: JobWalker::new(config),
+        }
+    }
+
     /// `GET /job/<name>/api/json`
     pub fn get(&self, job: impl Into<JobPath>, tree: Option<&str>) -> Result<JobInfo, Error> {
         let job = job.into();
@@ -631,6 +1719,62 @@ impl BlockingJobsService {
         Ok(parse_progressive_text(resp))
     }
 
+    /// Follow `GET /job/<name>/<build>/logText/progressiveText` as an iterator of appended
+    /// chunks.
+    ///
+    /// Polls from `config.start`, backs off while the build produces no output and ends once
+    /// Jenkins reports `X-More-Data: false`. The iterator ends after yielding an error.
+    pub fn follow_console(
+        &self,
+        job: impl Into<JobPath>,
+        build: impl Into<BuildNumber>,
+        config: ConsoleFollowConfig,
+    ) -> BlockingConsoleChunks {
+        BlockingConsoleChunks {
+            service: self.clone(),
+            job: job.into(),
+            build: build.into(),
+            cursor: ConsoleCursor::new(&config),
+        }
+    }
+
+    /// Like [`Self::follow_console`], but yields complete console lines.
+    pub fn follow_console_lines(
+        &self,
+        job: impl Into<JobPath>,
+        build: impl Into<BuildNumber>,
+        config: ConsoleFollowConfig,
+    ) -> BlockingConsoleLines {
+        self.follow_console(job, build, config).lines()
+    }
+
+    /// `GET /job/<name>/api/json?tree=allBuilds[...]{from,to}`: builds `from..to` (newest
+    /// first, `to` exclusive).
+    pub fn build_range(
+        &self,
+        job: impl Into<JobPath>,
+        from: usize,
+        to: usize,
+    ) -> Result<Vec<BuildInfo>, Error> {
+        let job = job.into();
+        let mut segments = job_segments(&job);
+        segments.extend(["api", "json"].map(str::to_owned));
+        let req = Request::get(segments)
+            .query_pair("tree", format!("allBuilds[{BUILDS_TREE}]{{{from},{to}}}"));
+        let page: BuildPage = self.client.send_json(req)?;
+        Ok(page.all_builds)
+    }
+
+    /// Walk the build history page by page ([`Self::build_range`]), yielding the builds
+    /// matching `query`.
+    pub fn builds(&self, job: impl Into<JobPath>, query: BuildQuery) -> BlockingBuilds {
+        BlockingBuilds {
+            service: self.clone(),
+            job: job.into(),
+            pager: BuildPager::new(query),
+        }
+    }
+
     /// `GET /job/<name>/<build>/api/json`
     pub fn build_info(
         &self,
@@ -674,6 +1818,43 @@ impl BlockingJobsService {
         self.client.send_text(Request::get(segments))
     }
 
+    /// `GET /job/<name>/<build>/testReport/api/json`
+    pub fn test_report(
+        &self,
+        job: impl Into<JobPath>,
+        build: impl Into<BuildNumber>,
+        tree: Option<&str>,
+    ) -> Result<TestReport, Error> {
+        let job = job.into();
+        let build = build.into();
+        self.client
+            .send_json(test_report_request(&job, build.as_str(), tree))
+    }
+
+    /// `GET /job/<name>/lastCompletedBuild/testReport/api/json`
+    pub fn last_completed_test_report(
+        &self,
+        job: impl Into<JobPath>,
+        tree: Option<&str>,
+    ) -> Result<TestReport, Error> {
+        let job = job.into();
+        self.client
+            .send_json(test_report_request(&job, "lastCompletedBuild", tree))
+    }
+
+    /// Fetch the test reports of `previous` and `current` and compare them.
+    pub fn test_report_diff(
+        &self,
+        job: impl Into<JobPath>,
+        previous: impl Into<BuildNumber>,
+        current: impl Into<BuildNumber>,
+    ) -> Result<TestReportDiff, Error> {
+        let job = job.into();
+        let previous = self.test_report(job.clone(), previous, None)?;
+        let current = self.test_report(job, current, None)?;
+        Ok(TestReportDiff::between(&previous, &current))
+    }
+
     /// `GET /job/<name>/<build>/artifact/<path>`
     pub fn download_artifact(
         &self,
@@ -681,15 +1862,224 @@ impl BlockingJobsService {
         build: impl Into<BuildNumber>,
         path: impl Into<ArtifactPath>,
     ) -> Result<Vec<u8>, Error> {
+        let req = artifact_request(&job.into(), &build.into(), &path.into(), None);
+        self.client.send_bytes(req)
+    }
+
+    /// Stream `GET /job/<name>/<build>/artifact/<path>` into `writer` without buffering it.
+    ///
+    /// With [`DownloadConfig::resume_from`], `writer` is expected to already hold that many
+    /// bytes; an MD5 can then only be checked if the server ignores the `Range` request,
+    /// and a partial response fails before anything is written.
+    pub fn download_artifact_to_writer<W>(
+        &self,
+        job: impl Into<JobPath>,
+        build: impl Into<BuildNumber>,
+        path: impl Into<ArtifactPath>,
+        writer: &mut W,
+        config: DownloadConfig,
+    ) -> Result<DownloadSummary, Error>
+    where
+        W: Write + ?Sized,
+    {
+        self.stream_artifact(
+            &job.into(),
+            &build.into(),
+            &path.into(),
+            writer,
+            None,
+            config,
+        )
+    }
+
+    /// Stream `GET /job/<name>/<build>/artifact/<path>` into the file at `dest`.
+    ///
+    /// Without [`DownloadConfig::resume_from`] the file is truncated; with it, the first
+    /// `offset` bytes of the existing file are kept and the rest is requested via `Range`.
+    pub fn download_artifact_to_path(
+        &self,
+        job: impl Into<JobPath>,
+        build: impl Into<BuildNumber>,
+        path: impl Into<ArtifactPath>,
+        dest: impl AsRef<Path>,
+        config: DownloadConfig,
+    ) -> Result<DownloadSummary, Error> {
+        let context = "writing the download destination";
+        let offset = config.resume_from.unwrap_or(0);
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .read(true)
+            .write(true)
+            .truncate(false)
+            .open(dest.as_ref())
+            .map_err(io_error(context))?;
+        let existing = file.metadata().map_err(io_error(context))?.len();
+        download::check_partial_len(existing, offset)?;
+        file.set_len(offset).map_err(io_error(context))?;
+
+        let prefix = if offset > 0 && download::wants_checksum(&config) {
+            Some(download::hash_file(&mut file)?)
+        } else {
+            None
+        };
+        file.seek(SeekFrom::Start(offset))
+            .map_err(io_error(context))?;
+
+        let summary = self.stream_artifact(
+            &job.into(),
+            &build.into(),
+            &path.into(),
+            &mut file,
+            prefix,
+            config,
+        )?;
+        file.sync_all().map_err(io_error(context))?;
+        Ok(summary)
+    }
+
+    /// `GET /job/<name>/<build>/api/json?tree=fingerprint[fileName,hash]`
+    pub fn fingerprints(
+        &self,
+        job: impl Into<JobPath>,
+        build: impl Into<BuildNumber>,
+    ) -> Result<Vec<Fingerprint>, Error> {
         let job = job.into();
         let build = build.into();
-        let path = path.into();
+        let req = build_selector_request(&job, build.as_str(), Some(FINGERPRINT_TREE));
+        let list: FingerprintList = self.client.send_json(req)?;
+        Ok(list.fingerprint)
+    }
 
-        let mut segments = job_segments(&job);
-        segments.push(build.as_str().to_owned());
-        segments.push("artifact".to_owned());
-        segments.extend(path.url_segments().map(ToOwned::to_owned));
-        self.client.send_bytes(Request::get(segments))
+    /// `GET /job/<name>/<build>/api/json?tree=artifacts[fileName,relativePath,displayPath]`
+    pub fn list_artifacts(
+        &self,
+        job: impl Into<JobPath>,
+        build: impl Into<BuildNumber>,
+    ) -> Result<Vec<Artifact>, Error> {
+        let job = job.into();
+        let build = build.into();
+        let req = build_selector_request(&job, build.as_str(), Some(ARTIFACTS_TREE));
+        let info: BuildInfo = self.client.send_json(req)?;
+        Ok(info.artifacts)
+    }
+
+    /// Artifacts whose relative path matches any of the Ant-style `patterns` (`**/*.jar`).
+    pub fn list_artifacts_matching<S: AsRef<str>>(
+        &self,
+        job: impl Into<JobPath>,
+        build: impl Into<BuildNumber>,
+        patterns: &[S],
+    ) -> Result<Vec<Artifact>, Error> {
+        let mut artifacts = self.list_artifacts(job, build)?;
+        artifacts.retain(|artifact| glob_match_any(patterns, &artifact.relative_path));
+        Ok(artifacts)
+    }
+
+    /// Stream `GET /job/<name>/<build>/artifact/*zip*/archive.zip` into `writer`.
+    pub fn download_artifacts_zip_to_writer<W>(
+        &self,
+        job: impl Into<JobPath>,
+        build: impl Into<BuildNumber>,
+        writer: &mut W,
+        config: DownloadConfig,
+    ) -> Result<DownloadSummary, Error>
+    where
+        W: Write + ?Sized,
+    {
+        self.download_artifact_to_writer(job, build, ARTIFACTS_ZIP, writer, config)
+    }
+
+    /// Stream `GET /job/<name>/<build>/artifact/*zip*/archive.zip` into the file at `dest`.
+    pub fn download_artifacts_zip_to_path(
+        &self,
+        job: impl Into<JobPath>,
+        build: impl Into<BuildNumber>,
+        dest: impl AsRef<Path>,
+        config: DownloadConfig,
+    ) -> Result<DownloadSummary, Error> {
+        self.download_artifact_to_path(job, build, ARTIFACTS_ZIP, dest, config)
+    }
+
+    /// Download the artifacts matching `patterns` (all when empty) into `dest_dir`,
+    /// keeping their relative paths.
+    ///
+    /// `config.resume_from` and `config.expected_md5` are ignored; with
+    /// `verify_fingerprint` every file is checked against its own fingerprint.
+    pub fn mirror_artifacts<S: AsRef<str>>(
+        &self,
+        job: impl Into<JobPath>,
+        build: impl Into<BuildNumber>,
+        dest_dir: impl AsRef<Path>,
+        patterns: &[S],
+        config: DownloadConfig,
+    ) -> Result<Vec<MirroredArtifact>, Error> {
+        let job = job.into();
+        let build = build.into();
+        let artifacts = self.list_artifacts_matching(job.clone(), build.clone(), patterns)?;
+        let fingerprints = if config.verify_fingerprint {
+            self.fingerprints(job.clone(), build.clone())?
+        } else {
+            Vec::new()
+        };
+
+        let mut mirrored = Vec::with_capacity(artifacts.len());
+        for artifact in artifacts {
+            let path = download::mirror_path(dest_dir.as_ref(), &artifact)?;
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)
+                    .map_err(io_error("creating the mirror directory"))?;
+            }
+            let file_config = download::mirror_config(&config, &fingerprints, &artifact)?;
+            let summary = self.download_artifact_to_path(
+                job.clone(),
+                build.clone(),
+                artifact.path(),
+                &path,
+                file_config,
+            )?;
+            mirrored.push(MirroredArtifact {
+                artifact,
+                path,
+                summary,
+            });
+        }
+        Ok(mirrored)
+    }
+
+    fn stream_artifact<W>(
+        &self,
+        job: &JobPath,
+        build: &BuildNumber,
+        path: &ArtifactPath,
+        writer: &mut W,
+        prefix: Option<md5::Context>,
+        config: DownloadConfig,
+    ) -> Result<DownloadSummary, Error>
+    where
+        W: Write + ?Sized,
+    {
+        let context = "writing the downloaded artifact";
+        let expected_md5 = match &config.expected_md5 {
+            Some(md5) => Some(md5.clone()),
+            None if config.verify_fingerprint => {
+                let fingerprints = self.fingerprints(job.clone(), build.clone())?;
+                Some(download::fingerprint_md5(&fingerprints, path)?)
+            }
+            None => None,
+        };
+
+        let req = artifact_request(job, build, path, config.resume_from);
+        let resp = self.client.send_stream(req)?;
+        let mut tracker = DownloadTracker::start(&config, resp.status, &resp.headers, prefix);
+        tracker.ensure_verifiable(expected_md5.as_deref())?;
+        for chunk in resp.body {
+            let chunk = chunk?;
+            let data = tracker.accept(&chunk);
+            writer.write_all(data).map_err(io_error(context))?;
+            tracker.written(data.len());
+        }
+        writer.flush().map_err(io_error(context))?;
+        tracker.finish(path, expected_md5.as_deref())
     }
 
     /// `POST /job/<name>/<build>/stop`
@@ -825,6 +2215,164 @@ impl BlockingJobsService {
         Ok(triggered_build_from_response(&resp))
     }
 
+    /// `GET /job/<name>/api/json?tree=property[parameterDefinitions[...]]`
+    pub fn parameter_definitions(
+        &self,
+        job: impl Into<JobPath>,
+    ) -> Result<Vec<ParameterDefinition>, Error> {
+        let info = self.get(job, Some(PARAMETERS_TREE))?;
+        Ok(info.parameter_definitions())
+    }
+
+    /// [`Self::build_with_parameters`] after checking `params` against
+    /// [`Self::parameter_definitions`]: unknown names, values outside a choice list and
+    /// non-boolean values for boolean parameters fail with [`Error::InvalidConfig`] before
+    /// anything is triggered.
+    pub fn build_with_validated_parameters<I, K, V>(
+        &self,
+        job: impl Into<JobPath>,
+        params: I,
+    ) -> Result<TriggeredBuild, Error>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        let job = job.into();
+        let params: Vec<(String, String)> = params
+            .into_iter()
+            .map(|(name, value)| (name.into(), value.into()))
+            .collect();
+        let definitions = self.parameter_definitions(job.clone())?;
+        validate_parameters(&job, &definitions, &params)?;
+        self.build_with_parameters(job, params)
+    }
+
+    /// Trigger `job` again with the parameters recorded on `build` (its `ParametersAction`).
+    ///
+    /// Uses `POST /job/<name>/build` when the build had no parameters, otherwise
+    /// `POST /job/<name>/buildWithParameters`. Fails for password and file parameters, whose
+    /// values Jenkins does not export.
+    pub fn rebuild(
+        &self,
+        job: impl Into<JobPath>,
+        build: impl Into<BuildNumber>,
+    ) -> Result<TriggeredBuild, Error> {
+        let job = job.into();
+        let build = build.into();
+        let info = self.build_info(job.clone(), build.clone(), Some(REBUILD_TREE))?;
+        match rebuild_parameters(&build, &info)? {
+            Some(params) => self.build_with_parameters(job, params),
+            None => self.build(job),
+        }
+    }
+
+    /// `POST /job/<name>/buildWithParameters` as `multipart/form-data`, uploading `files`
+    /// for the job's file parameters alongside the ordinary `params`.
+    pub fn build_with_files<I, K, V, F>(
+        &self,
+        job: impl Into<JobPath>,
+        params: I,
+        files: F,
+    ) -> Result<TriggeredBuild, Error>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+        F: IntoIterator<Item = FileParameter>,
+    {
+        let mut form = multipart_parameters(params);
+        for file in files {
+            let context = file_parameter_context(&file);
+            let bytes = match file.source {
+                FileSource::Bytes(bytes) => bytes,
+                FileSource::Path(path) => std::fs::read(&path).map_err(io_error(&context))?,
+            };
+            form = form.file(file.name, file.file_name, bytes);
+        }
+
+        let job = job.into();
+        let mut segments = job_segments(&job);
+        segments.push("buildWithParameters".to_owned());
+        let req = Request::post(segments).body(RequestBody::multipart(form));
+        let resp = self.client.send_response(req)?;
+        Ok(triggered_build_from_response(&resp))
+    }
+
+    /// Follow a queue item until its build finishes.
+    ///
+    /// Polls `GET /queue/item/<id>/api/json` until `executable` appears (or the item is
+    /// cancelled), then polls `GET /job/<name>/<build>/api/json` until `building == false`.
+    pub fn wait_for_build(
+        &self,
+        job: impl Into<JobPath>,
+        queue_item: impl Into<QueueItemId>,
+        config: WaitConfig,
+    ) -> Result<BuildOutcome, Error> {
+        let job = job.into();
+        let queue_item = queue_item.into();
+        let clock = WaitClock::start(&config);
+        let queue = self.client.queue();
+
+        let build = loop {
+            let item = queue.item(queue_item.clone(), None)?;
+            if let Some(build) = queue_item_build(&item) {
+                break build;
+            }
+            if item.cancelled == Some(true) {
+                return Ok(BuildOutcome::Cancelled(item));
+            }
+            std::thread::sleep(clock.next_delay("queue item to start a build")?);
+        };
+
+        loop {
+            let info = self.build_info(job.clone(), build.clone(), Some(WAIT_BUILD_TREE))?;
+            if info.building != Some(true) {
+                break;
+            }
+            std::thread::sleep(clock.next_delay("build to finish")?);
+        }
+
+        self.build_info(job, build, None)
+            .map(BuildOutcome::Completed)
+    }
+
+    /// `POST /job/<name>/build`, then [`Self::wait_for_build`] on the created queue item.
+    pub fn trigger_and_wait(
+        &self,
+        job: impl Into<JobPath>,
+        config: WaitConfig,
+    ) -> Result<BuildOutcome, Error> {
+        let job = job.into();
+        let mut segments = job_segments(&job);
+        segments.push("build".to_owned());
+        let resp = self.client.send_response(Request::post(segments.clone()))?;
+        let queue_item = triggered_queue_item(&segments, &resp)?;
+        self.wait_for_build(job, queue_item, config)
+    }
+
+    /// `POST /job/<name>/buildWithParameters`, then [`Self::wait_for_build`] on the created
+    /// queue item.
+    pub fn trigger_with_parameters_and_wait<I, K, V>(
+        &self,
+        job: impl Into<JobPath>,
+        params: I,
+        config: WaitConfig,
+    ) -> Result<BuildOutcome, Error>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        let job = job.into();
+        let mut segments = job_segments(&job);
+        segments.push("buildWithParameters".to_owned());
+        let req = Request::post(segments.clone()).form_pairs(params);
+        let resp = self.client.send_response(req)?;
+        let queue_item = triggered_queue_item(&segments, &resp)?;
+        self.wait_for_build(job, queue_item, config)
+    }
+
     /// `GET /job/<name>/config.xml`
     pub fn get_config_xml(&self, job: impl Into<JobPath>) -> Result<Vec<u8>, Error> {
         let job = job.into();
@@ -850,32 +2398,73 @@ impl BlockingJobsService {
     }
 
     /// `POST /createItem?name=<name>` with XML body.
+    ///
+    /// `name` may be a folder path (`team/app`); the job is then created through that
+    /// folder's `createItem`.
     pub fn create_from_xml(
         &self,
-        name: impl Into<JobName>,
+        name: impl Into<JobPath>,
         xml: impl Into<Vec<u8>>,
     ) -> Result<(), Error> {
-        let name = name.into();
-        let req = Request::post(["createItem"])
-            .query_pair("name", name.as_str())
-            .body(RequestBody::bytes_with_content_type(
-                xml.into(),
-                HeaderValue::from_static("application/xml"),
-            ));
-        self.client.send_unit(req)
+        let (req, _) = create_item_request(&name.into());
+        self.client.send_unit(req.body(xml_body(xml.into())))
     }
 
     /// `POST /createItem?name=<new>&mode=copy&from=<from>`
-    pub fn copy(&self, from: impl Into<JobPath>, to: impl Into<JobName>) -> Result<(), Error> {
+    ///
+    /// `to` may be a folder path (`team/app-copy`) to create the copy inside that folder.
+    pub fn copy(&self, from: impl Into<JobPath>, to: impl Into<JobPath>) -> Result<(), Error> {
         let from = from.into();
-        let to = to.into();
-        let req = Request::post(["createItem"])
-            .query_pair("name", to.as_str())
-            .query_pair("mode", "copy")
-            .query_pair("from", from.as_str());
+        let (req, parent) = create_item_request(&to.into());
+        // `from` is resolved relative to the target folder unless it is absolute.
+        let from = if parent.as_str().is_empty() {
+            from.as_str().to_owned()
+        } else {
+            format!("/{}", from.as_str().trim_start_matches('/'))
+        };
+        let req = req.query_pair("mode", "copy").query_pair("from", from);
         self.client.send_unit(req)
     }
 
+    /// `POST /job/<parent>/createItem?name=<name>` with a `Folder` config.xml; an empty
+    /// `parent` creates the folder at the root.
+    pub fn create_folder(
+        &self,
+        parent: impl Into<JobPath>,
+        name: impl Into<JobName>,
+        description: Option<&str>,
+    ) -> Result<(), Error> {
+        let parent = parent.into();
+        let name = name.into();
+        let path = JobPath::new(format!("{}/{}", parent.as_str(), name.as_str()));
+        let (req, _) = create_item_request(&path);
+        self.client
+            .send_unit(req.body(xml_body(folder_xml(description))))
+    }
+
+    /// `POST /job/<name>/move/move` with `destination=/<folder>`; an empty
+    /// `destination_folder` moves the job to the root. Returns the job's new path.
+    pub fn move_job(
+        &self,
+        job: impl Into<JobPath>,
+        destination_folder: impl Into<JobPath>,
+    ) -> Result<JobPath, Error> {
+        let job = job.into();
+        let destination = destination_folder.into();
+        let destination = destination.as_str().trim_matches('/');
+        let mut segments = job_segments(&job);
+        segments.extend(["move", "move"].map(str::to_owned));
+        let req = Request::post(segments).form_pairs([("destination", format!("/{destination}"))]);
+        self.client.send_unit(req)?;
+
+        let (_, name) = split_item_path(&job);
+        Ok(JobPath::new(if destination.is_empty() {
+            name
+        } else {
+            format!("{destination}/{name}")
+        }))
+    }
+
     /// `POST /job/<name>/doDelete`
     pub fn delete(&self, job: impl Into<JobPath>) -> Result<(), Error> {
         let job = job.into();
@@ -914,3 +2503,149 @@ impl BlockingJobsService {
         self.client.send_unit(req)
     }
 }
+
+/// Blocking iterator over appended console chunks, see [`BlockingJobsService::follow_console`].
+#[cfg(feature = "blocking")]
+pub struct BlockingConsoleChunks {
+    service: BlockingJobsService,
+    job: JobPath,
+    build: BuildNumber,
+    cursor: ConsoleCursor,
+}
+
+#[cfg(feature = "blocking")]
+impl BlockingConsoleChunks {
+    /// Regroup the chunks into complete console lines.
+    #[must_use]
+    pub fn lines(self) -> BlockingConsoleLines {
+        BlockingConsoleLines {
+            chunks: self,
+            lines: LineBuffer::default(),
+            finished: false,
+        }
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl Iterator for BlockingConsoleChunks {
+    type Item = Result<String, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.cursor.done {
+                return None;
+            }
+            if !self.cursor.next_delay.is_zero() {
+                std::thread::sleep(self.cursor.next_delay);
+            }
+            match self.service.progressive_console_text(
+                self.job.clone(),
+                self.build.clone(),
+                self.cursor.offset,
+            ) {
+                Ok(chunk) => {
+                    if let Some(text) = self.cursor.advance(chunk) {
+                        return Some(Ok(text));
+                    }
+                }
+                Err(err) => {
+                    self.cursor.done = true;
+                    return Some(Err(err));
+                }
+            }
+        }
+    }
+}
+
+/// Blocking iterator over complete console lines, see
+/// [`BlockingJobsService::follow_console_lines`].
+#[cfg(feature = "blocking")]
+pub struct BlockingConsoleLines {
+    chunks: BlockingConsoleChunks,
+    lines: LineBuffer,
+    finished: bool,
+}
+
+#[cfg(feature = "blocking")]
+impl Iterator for BlockingConsoleLines {
+    type Item = Result<String, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(line) = self.lines.pop() {
+                return Some(Ok(line));
+            }
+            if self.finished {
+                return None;
+            }
+            match self.chunks.next() {
+                Some(Ok(text)) => self.lines.push(&text),
+                Some(Err(err)) => return Some(Err(err)),
+                None => {
+                    self.lines.finish();
+                    self.finished = true;
+                }
+            }
+        }
+    }
+}
+
+/// Blocking iterator over a job's build history, see [`BlockingJobsService::builds`].
+#[cfg(feature = "blocking")]
+pub struct BlockingBuilds {
+    service: BlockingJobsService,
+    job: JobPath,
+    pager: BuildPager,
+}
+
+#[cfg(feature = "blocking")]
+impl Iterator for BlockingBuilds {
+    type Item = Result<BuildInfo, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(build) = self.pager.pop() {
+                return Some(Ok(build));
+            }
+            if self.pager.done() {
+                return None;
+            }
+            let (from, to) = self.pager.next_range();
+            match self.service.build_range(self.job.clone(), from, to) {
+                Ok(page) => self.pager.accept(page),
+                Err(err) => {
+                    self.pager.finish();
+                    return Some(Err(err));
+                }
+            }
+        }
+    }
+}
+
+/// Blocking iterator over a job tree, see [`BlockingJobsService::walk_jobs`].
+#[cfg(feature = "blocking")]
+pub struct BlockingJobWalk {
+    service: BlockingJobsService,
+    walker: JobWalker,
+}
+
+#[cfg(feature = "blocking")]
+impl Iterator for BlockingJobWalk {
+    type Item = Result<(JobPath, JobSummary), Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(item) = self.walker.pop() {
+                return Some(Ok(item));
+            }
+            let (container, depth, req) = self.walker.next_request()?;
+            match self.service.client.send_json::<JobList>(req) {
+                Ok(list) => self.walker.accept(container.as_ref(), depth, list.jobs),
+                Err(err) => {
+                    self.walker.finish();
+                    return Some(Err(err));
+                }
+            }
+        }
+    }
+}
@@ -4,18 +4,30 @@
 //! - `Client::jobs()` / `BlockingClient::jobs()`
 //! - `Client::queue()` / `BlockingClient::queue()`
 //! - `Client::system()` / `BlockingClient::system()`
+//! - `Client::pipeline()` / `BlockingClient::pipeline()`
+//! - `Client::multibranch()` / `BlockingClient::multibranch()`
+//! - `Client::plugins()` / `BlockingClient::plugins()`
+//! - `Client::credentials()` / `BlockingClient::credentials()`
 
 pub mod computers;
+pub mod credentials;
 pub mod jobs;
+pub mod multibranch;
 pub mod people;
+pub mod pipeline;
+pub mod plugins;
 pub mod queue;
 pub mod system;
 pub mod users;
 pub mod views;
 
 pub use computers::*;
+pub use credentials::*;
 pub use jobs::*;
+pub use multibranch::*;
 pub use people::*;
+pub use pipeline::*;
+pub use plugins::*;
 pub use queue::*;
 pub use system::*;
 pub use users::*;
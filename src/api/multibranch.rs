@@ -0,0 +1,196 @@
+use super::jobs::{WaitClock, job_segments};
+use crate::transport::request::Request;
+use crate::{BranchJob, BranchKind, Error, JobPath, WaitConfig};
+use serde::Deserialize;
+
+const BRANCHES_TREE: &str = "disabled,jobs[name,displayName,url,color,buildable,\
+lastBuild[number,url,result,building,timestamp,duration]],views[name,jobs[name]]";
+
+#[derive(Deserialize)]
+struct BranchListing {
+    /// Whether the multibranch project itself is disabled (absent on older branch-api).
+    #[serde(default)]
+    disabled: Option<bool>,
+    #[serde(default)]
+    jobs: Vec<BranchJob>,
+    /// One view per branch category (`default`, `change-requests`, `tags`).
+    #[serde(default)]
+    views: Vec<CategoryView>,
+}
+
+#[derive(Deserialize)]
+struct CategoryView {
+    #[serde(default)]
+    name: String,
+    #[serde(default)]
+    jobs: Vec<CategoryJob>,
+}
+
+#[derive(Deserialize)]
+struct CategoryJob {
+    name: String,
+}
+
+impl BranchListing {
+    fn into_branches(self) -> Vec<BranchJob> {
+        let mut jobs = self.jobs;
+        if self.disabled == Some(true) {
+            for job in &mut jobs {
+                job.project_disabled = true;
+            }
+        }
+        for view in &self.views {
+            // Jobs outside the other categories are branches already.
+            let Some(kind) = BranchKind::from_category(&view.name) else {
+                continue;
+            };
+            if kind == BranchKind::Branch {
+                continue;
+            }
+            for listed in &view.jobs {
+                for job in jobs.iter_mut().filter(|job| job.name == listed.name) {
+                    job.kind = kind.clone();
+                }
+            }
+        }
+        jobs
+    }
+}
+
+fn project_segments<const N: usize>(project: &JobPath, tail: [&str; N]) -> Vec<String> {
+    let mut segments = job_segments(project);
+    segments.extend(tail.map(str::to_owned));
+    segments
+}
+
+fn scan_request(project: &JobPath) -> Request {
+    Request::post(project_segments(project, ["build"])).query_pair("delay", "0")
+}
+
+fn branches_request(project: &JobPath) -> Request {
+    Request::get(project_segments(project, ["api", "json"])).query_pair("tree", BRANCHES_TREE)
+}
+
+/// Match on the job name or on the branch name (`feature/x` is job `feature%2Fx`).
+fn find_branch(branches: Vec<BranchJob>, branch: &str) -> Option<BranchJob> {
+    branches
+        .into_iter()
+        .find(|job| job.name == branch || job.display_name.as_deref() == Some(branch))
+}
+
+/// Multibranch projects and organization folders (Branch API plugin).
+#[derive(Clone)]
+#[cfg(feature = "async")]
+pub struct MultibranchService {
+    client: crate::Client,
+}
+
+#[cfg(feature = "async")]
+impl MultibranchService {
+    pub(crate) fn new(client: crate::Client) -> Self {
+        Self { client }
+    }
+}
+
+#[cfg(feature = "async")]
+impl MultibranchService {
+    /// `POST /job/<name>/build?delay=0` (branch indexing, or organization scan).
+    pub async fn scan(&self, project: impl Into<JobPath>) -> Result<(), Error> {
+        self.client.send_unit(scan_request(&project.into())).await
+    }
+
+    /// `GET /job/<name>/indexing/consoleText`
+    pub async fn indexing_log(&self, project: impl Into<JobPath>) -> Result<String, Error> {
+        let segments = project_segments(&project.into(), ["indexing", "consoleText"]);
+        self.client.send_text(Request::get(segments)).await
+    }
+
+    /// `GET /job/<name>/computation/consoleText` (organization folder scan log)
+    pub async fn organization_scan_log(&self, folder: impl Into<JobPath>) -> Result<String, Error> {
+        let segments = project_segments(&folder.into(), ["computation", "consoleText"]);
+        self.client.send_text(Request::get(segments)).await
+    }
+
+    /// `GET /job/<name>/api/json` with branches, pull requests and tags and their last build.
+    pub async fn branches(&self, project: impl Into<JobPath>) -> Result<Vec<BranchJob>, Error> {
+        let listing: BranchListing = self
+            .client
+            .send_json(branches_request(&project.into()))
+            .await?;
+        Ok(listing.into_branches())
+    }
+
+    /// Poll [`Self::branches`] until `branch` shows up, e.g. after [`Self::scan`].
+    pub async fn wait_for_branch(
+        &self,
+        project: impl Into<JobPath>,
+        branch: &str,
+        config: WaitConfig,
+    ) -> Result<BranchJob, Error> {
+        let project = project.into();
+        let clock = WaitClock::start(&config);
+        loop {
+            if let Some(job) = find_branch(self.branches(project.clone()).await?, branch) {
+                return Ok(job);
+            }
+            tokio::time::sleep(clock.next_delay(&format!("branch `{branch}` to appear"))?).await;
+        }
+    }
+}
+
+/// Multibranch projects and organization folders (Branch API plugin).
+#[derive(Clone)]
+#[cfg(feature = "blocking")]
+pub struct BlockingMultibranchService {
+    client: crate::BlockingClient,
+}
+
+#[cfg(feature = "blocking")]
+impl BlockingMultibranchService {
+    pub(crate) fn new(client: crate::BlockingClient) -> Self {
+        Self { client }
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl BlockingMultibranchService {
+    /// `POST /job/<name>/build?delay=0` (branch indexing, or organization scan).
+    pub fn scan(&self, project: impl Into<JobPath>) -> Result<(), Error> {
+        self.client.send_unit(scan_request(&project.into()))
+    }
+
+    /// `GET /job/<name>/indexing/consoleText`
+    pub fn indexing_log(&self, project: impl Into<JobPath>) -> Result<String, Error> {
+        let segments = project_segments(&project.into(), ["indexing", "consoleText"]);
+        self.client.send_text(Request::get(segments))
+    }
+
+    /// `GET /job/<name>/computation/consoleText` (organization folder scan log)
+    pub fn organization_scan_log(&self, folder: impl Into<JobPath>) -> Result<String, Error> {
+        let segments = project_segments(&folder.into(), ["computation", "consoleText"]);
+        self.client.send_text(Request::get(segments))
+    }
+
+    /// `GET /job/<name>/api/json` with branches, pull requests and tags and their last build.
+    pub fn branches(&self, project: impl Into<JobPath>) -> Result<Vec<BranchJob>, Error> {
+        let listing: BranchListing = self.client.send_json(branches_request(&project.into()))?;
+        Ok(listing.into_branches())
+    }
+
+    /// Poll [`Self::branches`] until `branch` shows up, e.g. after [`Self::scan`].
+    pub fn wait_for_branch(
+        &self,
+        project: impl Into<JobPath>,
+        branch: &str,
+        config: WaitConfig,
+    ) -> Result<BranchJob, Error> {
+        let project = project.into();
+        let clock = WaitClock::start(&config);
+        loop {
+            if let Some(job) = find_branch(self.branches(project.clone())?, branch) {
+                return Ok(job);
+            }
+            std::thread::sleep(clock.next_delay(&format!("branch `{branch}` to appear"))?);
+        }
+    }
+}
@@ -0,0 +1,364 @@
+use super::jobs::job_segments;
+use crate::transport::request::Request;
+use crate::util::html;
+use crate::{
+    BuildNumber, Error, FlowNodeId, InputId, JobPath, PendingInputAction, PipelineNodeLog,
+    PipelineRun, PipelineStage, ReplayScripts,
+};
+use http::{Method, StatusCode};
+use serde_json::{Map, Value, json};
+
+fn run_segments<const N: usize>(
+    job: &JobPath,
+    build: &BuildNumber,
+    tail: [&str; N],
+) -> Vec<String> {
+    let mut segments = job_segments(job);
+    segments.push(build.as_str().to_owned());
+    segments.extend(tail.map(str::to_owned));
+    segments
+}
+
+fn input_segments(
+    job: &JobPath,
+    build: &BuildNumber,
+    input_id: &InputId,
+    action: &str,
+) -> Vec<String> {
+    run_segments(job, build, ["input", input_id.as_str(), action])
+}
+
+/// `proceedEmpty` when there is nothing to submit, otherwise `submit` with the `json` form
+/// field Jenkins' input step expects (`proceed` must be present or the input is aborted).
+fn proceed_request<I, K, V>(
+    job: &JobPath,
+    build: &BuildNumber,
+    input_id: &InputId,
+    parameters: I,
+) -> Request
+where
+    I: IntoIterator<Item = (K, V)>,
+    K: Into<String>,
+    V: Into<String>,
+{
+    let parameters: Vec<_> = parameters
+        .into_iter()
+        .map(|(name, value)| json!({ "name": name.into(), "value": value.into() }))
+        .collect();
+    if parameters.is_empty() {
+        return Request::post(input_segments(job, build, input_id, "proceedEmpty"));
+    }
+
+    let payload = json!({ "parameter": parameters }).to_string();
+    Request::post(input_segments(job, build, input_id, "submit"))
+        .form_pairs([("json", payload.as_str()), ("proceed", "Proceed")])
+}
+
+/// Read the script editors of the Replay page; `mainScript` is always present for a
+/// replayable run.
+fn parse_replay_page(segments: &[String], page: &str) -> Result<ReplayScripts, Error> {
+    let mut main_script = None;
+    let mut loaded_scripts = std::collections::BTreeMap::new();
+    for (name, script) in html::form_textareas(page) {
+        if name == "mainScript" {
+            main_script = Some(script);
+        } else {
+            loaded_scripts.insert(name, script);
+        }
+    }
+
+    let main_script = main_script.ok_or_else(|| Error::Decode {
+        status: StatusCode::OK,
+        method: Method::GET,
+        path: format!("/{}", segments.join("/")).into_boxed_str(),
+        request_id: None,
+        body_snippet: None,
+        source: "replay page has no `mainScript` editor (not a Pipeline run?)".into(),
+    })?;
+    Ok(ReplayScripts {
+        main_script,
+        loaded_scripts,
+    })
+}
+
+/// `POST /replay/run` with the Stapler `json` form field Jenkins' ReplayAction reads.
+fn replay_run_request(job: &JobPath, build: &BuildNumber, scripts: &ReplayScripts) -> Request {
+    let mut form = Map::new();
+    form.insert(
+        "mainScript".to_owned(),
+        Value::String(scripts.main_script.clone()),
+    );
+    for (name, script) in &scripts.loaded_scripts {
+        form.insert(name.clone(), Value::String(script.clone()));
+    }
+    let payload = Value::Object(form).to_string();
+    Request::post(run_segments(job, build, ["replay", "run"]))
+        .form_pairs([("json", payload.as_str())])
+}
+
+fn node_segments(
+    job: &JobPath,
+    build: &BuildNumber,
+    node: &FlowNodeId,
+    action: &str,
+) -> Vec<String> {
+    run_segments(
+        job,
+        build,
+        ["execution", "node", node.as_str(), "wfapi", action],
+    )
+}
+
+/// Pipeline stage view APIs (Workflow REST API, `wfapi`).
+#[derive(Clone)]
+#[cfg(feature = "async")]
+pub struct PipelineService {
+    client: crate::Client,
+}
+
+#[cfg(feature = "async")]
+impl PipelineService {
+    pub(crate) fn new(client: crate::Client) -> Self {
+        Self { client }
+    }
+}
+
+#[cfg(feature = "async")]
+impl PipelineService {
+    /// `GET /job/<name>/wfapi/runs`
+    pub async fn runs(&self, job: impl Into<JobPath>) -> Result<Vec<PipelineRun>, Error> {
+        let mut segments = job_segments(&job.into());
+        segments.extend(["wfapi", "runs"].map(str::to_owned));
+        self.client.send_json(Request::get(segments)).await
+    }
+
+    /// `GET /job/<name>/<build>/wfapi/describe`
+    pub async fn describe(
+        &self,
+        job: impl Into<JobPath>,
+        build: impl Into<BuildNumber>,
+    ) -> Result<PipelineRun, Error> {
+        let segments = run_segments(&job.into(), &build.into(), ["wfapi", "describe"]);
+        self.client.send_json(Request::get(segments)).await
+    }
+
+    /// `GET /job/<name>/<build>/execution/node/<id>/wfapi/describe`
+    pub async fn describe_node(
+        &self,
+        job: impl Into<JobPath>,
+        build: impl Into<BuildNumber>,
+        node: impl Into<FlowNodeId>,
+    ) -> Result<PipelineStage, Error> {
+        let segments = node_segments(&job.into(), &build.into(), &node.into(), "describe");
+        self.client.send_json(Request::get(segments)).await
+    }
+
+    /// `GET /job/<name>/<build>/execution/node/<id>/wfapi/log`
+    pub async fn node_log(
+        &self,
+        job: impl Into<JobPath>,
+        build: impl Into<BuildNumber>,
+        node: impl Into<FlowNodeId>,
+    ) -> Result<PipelineNodeLog, Error> {
+        let segments = node_segments(&job.into(), &build.into(), &node.into(), "log");
+        self.client.send_json(Request::get(segments)).await
+    }
+
+    /// `GET /job/<name>/<build>/wfapi/pendingInputActions`
+    pub async fn pending_inputs(
+        &self,
+        job: impl Into<JobPath>,
+        build: impl Into<BuildNumber>,
+    ) -> Result<Vec<PendingInputAction>, Error> {
+        let segments = run_segments(&job.into(), &build.into(), ["wfapi", "pendingInputActions"]);
+        self.client.send_json(Request::get(segments)).await
+    }
+
+    /// `POST /job/<name>/<build>/input/<id>/proceedEmpty`, or `/submit` when `parameters`
+    /// is non-empty.
+    pub async fn proceed_input<I, K, V>(
+        &self,
+        job: impl Into<JobPath>,
+        build: impl Into<BuildNumber>,
+        input_id: impl Into<InputId>,
+        parameters: I,
+    ) -> Result<(), Error>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        let req = proceed_request(&job.into(), &build.into(), &input_id.into(), parameters);
+        self.client.send_unit(req).await
+    }
+
+    /// `POST /job/<name>/<build>/input/<id>/abort`
+    pub async fn abort_input(
+        &self,
+        job: impl Into<JobPath>,
+        build: impl Into<BuildNumber>,
+        input_id: impl Into<InputId>,
+    ) -> Result<(), Error> {
+        let segments = input_segments(&job.into(), &build.into(), &input_id.into(), "abort");
+        self.client.send_unit(Request::post(segments)).await
+    }
+
+    /// `GET /job/<name>/<build>/replay/` (scraped; Jenkins has no JSON API for it)
+    pub async fn replay_scripts(
+        &self,
+        job: impl Into<JobPath>,
+        build: impl Into<BuildNumber>,
+    ) -> Result<ReplayScripts, Error> {
+        let segments = run_segments(&job.into(), &build.into(), ["replay", ""]);
+        let page = self
+            .client
+            .send_text(Request::get(segments.clone()))
+            .await?;
+        parse_replay_page(&segments, &page)
+    }
+
+    /// Replay a run: read its scripts with [`Self::replay_scripts`], let `edit` change them,
+    /// then `POST /job/<name>/<build>/replay/run`.
+    pub async fn replay_build<F>(
+        &self,
+        job: impl Into<JobPath>,
+        build: impl Into<BuildNumber>,
+        edit: F,
+    ) -> Result<(), Error>
+    where
+        F: FnOnce(&mut ReplayScripts),
+    {
+        let job = job.into();
+        let build = build.into();
+        let mut scripts = self.replay_scripts(job.clone(), build.clone()).await?;
+        edit(&mut scripts);
+        self.client
+            .send_unit(replay_run_request(&job, &build, &scripts))
+            .await
+    }
+}
+
+/// Pipeline stage view APIs (Workflow REST API, `wfapi`) (blocking).
+#[derive(Clone)]
+#[cfg(feature = "blocking")]
+pub struct BlockingPipelineService {
+    client: crate::BlockingClient,
+}
+
+#[cfg(feature = "blocking")]
+impl BlockingPipelineService {
+    pub(crate) fn new(client: crate::BlockingClient) -> Self {
+        Self { client }
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl BlockingPipelineService {
+    /// `GET /job/<name>/wfapi/runs`
+    pub fn runs(&self, job: impl Into<JobPath>) -> Result<Vec<PipelineRun>, Error> {
+        let mut segments = job_segments(&job.into());
+        segments.extend(["wfapi", "runs"].map(str::to_owned));
+        self.client.send_json(Request::get(segments))
+    }
+
+    /// `GET /job/<name>/<build>/wfapi/describe`
+    pub fn describe(
+        &self,
+        job: impl Into<JobPath>,
+        build: impl Into<BuildNumber>,
+    ) -> Result<PipelineRun, Error> {
+        let segments = run_segments(&job.into(), &build.into(), ["wfapi", "describe"]);
+        self.client.send_json(Request::get(segments))
+    }
+
+    /// `GET /job/<name>/<build>/execution/node/<id>/wfapi/describe`
+    pub fn describe_node(
+        &self,
+        job: impl Into<JobPath>,
+        build: impl Into<BuildNumber>,
+        node: impl Into<FlowNodeId>,
+    ) -> Result<PipelineStage, Error> {
+        let segments = node_segments(&job.into(), &build.into(), &node.into(), "describe");
+        self.client.send_json(Request::get(segments))
+    }
+
+    /// `GET /job/<name>/<build>/execution/node/<id>/wfapi/log`
+    pub fn node_log(
+        &self,
+        job: impl Into<JobPath>,
+        build: impl Into<BuildNumber>,
+        node: impl Into<FlowNodeId>,
+    ) -> Result<PipelineNodeLog, Error> {
+        let segments = node_segments(&job.into(), &build.into(), &node.into(), "log");
+        self.client.send_json(Request::get(segments))
+    }
+
+    /// `GET /job/<name>/<build>/wfapi/pendingInputActions`
+    pub fn pending_inputs(
+        &self,
+        job: impl Into<JobPath>,
+        build: impl Into<BuildNumber>,
+    ) -> Result<Vec<PendingInputAction>, Error> {
+        let segments = run_segments(&job.into(), &build.into(), ["wfapi", "pendingInputActions"]);
+        self.client.send_json(Request::get(segments))
+    }
+
+    /// `POST /job/<name>/<build>/input/<id>/proceedEmpty`, or `/submit` when `parameters`
+    /// is non-empty.
+    pub fn proceed_input<I, K, V>(
+        &self,
+        job: impl Into<JobPath>,
+        build: impl Into<BuildNumber>,
+        input_id: impl Into<InputId>,
+        parameters: I,
+    ) -> Result<(), Error>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        let req = proceed_request(&job.into(), &build.into(), &input_id.into(), parameters);
+        self.client.send_unit(req)
+    }
+
+    /// `POST /job/<name>/<build>/input/<id>/abort`
+    pub fn abort_input(
+        &self,
+        job: impl Into<JobPath>,
+        build: impl Into<BuildNumber>,
+        input_id: impl Into<InputId>,
+    ) -> Result<(), Error> {
+        let segments = input_segments(&job.into(), &build.into(), &input_id.into(), "abort");
+        self.client.send_unit(Request::post(segments))
+    }
+
+    /// `GET /job/<name>/<build>/replay/` (scraped; Jenkins has no JSON API for it)
+    pub fn replay_scripts(
+        &self,
+        job: impl Into<JobPath>,
+        build: impl Into<BuildNumber>,
+    ) -> Result<ReplayScripts, Error> {
+        let segments = run_segments(&job.into(), &build.into(), ["replay", ""]);
+        let page = self.client.send_text(Request::get(segments.clone()))?;
+        parse_replay_page(&segments, &page)
+    }
+
+    /// Replay a run: read its scripts with [`Self::replay_scripts`], let `edit` change them,
+    /// then `POST /job/<name>/<build>/replay/run`.
+    pub fn replay_build<F>(
+        &self,
+        job: impl Into<JobPath>,
+        build: impl Into<BuildNumber>,
+        edit: F,
+    ) -> Result<(), Error>
+    where
+        F: FnOnce(&mut ReplayScripts),
+    {
+        let job = job.into();
+        let build = build.into();
+        let mut scripts = self.replay_scripts(job.clone(), build.clone())?;
+        edit(&mut scripts);
+        self.client
+            .send_unit(replay_run_request(&job, &build, &scripts))
+    }
+}
@@ -0,0 +1,370 @@
+use super::jobs::WaitClock;
+use crate::transport::request::{Request, RequestBody};
+use crate::util::xml;
+use crate::{
+    Error, PluginAction, PluginCatalog, PluginInfo, PluginManifest, PluginPlan, UpdateCenterStatus,
+    WaitConfig,
+};
+use http::HeaderValue;
+use serde::Deserialize;
+
+const PLUGINS_TREE: &str = "plugins[shortName,longName,version,enabled,active,hasUpdate,\
+deleted,bundled,pinned,url,dependencies[shortName,version,optional]]";
+
+const UPDATE_CENTER_TREE: &str =
+    "jobs[id,type,name,errorMessage,status[type,success]],restartRequiredForCompletion";
+
+#[derive(Deserialize)]
+struct PluginList {
+    #[serde(default)]
+    plugins: Vec<PluginInfo>,
+}
+
+fn list_request() -> Request {
+    Request::get(["pluginManager", "api", "json"]).query_pair("tree", PLUGINS_TREE)
+}
+
+fn update_center_request() -> Request {
+    Request::get(["updateCenter", "api", "json"]).query_pair("tree", UPDATE_CENTER_TREE)
+}
+
+fn plugin_request(name: &str, action: &str) -> Request {
+    Request::post(["pluginManager", "plugin", name, action])
+}
+
+/// `installNecessaryPlugins` document; entries without `@version` get `@latest`.
+fn install_request<I, S>(plugins: I) -> Option<Request>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let mut body = String::from("<jenkins>\n");
+    let mut any = false;
+    for plugin in plugins {
+        let plugin = plugin.as_ref();
+        let plugin = if plugin.contains('@') {
+            plugin.to_owned()
+        } else {
+            format!("{plugin}@latest")
+        };
+        body.push_str(&format!(
+            "  <install plugin=\"{}\"/>\n",
+            xml::escape(&plugin)
+        ));
+        any = true;
+    }
+    body.push_str("</jenkins>\n");
+    any.then(|| {
+        Request::post(["pluginManager", "installNecessaryPlugins"]).body(
+            RequestBody::bytes_with_content_type(
+                body.into_bytes(),
+                HeaderValue::from_static("text/xml"),
+            ),
+        )
+    })
+}
+
+/// `name@version` entries for the plan's installs and upgrades.
+///
+/// `installNecessaryPlugins` treats the version as a minimum and deploys the update site's
+/// current release, so plans are refused up front when an install or upgrade targets
+/// anything but the `catalog` release, and when they contain downgrades.
+fn plan_installs(plan: &PluginPlan, catalog: &PluginCatalog) -> Result<Vec<String>, Error> {
+    let refuse = |action: &PluginAction, reason: String| Error::InvalidConfig {
+        message: format!("cannot apply `{action}`: {reason}").into(),
+        source: None,
+    };
+    let mut installs = Vec::new();
+    for action in &plan.actions {
+        match action {
+            PluginAction::Install { name, version: to }
+            | PluginAction::Upgrade { name, to, .. } => {
+                match catalog.get(name).map(|plugin| plugin.version.as_str()) {
+                    Some(latest) if latest == to => installs.push(format!("{name}@{to}")),
+                    Some(latest) => {
+                        return Err(refuse(
+                            action,
+                            format!("the update center would install {latest} instead"),
+                        ));
+                    }
+                    None => {
+                        return Err(refuse(
+                            action,
+                            "the plugin is not in the update center".to_owned(),
+                        ));
+                    }
+                }
+            }
+            PluginAction::Downgrade { .. } => {
+                return Err(refuse(
+                    action,
+                    "Jenkins only installs newer versions".to_owned(),
+                ));
+            }
+            PluginAction::Remove { .. } => {}
+        }
+    }
+    Ok(installs)
+}
+
+fn plan_removals(plan: &PluginPlan) -> impl Iterator<Item = &str> {
+    plan.actions
+        .iter()
+        .filter(|action| matches!(action, PluginAction::Remove { .. }))
+        .map(PluginAction::name)
+}
+
+/// Plugin manager and update center.
+#[derive(Clone)]
+#[cfg(feature = "async")]
+pub struct PluginsService {
+    client: crate::Client,
+}
+
+#[cfg(feature = "async")]
+impl PluginsService {
+    pub(crate) fn new(client: crate::Client) -> Self {
+        Self { client }
+    }
+}
+
+#[cfg(feature = "async")]
+impl PluginsService {
+    /// `GET /pluginManager/api/json` with versions, state and dependencies.
+    pub async fn list(&self) -> Result<Vec<PluginInfo>, Error> {
+        let list: PluginList = self.client.send_json(list_request()).await?;
+        Ok(list.plugins)
+    }
+
+    /// Installed plugin `short_name`, if any.
+    pub async fn get(&self, short_name: &str) -> Result<Option<PluginInfo>, Error> {
+        let plugins = self.list().await?;
+        Ok(plugins
+            .into_iter()
+            .find(|plugin| plugin.short_name == short_name))
+    }
+
+    /// `POST /pluginManager/installNecessaryPlugins` for `name` or `name@version` entries.
+    ///
+    /// Installation is asynchronous; follow it with [`Self::wait_for_installations`].
+    pub async fn install<I, S>(&self, plugins: I) -> Result<(), Error>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        match install_request(plugins) {
+            Some(req) => self.client.send_unit(req).await,
+            None => Ok(()),
+        }
+    }
+
+    /// `POST /pluginManager/plugin/<name>/doUninstall` (effective after a restart).
+    pub async fn uninstall(&self, short_name: &str) -> Result<(), Error> {
+        self.client
+            .send_unit(plugin_request(short_name, "doUninstall"))
+            .await
+    }
+
+    /// `POST /pluginManager/plugin/<name>/makeDisabled` (effective after a restart).
+    pub async fn disable(&self, short_name: &str) -> Result<(), Error> {
+        self.client
+            .send_unit(plugin_request(short_name, "makeDisabled"))
+            .await
+    }
+
+    /// `POST /pluginManager/plugin/<name>/makeEnabled` (effective after a restart).
+    pub async fn enable(&self, short_name: &str) -> Result<(), Error> {
+        self.client
+            .send_unit(plugin_request(short_name, "makeEnabled"))
+            .await
+    }
+
+    /// `POST /pluginManager/checkUpdatesServer` (refresh update site metadata).
+    pub async fn check_updates(&self) -> Result<(), Error> {
+        self.client
+            .send_unit(Request::post(["pluginManager", "checkUpdatesServer"]))
+            .await
+    }
+
+    /// `GET /updateCenter/api/json` with jobs and the restart flag.
+    pub async fn update_center(&self) -> Result<UpdateCenterStatus, Error> {
+        self.client.send_json(update_center_request()).await
+    }
+
+    /// Whether installed, updated or removed plugins wait for a restart.
+    pub async fn restart_required(&self) -> Result<bool, Error> {
+        Ok(self.update_center().await?.restart_required())
+    }
+
+    /// Poll [`Self::update_center`] until no job is pending or installing.
+    ///
+    /// Failed installations do not fail the wait; check
+    /// [`UpdateCenterStatus::failed_installations`] on the result.
+    pub async fn wait_for_installations(
+        &self,
+        config: WaitConfig,
+    ) -> Result<UpdateCenterStatus, Error> {
+        let clock = WaitClock::start(&config);
+        loop {
+            let status = self.update_center().await?;
+            if !status.is_busy() {
+                return Ok(status);
+            }
+            tokio::time::sleep(clock.next_delay("plugin installations to finish")?).await;
+        }
+    }
+
+    /// Diff installed plugins against `manifest`; print the result for a dry run.
+    ///
+    /// See [`PluginPlan::compute`] for the resolution rules.
+    pub async fn plan(
+        &self,
+        manifest: &PluginManifest,
+        catalog: &PluginCatalog,
+    ) -> Result<PluginPlan, Error> {
+        PluginPlan::compute(manifest, &self.list().await?, catalog)
+    }
+
+    /// Execute `plan` (installs and upgrades, then removals) and wait for the update center.
+    ///
+    /// Jenkins only installs the update site's current release, so plans are refused up front
+    /// when an install or upgrade targets another version than `catalog` publishes, or when
+    /// they contain downgrades. Most changes take effect after a restart; check
+    /// [`UpdateCenterStatus::restart_required`] on the result.
+    pub async fn apply(
+        &self,
+        plan: &PluginPlan,
+        catalog: &PluginCatalog,
+        config: WaitConfig,
+    ) -> Result<UpdateCenterStatus, Error> {
+        self.install(plan_installs(plan, catalog)?).await?;
+        for name in plan_removals(plan) {
+            self.uninstall(name).await?;
+        }
+        self.wait_for_installations(config).await
+    }
+}
+
+/// Plugin manager and update center.
+#[derive(Clone)]
+#[cfg(feature = "blocking")]
+pub struct BlockingPluginsService {
+    client: crate::BlockingClient,
+}
+
+#[cfg(feature = "blocking")]
+impl BlockingPluginsService {
+    pub(crate) fn new(client: crate::BlockingClient) -> Self {
+        Self { client }
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl BlockingPluginsService {
+    /// `GET /pluginManager/api/json` with versions, state and dependencies.
+    pub fn list(&self) -> Result<Vec<PluginInfo>, Error> {
+        let list: PluginList = self.client.send_json(list_request())?;
+        Ok(list.plugins)
+    }
+
+    /// Installed plugin `short_name`, if any.
+    pub fn get(&self, short_name: &str) -> Result<Option<PluginInfo>, Error> {
+        Ok(self
+            .list()?
+            .into_iter()
+            .find(|plugin| plugin.short_name == short_name))
+    }
+
+    /// `POST /pluginManager/installNecessaryPlugins` for `name` or `name@version` entries.
+    ///
+    /// Installation is asynchronous; follow it with [`Self::wait_for_installations`].
+    pub fn install<I, S>(&self, plugins: I) -> Result<(), Error>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        match install_request(plugins) {
+            Some(req) => self.client.send_unit(req),
+            None => Ok(()),
+        }
+    }
+
+    /// `POST /pluginManager/plugin/<name>/doUninstall` (effective after a restart).
+    pub fn uninstall(&self, short_name: &str) -> Result<(), Error> {
+        self.client
+            .send_unit(plugin_request(short_name, "doUninstall"))
+    }
+
+    /// `POST /pluginManager/plugin/<name>/makeDisabled` (effective after a restart).
+    pub fn disable(&self, short_name: &str) -> Result<(), Error> {
+        self.client
+            .send_unit(plugin_request(short_name, "makeDisabled"))
+    }
+
+    /// `POST /pluginManager/plugin/<name>/makeEnabled` (effective after a restart).
+    pub fn enable(&self, short_name: &str) -> Result<(), Error> {
+        self.client
+            .send_unit(plugin_request(short_name, "makeEnabled"))
+    }
+
+    /// `POST /pluginManager/checkUpdatesServer` (refresh update site metadata).
+    pub fn check_updates(&self) -> Result<(), Error> {
+        self.client
+            .send_unit(Request::post(["pluginManager", "checkUpdatesServer"]))
+    }
+
+    /// `GET /updateCenter/api/json` with jobs and the restart flag.
+    pub fn update_center(&self) -> Result<UpdateCenterStatus, Error> {
+        self.client.send_json(update_center_request())
+    }
+
+    /// Whether installed, updated or removed plugins wait for a restart.
+    pub fn restart_required(&self) -> Result<bool, Error> {
+        Ok(self.update_center()?.restart_required())
+    }
+
+    /// Poll [`Self::update_center`] until no job is pending or installing.
+    ///
+    /// Failed installations do not fail the wait; check
+    /// [`UpdateCenterStatus::failed_installations`] on the result.
+    pub fn wait_for_installations(&self, config: WaitConfig) -> Result<UpdateCenterStatus, Error> {
+        let clock = WaitClock::start(&config);
+        loop {
+            let status = self.update_center()?;
+            if !status.is_busy() {
+                return Ok(status);
+            }
+            std::thread::sleep(clock.next_delay("plugin installations to finish")?);
+        }
+    }
+
+    /// Diff installed plugins against `manifest`; print the result for a dry run.
+    ///
+    /// See [`PluginPlan::compute`] for the resolution rules.
+    pub fn plan(
+        &self,
+        manifest: &PluginManifest,
+        catalog: &PluginCatalog,
+    ) -> Result<PluginPlan, Error> {
+        PluginPlan::compute(manifest, &self.list()?, catalog)
+    }
+
+    /// Execute `plan` (installs and upgrades, then removals) and wait for the update center.
+    ///
+    /// Jenkins only installs the update site's current release, so plans are refused up front
+    /// when an install or upgrade targets another version than `catalog` publishes, or when
+    /// they contain downgrades. Most changes take effect after a restart; check
+    /// [`UpdateCenterStatus::restart_required`] on the result.
+    pub fn apply(
+        &self,
+        plan: &PluginPlan,
+        catalog: &PluginCatalog,
+        config: WaitConfig,
+    ) -> Result<UpdateCenterStatus, Error> {
+        self.install(plan_installs(plan, catalog)?)?;
+        for name in plan_removals(plan) {
+            self.uninstall(name)?;
+        }
+        self.wait_for_installations(config)
+    }
+}
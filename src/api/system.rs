@@ -2,6 +2,14 @@ use crate::transport::request::{Request, RequestBody};
 use crate::{Crumb, Error, SystemPayload, SystemRoot, WhoAmI};
 use http::HeaderValue;
 
+/// Permission guarding every script console endpoint.
+pub(crate) const RUN_SCRIPTS: &str = "Overall/RunScripts";
+
+/// `POST <segments>` with the `script` form field.
+pub(crate) fn script_request<const N: usize>(segments: [&str; N], script: String) -> Request {
+    Request::post(segments).form_pairs([("script", script)])
+}
+
 /// Jenkins system-level (core) APIs.
 #[derive(Clone)]
 #[cfg(feature = "async")]
@@ -117,6 +125,17 @@ impl SystemService {
     pub async fn exit(&self) -> Result<(), Error> {
         self.client.send_unit(Request::post(["exit"])).await
     }
+
+    /// `POST /scriptText`: run Groovy on the controller and return what it printed.
+    ///
+    /// Build scripts with caller input through [`crate::GroovyScript`]. Script exceptions
+    /// are part of the output; a `403` becomes [`Error::MissingPermission`].
+    pub async fn run_script(&self, script: impl Into<String>) -> Result<String, Error> {
+        self.client
+            .send_text(script_request(["scriptText"], script.into()))
+            .await
+            .map_err(|err| err.require_permission(RUN_SCRIPTS))
+    }
 }
 
 /// Jenkins system-level (core) APIs (blocking).
@@ -224,4 +243,14 @@ impl BlockingSystemService {
     pub fn exit(&self) -> Result<(), Error> {
         self.client.send_unit(Request::post(["exit"]))
     }
+
+    /// `POST /scriptText`: run Groovy on the controller and return what it printed.
+    ///
+    /// Build scripts with caller input through [`crate::GroovyScript`]. Script exceptions
+    /// are part of the output; a `403` becomes [`Error::MissingPermission`].
+    pub fn run_script(&self, script: impl Into<String>) -> Result<String, Error> {
+        self.client
+            .send_text(script_request(["scriptText"], script.into()))
+            .map_err(|err| err.require_permission(RUN_SCRIPTS))
+    }
 }
@@ -4,8 +4,13 @@ use crate::{
     Auth, BodySnippetConfig, Error, HttpError, RequestHookContext, TlsRootStore, api,
     transport::{
         TransportBody, TransportRequest,
-        blocking_transport::{DynBlockingTransport, ReqxBlocking},
-        middleware::{CrumbBlocking, HookBlocking, RetryBlocking, RetryConfig},
+        blocking_transport::{
+            BlockingStreamResponse, BlockingTransport, DynBlockingTransport, ReqxBlocking,
+        },
+        middleware::{
+            CassetteBlocking, CassetteConfig, CrumbBlocking, HookBlocking, RetryBlocking,
+            RetryConfig, cassette::Cassette,
+        },
         request::{Request, Response},
     },
     util::{
@@ -14,19 +19,22 @@ use crate::{
         url::{endpoint_url, normalize_base_url, sanitize_url_for_error},
     },
 };
-use http::HeaderMap;
+use http::{HeaderMap, StatusCode};
 use serde::de::DeserializeOwned;
 use std::{sync::Arc, time::Duration};
 use url::Url;
 
 #[cfg(feature = "tracing")]
-use tracing::field;
+use super::span;
 
 #[derive(Clone, Copy, Debug)]
 struct CrumbConfig {
     ttl: Duration,
 }
 
+/// Upper bound on how much of an error body a streaming request reads for diagnostics.
+const ERROR_BODY_LIMIT: usize = 64 * 1024;
+
 const DEFAULT_USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
 
 /// Configures and constructs [`BlockingClient`].
@@ -43,6 +51,8 @@ pub struct BlockingClientBuilder {
     default_headers: HeaderMap,
     body_snippet: BodySnippetConfig,
     request_hook: Option<crate::RequestHook>,
+    transport: Option<DynBlockingTransport>,
+    cassette: Option<CassetteConfig>,
 }
 
 impl BlockingClientBuilder {
@@ -61,6 +71,8 @@ impl BlockingClientBuilder {
             default_headers: HeaderMap::new(),
             body_snippet: BodySnippetConfig::default(),
             request_hook: None,
+            transport: None,
+            cassette: None,
         })
     }
 
@@ -150,17 +162,47 @@ impl BlockingClientBuilder {
         self
     }
 
+    /// Send requests through `transport` instead of the built-in `reqx` client.
+    ///
+    /// The hook, retry and crumb layers still wrap it. `user_agent`, `connect_timeout`,
+    /// `no_system_proxy` and `tls_root_store` only configure the built-in client.
+    pub fn transport(mut self, transport: impl BlockingTransport) -> Self {
+        self.transport = Some(Arc::new(transport));
+        self
+    }
+
+    /// Record traffic to a cassette file, or replay one without touching the network.
+    ///
+    /// The cassette sits right above the transport, so every retry attempt and crumb
+    /// fetch is its own interaction. The auth token and `config.secrets` are scrubbed
+    /// before anything is written; recordings are written by [`BlockingClient::save_cassette`].
+    pub fn cassette(mut self, config: CassetteConfig) -> Self {
+        self.cassette = Some(config);
+        self
+    }
+
     pub fn build(self) -> Result<BlockingClient, Error> {
         let base = self.base_url;
 
-        let mut transport: DynBlockingTransport = Arc::new(ReqxBlocking::try_new(
-            base.as_str(),
-            &self.user_agent,
-            self.timeout,
-            self.connect_timeout,
-            self.no_proxy,
-            self.tls_root_store,
-        )?);
+        let mut transport: DynBlockingTransport = match self.transport {
+            Some(transport) => transport,
+            None => Arc::new(ReqxBlocking::try_new(
+                base.as_str(),
+                &self.user_agent,
+                self.timeout,
+                self.connect_timeout,
+                self.no_proxy,
+                self.tls_root_store,
+            )?),
+        };
+
+        let cassette = match self.cassette {
+            Some(config) => Some(Arc::new(Cassette::open(config, &base, self.auth.clone())?)),
+            None => None,
+        };
+        if let Some(cassette) = &cassette {
+            transport = Arc::new(CassetteBlocking::new(transport, cassette.clone()));
+        }
 
         if let Some(hook) = self.request_hook {
             transport = Arc::new(HookBlocking::new(transport, hook));
@@ -190,6 +232,7 @@ impl BlockingClientBuilder {
                 default_headers: self.default_headers,
                 body_snippet: self.body_snippet,
                 transport,
+                cassette,
             }),
         })
     }
@@ -207,6 +250,7 @@ struct Inner {
     default_headers: HeaderMap,
     body_snippet: BodySnippetConfig,
     transport: DynBlockingTransport,
+    cassette: Option<Arc<Cassette>>,
 }
 
 impl BlockingClient {
@@ -218,6 +262,18 @@ impl BlockingClient {
         Self::builder(base)?.build()
     }
 
+    /// Write interactions recorded so far to the cassette file.
+    ///
+    /// Recordings are kept in memory and otherwise only written when the last clone of
+    /// the client is dropped, where write errors go unreported. No-op without a recording
+    /// cassette.
+    pub fn save_cassette(&self) -> Result<(), Error> {
+        match &self.inner.cassette {
+            Some(cassette) => cassette.save(),
+            None => Ok(()),
+        }
+    }
+
     #[must_use]
     pub fn system(&self) -> api::BlockingSystemService {
         api::BlockingSystemService::new(self.clone())
@@ -253,6 +309,26 @@ impl BlockingClient {
         api::BlockingPeopleService::new(self.clone())
     }
 
+    #[must_use]
+    pub fn pipeline(&self) -> api::BlockingPipelineService {
+        api::BlockingPipelineService::new(self.clone())
+    }
+
+    #[must_use]
+    pub fn multibranch(&self) -> api::BlockingMultibranchService {
+        api::BlockingMultibranchService::new(self.clone())
+    }
+
+    #[must_use]
+    pub fn plugins(&self) -> api::BlockingPluginsService {
+        api::BlockingPluginsService::new(self.clone())
+    }
+
+    #[must_use]
+    pub fn credentials(&self) -> api::BlockingCredentialsService {
+        api::BlockingCredentialsService::new(self.clone())
+    }
+
     pub(crate) fn send_json<T: DeserializeOwned + Send + 'static>(
         &self,
         req: Request,
@@ -296,53 +372,17 @@ impl BlockingClient {
         #[cfg(feature = "metrics")]
         let _inflight = crate::transport::metrics::InFlightGuard::new();
 
-        if req.body.is_some() && !req.form.is_empty() {
-            return Err(Error::InvalidConfig {
-                message: "request.body and request.form are mutually exclusive".into(),
-                source: None,
-            });
-        }
-
-        let url = endpoint_url(&self.inner.base, req.segments.iter().map(|s| s.as_str()))?;
-
-        let mut headers = self.inner.default_headers.clone();
-        if let Some(auth) = &self.inner.auth {
-            auth.apply(&mut headers)?;
-        }
-        headers.extend(req.headers.clone());
-
-        let body = req.body.clone().map(|body| TransportBody {
-            bytes: body.bytes,
-            content_type: body.content_type,
-        });
+        let request = self.transport_request(req)?;
+        let url = request.url.clone();
 
         #[cfg(any(feature = "tracing", feature = "metrics"))]
         let start = std::time::Instant::now();
         #[cfg(feature = "tracing")]
-        let span = tracing::info_span!(
-            "jenkins.request",
-            http.method = %req.method,
-            http.host = %self.inner.base.host_str().unwrap_or_default(),
-            http.path = %url.path(),
-            http.status = field::Empty,
-            request_id = field::Empty,
-            retries = field::Empty,
-            latency_ms = field::Empty,
-            error_kind = field::Empty,
-        );
+        let span = span::request_span(&req.method, &self.inner.base, &url);
         #[cfg(feature = "tracing")]
         let _enter = span.enter();
 
-        let timeout = req.timeout_override.unwrap_or(self.inner.timeout);
-        let resp = match self.inner.transport.send(TransportRequest {
-            method: req.method.clone(),
-            url: url.clone(),
-            headers,
-            query: req.query.clone(),
-            form: req.form.clone(),
-            body,
-            timeout,
-        }) {
+        let resp = match self.inner.transport.send(request) {
             Ok(resp) => resp,
             Err(err) => {
                 #[cfg(feature = "metrics")]
@@ -354,48 +394,16 @@ impl BlockingClient {
                     Some(err.kind()),
                 );
                 #[cfg(feature = "tracing")]
-                {
-                    span.record("error_kind", field::debug(err.kind()));
-                    span.record("latency_ms", start.elapsed().as_millis() as i64);
-                }
+                span::record_error(&span, &err, Some(start));
                 return Err(err);
             }
         };
 
-        let request_id = diagnostics::request_id(&resp.headers);
-
         #[cfg(feature = "tracing")]
-        {
-            span.record("http.status", resp.status.as_u16() as i64);
-            span.record("retries", resp.meta.retries as i64);
-            span.record("latency_ms", start.elapsed().as_millis() as i64);
-            if let Some(rid) = request_id.as_deref() {
-                span.record("request_id", field::display(rid));
-            }
-        }
+        span::record_response(&span, resp.status, resp.meta.retries, &resp.headers, start);
 
         if resp.status.is_client_error() || resp.status.is_server_error() {
-            let safe_url = sanitize_url_for_error(&url);
-            let message = diagnostics::extract_message(&resp.body)
-                .map(|msg| redact_text(msg.into(), self.inner.auth.as_ref()).into_boxed_str());
-            let http_error = HttpError {
-                status: resp.status,
-                method: req.method.clone(),
-                url: Box::new(safe_url),
-                message,
-                request_id,
-                body_snippet: diagnostics::body_snippet(
-                    &resp.body,
-                    self.inner.body_snippet,
-                    self.inner.auth.as_ref(),
-                ),
-            };
-
-            let retry_after = crate::transport::middleware::retry::parse_retry_after(
-                &resp.headers,
-                std::time::SystemTime::now(),
-            );
-            let err = Error::from_http(http_error, retry_after);
+            let err = self.http_error(req, &url, resp.status, &resp.headers, &resp.body);
 
             #[cfg(feature = "metrics")]
             crate::transport::metrics::record_outcome(
@@ -406,7 +414,7 @@ impl BlockingClient {
                 Some(err.kind()),
             );
             #[cfg(feature = "tracing")]
-            span.record("error_kind", field::debug(err.kind()));
+            span::record_error(&span, &err, None);
 
             return Err(err);
         }
@@ -428,4 +436,131 @@ impl BlockingClient {
 
         Ok(response)
     }
+
+    /// Send a request and hand back the response body unread.
+    ///
+    /// Error statuses are still turned into [`Error`]s; their body is read up to
+    /// [`ERROR_BODY_LIMIT`] bytes for diagnostics.
+    pub(crate) fn send_stream(&self, req: Request) -> Result<BlockingStreamResponse, Error> {
+        let request = self.transport_request(&req)?;
+        let url = request.url.clone();
+
+        #[cfg(any(feature = "tracing", feature = "metrics"))]
+        let start = std::time::Instant::now();
+        #[cfg(feature = "tracing")]
+        let span = span::request_span(&req.method, &self.inner.base, &url);
+        #[cfg(feature = "tracing")]
+        let _enter = span.enter();
+
+        let result = self.inner.transport.send_stream(request);
+
+        #[cfg(feature = "tracing")]
+        match &result {
+            Ok(resp) => {
+                span::record_response(&span, resp.status, resp.meta.retries, &resp.headers, start)
+            }
+            Err(err) => span::record_error(&span, err, Some(start)),
+        }
+
+        let result = match result {
+            Ok(mut resp) if resp.status.is_client_error() || resp.status.is_server_error() => {
+                let mut body = Vec::new();
+                for chunk in resp.body.by_ref() {
+                    let Ok(chunk) = chunk else { break };
+                    body.extend_from_slice(&chunk);
+                    if body.len() >= ERROR_BODY_LIMIT {
+                        break;
+                    }
+                }
+                body.truncate(ERROR_BODY_LIMIT);
+                let err = self.http_error(&req, &url, resp.status, &resp.headers, &body);
+                #[cfg(feature = "tracing")]
+                span::record_error(&span, &err, None);
+                Err(err)
+            }
+            other => other,
+        };
+
+        #[cfg(feature = "metrics")]
+        match &result {
+            Ok(resp) => crate::transport::metrics::record_outcome(
+                &req.method,
+                Some(resp.status),
+                start.elapsed(),
+                resp.meta.retries,
+                None,
+            ),
+            Err(err) => crate::transport::metrics::record_outcome(
+                &req.method,
+                err.status(),
+                start.elapsed(),
+                0,
+                Some(err.kind()),
+            ),
+        }
+
+        result
+    }
+
+    fn transport_request(&self, req: &Request) -> Result<TransportRequest, Error> {
+        if req.body.is_some() && !req.form.is_empty() {
+            return Err(Error::InvalidConfig {
+                message: "request.body and request.form are mutually exclusive".into(),
+                source: None,
+            });
+        }
+
+        let url = endpoint_url(&self.inner.base, req.segments.iter().map(|s| s.as_str()))?;
+
+        let mut headers = self.inner.default_headers.clone();
+        if let Some(auth) = &self.inner.auth {
+            auth.apply(&mut headers)?;
+        }
+        headers.extend(req.headers.clone());
+
+        let body = req.body.clone().map(|body| TransportBody {
+            bytes: body.bytes,
+            content_type: body.content_type,
+        });
+
+        Ok(TransportRequest {
+            method: req.method.clone(),
+            url,
+            headers,
+            query: req.query.clone(),
+            form: req.form.clone(),
+            body,
+            timeout: req.timeout_override.unwrap_or(self.inner.timeout),
+        })
+    }
+
+    fn http_error(
+        &self,
+        req: &Request,
+        url: &Url,
+        status: StatusCode,
+        headers: &HeaderMap,
+        body: &[u8],
+    ) -> Error {
+        let message = diagnostics::extract_message(body)
+            .map(|msg| redact_text(msg.into(), self.inner.auth.as_ref()).into_boxed_str());
+        let http_error = HttpError {
+            status,
+            method: req.method.clone(),
+            url: Box::new(sanitize_url_for_error(url)),
+            message,
+            request_id: diagnostics::request_id(headers),
+            body_snippet: diagnostics::body_snippet(
+                body,
+                self.inner.body_snippet,
+                self.inner.auth.as_ref(),
+            ),
+        };
+
+        let retry_after = crate::transport::middleware::retry::parse_retry_after(
+            headers,
+            std::time::SystemTime::now(),
+        );
+        Error::from_http(http_error, retry_after)
+    }
 }
@@ -4,6 +4,8 @@
 pub mod async_client;
 #[cfg(feature = "blocking")]
 pub mod blocking_client;
+#[cfg(feature = "tracing")]
+mod span;
 
 #[cfg(feature = "async")]
 pub use async_client::{Client, ClientBuilder};
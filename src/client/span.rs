@@ -0,0 +1,46 @@
+//! `jenkins.request` tracing span shared by buffered and streaming requests of both clients.
+
+use crate::{Error, util::diagnostics};
+use http::{HeaderMap, Method, StatusCode};
+use std::time::Instant;
+use tracing::{Span, field};
+use url::Url;
+
+/// Span for one request; fields are filled in by [`record_response`] and [`record_error`].
+pub(crate) fn request_span(method: &Method, base: &Url, url: &Url) -> Span {
+    tracing::info_span!(
+        "jenkins.request",
+        http.method = %method,
+        http.host = %base.host_str().unwrap_or_default(),
+        http.path = %url.path(),
+        http.status = field::Empty,
+        request_id = field::Empty,
+        retries = field::Empty,
+        latency_ms = field::Empty,
+        error_kind = field::Empty,
+    )
+}
+
+/// Status, retries, latency and request id of a response (successful or not).
+pub(crate) fn record_response(
+    span: &Span,
+    status: StatusCode,
+    retries: usize,
+    headers: &HeaderMap,
+    start: Instant,
+) {
+    span.record("http.status", status.as_u16() as i64);
+    span.record("retries", retries as i64);
+    span.record("latency_ms", start.elapsed().as_millis() as i64);
+    if let Some(rid) = diagnostics::request_id(headers).as_deref() {
+        span.record("request_id", field::display(rid));
+    }
+}
+
+/// Error kind, plus the latency when no response was recorded.
+pub(crate) fn record_error(span: &Span, err: &Error, start: Option<Instant>) {
+    span.record("error_kind", field::debug(err.kind()));
+    if let Some(start) = start {
+        span.record("latency_ms", start.elapsed().as_millis() as i64);
+    }
+}
@@ -31,6 +31,9 @@ pub enum ErrorKind {
     Transport,
     Decode,
     InvalidConfig,
+    WaitTimeout,
+    Io,
+    ChecksumMismatch,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -66,6 +69,13 @@ pub enum Error {
     #[error("{0}")]
     Auth(HttpError),
 
+    /// `403` from an endpoint gated by a single, known permission (e.g. `Overall/RunScripts`).
+    #[error("Missing Jenkins permission {permission}: {error}")]
+    MissingPermission {
+        permission: Box<str>,
+        error: HttpError,
+    },
+
     #[error("{0}")]
     NotFound(HttpError),
 
@@ -101,6 +111,26 @@ pub enum Error {
         source: Box<dyn StdError + Send + Sync>,
     },
 
+    #[error("Timed out after {elapsed:?} waiting for {waiting_for}")]
+    WaitTimeout {
+        waiting_for: Box<str>,
+        elapsed: Duration,
+    },
+
+    #[error("I/O error while {context}: {source}")]
+    Io {
+        context: Box<str>,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("Checksum mismatch for {path}: expected {expected}, got {actual}")]
+    ChecksumMismatch {
+        path: Box<str>,
+        expected: Box<str>,
+        actual: Box<str>,
+    },
+
     #[error("Invalid configuration: {message}")]
     InvalidConfig {
         message: Box<str>,
@@ -113,7 +143,7 @@ impl Error {
     #[must_use]
     pub fn kind(&self) -> ErrorKind {
         match self {
-            Self::Auth(_) => ErrorKind::Auth,
+            Self::Auth(_) | Self::MissingPermission { .. } => ErrorKind::Auth,
             Self::NotFound(_) => ErrorKind::NotFound,
             Self::Conflict(_) => ErrorKind::Conflict,
             Self::RateLimited { .. } => ErrorKind::RateLimited,
@@ -121,6 +151,9 @@ impl Error {
             Self::Transport { .. } => ErrorKind::Transport,
             Self::Decode { .. } => ErrorKind::Decode,
             Self::InvalidConfig { .. } => ErrorKind::InvalidConfig,
+            Self::WaitTimeout { .. } => ErrorKind::WaitTimeout,
+            Self::Io { .. } => ErrorKind::Io,
+            Self::ChecksumMismatch { .. } => ErrorKind::ChecksumMismatch,
         }
     }
 
@@ -128,9 +161,15 @@ impl Error {
     pub fn status(&self) -> Option<StatusCode> {
         match self {
             Self::Auth(e) | Self::NotFound(e) | Self::Conflict(e) | Self::Api(e) => Some(e.status),
-            Self::RateLimited { error, .. } => Some(error.status),
+            Self::RateLimited { error, .. } | Self::MissingPermission { error, .. } => {
+                Some(error.status)
+            }
             Self::Decode { status, .. } => Some(*status),
-            Self::Transport { .. } | Self::InvalidConfig { .. } => None,
+            Self::Transport { .. }
+            | Self::InvalidConfig { .. }
+            | Self::WaitTimeout { .. }
+            | Self::Io { .. }
+            | Self::ChecksumMismatch { .. } => None,
         }
     }
 
@@ -140,9 +179,15 @@ impl Error {
             Self::Auth(e) | Self::NotFound(e) | Self::Conflict(e) | Self::Api(e) => {
                 e.request_id.as_deref()
             }
-            Self::RateLimited { error, .. } => error.request_id.as_deref(),
+            Self::RateLimited { error, .. } | Self::MissingPermission { error, .. } => {
+                error.request_id.as_deref()
+            }
             Self::Decode { request_id, .. } => request_id.as_deref(),
-            Self::Transport { .. } | Self::InvalidConfig { .. } => None,
+            Self::Transport { .. }
+            | Self::InvalidConfig { .. }
+            | Self::WaitTimeout { .. }
+            | Self::Io { .. }
+            | Self::ChecksumMismatch { .. } => None,
         }
     }
 
@@ -156,7 +201,7 @@ impl Error {
 
     #[must_use]
     pub fn is_auth_error(&self) -> bool {
-        matches!(self, Self::Auth(_))
+        matches!(self, Self::Auth(_) | Self::MissingPermission { .. })
     }
 
     #[must_use]
@@ -177,6 +222,17 @@ impl Error {
         }
     }
 
+    /// Turn a `403` into [`Self::MissingPermission`] for an endpoint that needs `permission`.
+    pub(crate) fn require_permission(self, permission: &str) -> Self {
+        match self {
+            Self::Auth(error) if error.status == StatusCode::FORBIDDEN => Self::MissingPermission {
+                permission: permission.into(),
+                error,
+            },
+            other => other,
+        }
+    }
+
     pub(crate) fn from_http(error: HttpError, retry_after: Option<Duration>) -> Self {
         match error.status {
             StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => Self::Auth(error),
@@ -100,6 +100,8 @@ mod auth;
 mod client;
 mod error;
 mod request_hook;
+#[cfg(feature = "testing")]
+pub mod testing;
 mod tls;
 pub mod types;
 
@@ -108,21 +110,33 @@ mod util;
 
 #[cfg(feature = "blocking")]
 pub use api::{
-    BlockingComputersService, BlockingJobsService, BlockingPeopleService, BlockingQueueService,
+    BlockingBuilds, BlockingComputersService, BlockingConsoleChunks, BlockingConsoleLines,
+    BlockingCredentialsService, BlockingJobWalk, BlockingJobsService, BlockingMultibranchService,
+    BlockingPeopleService, BlockingPipelineService, BlockingPluginsService, BlockingQueueService,
     BlockingSystemService, BlockingUsersService, BlockingViewsService,
 };
 #[cfg(feature = "async")]
 pub use api::{
-    ComputersService, JobsService, PeopleService, QueueService, SystemService, UsersService,
-    ViewsService,
+    ComputersService, CredentialsService, JobsService, MultibranchService, PeopleService,
+    PipelineService, PluginsService, QueueService, SystemService, UsersService, ViewsService,
 };
-pub use auth::Auth;
+pub use auth::{Auth, SecretString};
 pub use error::{BodySnippetConfig, Error, ErrorKind, HttpError, Result, TransportErrorKind};
 pub use request_hook::{RequestHook, RequestHookContext};
 pub use tls::TlsRootStore;
-pub use transport::middleware::RetryConfig;
+pub use transport::middleware::{CassetteConfig, CassetteFormat, CassetteMode, RetryConfig};
+pub use transport::{ResponseMeta, TransportBody, TransportRequest, TransportResponse};
 pub use types::*;
 
+#[cfg(feature = "async")]
+pub use async_trait::async_trait;
+#[cfg(feature = "async")]
+pub use transport::async_transport::{AsyncBodyStream, AsyncStreamResponse, AsyncTransport};
+#[cfg(feature = "blocking")]
+pub use transport::blocking_transport::{
+    BlockingBodyStream, BlockingStreamResponse, BlockingTransport,
+};
+
 #[cfg(feature = "blocking")]
 pub use client::{BlockingClient, BlockingClientBuilder};
 #[cfg(feature = "async")]
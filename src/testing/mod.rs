@@ -0,0 +1,349 @@
+//! In-memory fake Jenkins for tests of code built on this SDK (`testing` feature).
+//!
+//! [`FakeJenkins`] is a transport: it answers the core REST endpoints (jobs and folders,
+//! queue, builds with progressive logs, nodes, views, crumbs) from a model the test
+//! drives directly, without sockets.
+//!
+//! ```
+//! # #[cfg(feature = "async")]
+//! # async fn demo() -> Result<(), jenkins_sdk::Error> {
+//! use jenkins_sdk::BuildResult;
+//! use jenkins_sdk::testing::FakeJenkins;
+//!
+//! let fake = FakeJenkins::new();
+//! fake.add_job("team/app");
+//! let client = fake.client_builder()?.build()?;
+//!
+//! let triggered = client.jobs().build("team/app").await?;
+//! let queue_item = triggered.queue_item_id.unwrap();
+//! fake.complete_queue_item(queue_item.as_str().parse().unwrap(), 12, BuildResult::Failure);
+//!
+//! let build = client.jobs().build_info("team/app", "12", None).await?;
+//! assert_eq!(build.result, Some(BuildResult::Failure));
+//! # Ok(())
+//! # }
+//! ```
+
+mod state;
+
+use self::state::{Build, Incoming, InjectedFailure, Job, Node, Reply, State};
+use crate::transport::{TransportRequest, TransportResponse};
+use crate::{BuildResult, Error, JobPath};
+use http::{Method, StatusCode};
+use std::sync::{Arc, Mutex, MutexGuard};
+use url::Url;
+
+const FAKE_BASE_URL: &str = "http://jenkins.fake/";
+
+/// Stateful in-memory Jenkins controller.
+///
+/// Clones share the same state. Driver methods panic on unknown jobs, builds or queue
+/// items so that broken test setups fail loudly.
+#[derive(Clone)]
+pub struct FakeJenkins {
+    state: Arc<Mutex<State>>,
+}
+
+impl Default for FakeJenkins {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FakeJenkins {
+    #[must_use]
+    pub fn new() -> Self {
+        let base = Url::parse(FAKE_BASE_URL).expect("valid fake base URL");
+        let mut state = State::new(base);
+        state.nodes.insert(
+            "(built-in)".to_owned(),
+            Node {
+                executors: 2,
+                offline: false,
+                offline_reason: None,
+            },
+        );
+        Self {
+            state: Arc::new(Mutex::new(state)),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Base URL clients must be built with.
+    #[must_use]
+    pub fn base_url(&self) -> String {
+        self.lock().base.to_string()
+    }
+
+    /// Async client builder wired to this fake.
+    #[cfg(feature = "async")]
+    pub fn client_builder(&self) -> Result<crate::ClientBuilder, Error> {
+        Ok(crate::Client::builder(self.base_url())?.transport(self.clone()))
+    }
+
+    /// Blocking client builder wired to this fake.
+    #[cfg(feature = "blocking")]
+    pub fn blocking_client_builder(&self) -> Result<crate::BlockingClientBuilder, Error> {
+        Ok(crate::BlockingClient::builder(self.base_url())?.transport(self.clone()))
+    }
+
+    /// Reject non-GET requests without this `Jenkins-Crumb` (and serve it from
+    /// `/crumbIssuer/api/json`).
+    pub fn require_crumb(&self, crumb: impl Into<String>) -> &Self {
+        self.lock().crumb = Some(crumb.into());
+        self
+    }
+
+    /// Add a freestyle job, creating missing parent folders.
+    pub fn add_job(&self, path: impl Into<JobPath>) -> &Self {
+        self.add(path.into(), state::FREESTYLE_CLASS)
+    }
+
+    /// Add a Pipeline job, creating missing parent folders.
+    pub fn add_pipeline(&self, path: impl Into<JobPath>) -> &Self {
+        self.add(path.into(), state::PIPELINE_CLASS)
+    }
+
+    pub fn add_folder(&self, path: impl Into<JobPath>) -> &Self {
+        self.add(path.into(), state::FOLDER_CLASS)
+    }
+
+    fn add(&self, path: JobPath, class: &'static str) -> &Self {
+        self.lock()
+            .insert_job(&state::normalize(path.as_str()), Job::new(class));
+        self
+    }
+
+    /// Add an agent with `executors` executors.
+    pub fn add_node(&self, name: impl Into<String>, executors: u32) -> &Self {
+        self.lock().nodes.insert(
+            name.into(),
+            Node {
+                executors,
+                offline: false,
+                offline_reason: None,
+            },
+        );
+        self
+    }
+
+    /// Mark a node offline (`reason: Some`) or back online (`None`).
+    ///
+    /// # Panics
+    /// When the node does not exist.
+    pub fn set_node_offline(&self, name: &str, reason: Option<&str>) -> &Self {
+        let mut state = self.lock();
+        let node = state
+            .nodes
+            .get_mut(name)
+            .unwrap_or_else(|| panic!("fake Jenkins has no node `{name}`"));
+        node.offline = reason.is_some();
+        node.offline_reason = reason.map(str::to_owned);
+        self
+    }
+
+    /// Add a list view showing `jobs`.
+    pub fn add_view<I, J>(&self, name: impl Into<String>, jobs: I) -> &Self
+    where
+        I: IntoIterator<Item = J>,
+        J: Into<JobPath>,
+    {
+        let jobs = jobs
+            .into_iter()
+            .map(|job| state::normalize(job.into().as_str()))
+            .collect();
+        self.lock().views.insert(name.into(), jobs);
+        self
+    }
+
+    /// Id the next queue item gets (ids are then allocated sequentially).
+    pub fn set_next_queue_id(&self, id: u64) -> &Self {
+        self.lock().next_queue_id = id;
+        self
+    }
+
+    /// Queue `job` as if it was triggered by someone else; returns the queue item id.
+    ///
+    /// # Panics
+    /// When the job does not exist.
+    pub fn enqueue(&self, job: impl Into<JobPath>) -> u64 {
+        let job = state::normalize(job.into().as_str());
+        let mut state = self.lock();
+        assert!(
+            state.jobs.contains_key(&job),
+            "fake Jenkins has no job `{job}`"
+        );
+        state.enqueue(&job, Vec::new())
+    }
+
+    /// Leave the queue: start build `number` for queue item `queue_item`.
+    ///
+    /// # Panics
+    /// When the queue item does not exist, was cancelled or already started.
+    pub fn start_build(&self, queue_item: u64, number: u64) -> &Self {
+        let mut state = self.lock();
+        let entry = state
+            .queue
+            .get_mut(&queue_item)
+            .unwrap_or_else(|| panic!("fake Jenkins has no queue item {queue_item}"));
+        assert!(
+            !entry.cancelled && entry.build.is_none(),
+            "queue item {queue_item} already left the queue"
+        );
+        entry.build = Some(number);
+        let (path, parameters) = (entry.job.clone(), entry.parameters.clone());
+
+        let job = state
+            .jobs
+            .get_mut(&path)
+            .unwrap_or_else(|| panic!("fake Jenkins has no job `{path}`"));
+        job.next_build_number = job.next_build_number.max(number + 1);
+        job.builds.insert(
+            number,
+            Build {
+                number,
+                queue_id: Some(queue_item),
+                result: None,
+                building: true,
+                log: "Started by user fake\n".to_owned(),
+                timestamp: state::now_millis(),
+                duration: 0,
+                parameters,
+            },
+        );
+        self
+    }
+
+    /// Append console output to a running (or finished) build.
+    pub fn append_log(&self, job: impl Into<JobPath>, number: u64, text: &str) -> &Self {
+        self.with_build(job.into(), number, |build| build.log.push_str(text));
+        self
+    }
+
+    /// Finish a build with `result`, appending the usual `Finished: <RESULT>` line.
+    pub fn finish_build(&self, job: impl Into<JobPath>, number: u64, result: BuildResult) -> &Self {
+        self.with_build(job.into(), number, |build| {
+            build.log.push_str(&format!("Finished: {result}\n"));
+            build.duration = state::now_millis().saturating_sub(build.timestamp);
+            build.building = false;
+            build.result = Some(result);
+        });
+        self
+    }
+
+    /// [`Self::start_build`] then [`Self::finish_build`] in one step.
+    pub fn complete_queue_item(&self, queue_item: u64, number: u64, result: BuildResult) -> &Self {
+        self.start_build(queue_item, number);
+        let job = self.lock().queue[&queue_item].job.clone();
+        self.finish_build(job.as_str(), number, result)
+    }
+
+    /// Cancel a pending queue item, as another user would.
+    ///
+    /// # Panics
+    /// When the queue item does not exist.
+    pub fn cancel_queue_item(&self, queue_item: u64) -> &Self {
+        self.lock()
+            .queue
+            .get_mut(&queue_item)
+            .unwrap_or_else(|| panic!("fake Jenkins has no queue item {queue_item}"))
+            .cancelled = true;
+        self
+    }
+
+    /// Answer the next `method path` request (e.g. `POST /job/app/build`) with `status`.
+    pub fn fail_next(&self, method: Method, path: impl Into<String>, status: StatusCode) -> &Self {
+        self.lock().failures.push(InjectedFailure {
+            method,
+            path: path.into(),
+            status,
+        });
+        self
+    }
+
+    /// Requests received so far, as `METHOD /path` (query strings omitted).
+    #[must_use]
+    pub fn requests(&self) -> Vec<String> {
+        self.lock().requests.clone()
+    }
+
+    #[must_use]
+    pub fn job_exists(&self, path: impl Into<JobPath>) -> bool {
+        let path = state::normalize(path.into().as_str());
+        self.lock().jobs.contains_key(&path)
+    }
+
+    #[must_use]
+    pub fn is_disabled(&self, path: impl Into<JobPath>) -> bool {
+        let path = state::normalize(path.into().as_str());
+        self.lock().jobs.get(&path).is_some_and(|job| job.disabled)
+    }
+
+    /// Parameters the build was triggered with, in submission order.
+    #[must_use]
+    pub fn build_parameters(&self, job: impl Into<JobPath>, number: u64) -> Vec<(String, String)> {
+        let mut parameters = Vec::new();
+        self.with_build(job.into(), number, |build| {
+            parameters = build.parameters.clone();
+        });
+        parameters
+    }
+
+    /// Ids of the items still waiting in the queue.
+    #[must_use]
+    pub fn pending_queue_items(&self) -> Vec<u64> {
+        self.lock()
+            .queue
+            .iter()
+            .filter(|(_, entry)| !entry.cancelled && entry.build.is_none())
+            .map(|(id, _)| *id)
+            .collect()
+    }
+
+    fn with_build(&self, job: JobPath, number: u64, f: impl FnOnce(&mut Build)) {
+        let path = state::normalize(job.as_str());
+        let mut state = self.lock();
+        let build = state
+            .build_mut(&path, number)
+            .unwrap_or_else(|| panic!("fake Jenkins has no build `{path}` #{number}"));
+        f(build);
+    }
+
+    fn respond(&self, req: &TransportRequest) -> TransportResponse {
+        let mut state = self.lock();
+        let segments = state.relative_segments(&req.url);
+        let params = req.query.iter().chain(&req.form).cloned().collect();
+        let Reply {
+            status,
+            headers,
+            body,
+        } = state.handle(Incoming {
+            method: &req.method,
+            segments,
+            headers: &req.headers,
+            params,
+            body: req.body.as_ref().map_or(&[], |body| body.bytes.as_slice()),
+        });
+        TransportResponse::new(status, headers, body)
+    }
+}
+
+#[cfg(feature = "async")]
+#[async_trait::async_trait]
+impl crate::AsyncTransport for FakeJenkins {
+    async fn send(&self, req: TransportRequest) -> Result<TransportResponse, Error> {
+        Ok(self.respond(&req))
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl crate::BlockingTransport for FakeJenkins {
+    fn send(&self, req: TransportRequest) -> Result<TransportResponse, Error> {
+        Ok(self.respond(&req))
+    }
+}
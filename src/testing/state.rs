@@ -0,0 +1,916 @@
+//! Model and request routing of [`super::FakeJenkins`].
+
+use crate::BuildResult;
+use http::{HeaderMap, HeaderValue, Method, StatusCode};
+use serde_json::{Value, json};
+use std::collections::BTreeMap;
+use std::time::{SystemTime, UNIX_EPOCH};
+use url::Url;
+
+pub(super) const FREESTYLE_CLASS: &str = "hudson.model.FreeStyleProject";
+pub(super) const PIPELINE_CLASS: &str = "org.jenkinsci.plugins.workflow.job.WorkflowJob";
+pub(super) const FOLDER_CLASS: &str = "com.cloudbees.hudson.plugins.folder.Folder";
+
+const BUILD_SELECTORS: [&str; 7] = [
+    "lastBuild",
+    "lastCompletedBuild",
+    "lastSuccessfulBuild",
+    "lastFailedBuild",
+    "lastStableBuild",
+    "lastUnstableBuild",
+    "lastUnsuccessfulBuild",
+];
+
+pub(super) struct Job {
+    pub(super) class: &'static str,
+    pub(super) description: Option<String>,
+    pub(super) disabled: bool,
+    pub(super) config_xml: String,
+    pub(super) builds: BTreeMap<u64, Build>,
+    pub(super) next_build_number: u64,
+}
+
+impl Job {
+    pub(super) fn new(class: &'static str) -> Self {
+        Self {
+            class,
+            description: None,
+            disabled: false,
+            config_xml: format!("<?xml version='1.1' encoding='UTF-8'?>\n<{class}/>\n"),
+            builds: BTreeMap::new(),
+            next_build_number: 1,
+        }
+    }
+
+    fn is_folder(&self) -> bool {
+        self.class == FOLDER_CLASS
+    }
+
+    fn color(&self) -> String {
+        if self.disabled {
+            return "disabled".to_owned();
+        }
+        let Some(last) = self.builds.values().next_back() else {
+            return "notbuilt".to_owned();
+        };
+        let settled = self
+            .builds
+            .values()
+            .rev()
+            .find_map(|build| build.result.as_ref());
+        let base = match settled {
+            Some(BuildResult::Success) => "blue",
+            Some(BuildResult::Unstable) => "yellow",
+            Some(BuildResult::Failure) => "red",
+            Some(BuildResult::Aborted) => "aborted",
+            _ => "notbuilt",
+        };
+        if last.building {
+            format!("{base}_anime")
+        } else {
+            base.to_owned()
+        }
+    }
+
+    fn select(&self, selector: &str) -> Option<&Build> {
+        if let Ok(number) = selector.parse::<u64>() {
+            return self.builds.get(&number);
+        }
+        let matches = |build: &&Build| {
+            let result = build.result.as_ref();
+            match selector {
+                "lastBuild" => true,
+                "lastCompletedBuild" => !build.building,
+                "lastSuccessfulBuild" => {
+                    matches!(result, Some(BuildResult::Success | BuildResult::Unstable))
+                }
+                "lastFailedBuild" => result == Some(&BuildResult::Failure),
+                "lastStableBuild" => result == Some(&BuildResult::Success),
+                "lastUnstableBuild" => result == Some(&BuildResult::Unstable),
+                "lastUnsuccessfulBuild" => !build.building && result != Some(&BuildResult::Success),
+                _ => false,
+            }
+        };
+        self.builds.values().rev().find(matches)
+    }
+}
+
+pub(super) struct Build {
+    pub(super) number: u64,
+    pub(super) queue_id: Option<u64>,
+    pub(super) result: Option<BuildResult>,
+    pub(super) building: bool,
+    pub(super) log: String,
+    pub(super) timestamp: u64,
+    pub(super) duration: u64,
+    pub(super) parameters: Vec<(String, String)>,
+}
+
+pub(super) struct QueueEntry {
+    pub(super) job: String,
+    pub(super) parameters: Vec<(String, String)>,
+    pub(super) cancelled: bool,
+    pub(super) build: Option<u64>,
+    pub(super) in_queue_since: u64,
+}
+
+pub(super) struct Node {
+    pub(super) executors: u32,
+    pub(super) offline: bool,
+    pub(super) offline_reason: Option<String>,
+}
+
+pub(super) struct InjectedFailure {
+    pub(super) method: Method,
+    pub(super) path: String,
+    pub(super) status: StatusCode,
+}
+
+/// Everything the fake knows; guarded by the mutex of [`super::FakeJenkins`].
+pub(super) struct State {
+    pub(super) base: Url,
+    pub(super) jobs: BTreeMap<String, Job>,
+    pub(super) queue: BTreeMap<u64, QueueEntry>,
+    pub(super) next_queue_id: u64,
+    pub(super) nodes: BTreeMap<String, Node>,
+    pub(super) views: BTreeMap<String, Vec<String>>,
+    pub(super) crumb: Option<String>,
+    pub(super) failures: Vec<InjectedFailure>,
+    pub(super) requests: Vec<String>,
+}
+
+/// What the fake answers to one request.
+pub(super) struct Reply {
+    pub(super) status: StatusCode,
+    pub(super) headers: HeaderMap,
+    pub(super) body: Vec<u8>,
+}
+
+impl Reply {
+    fn status(status: StatusCode) -> Self {
+        Self {
+            status,
+            headers: HeaderMap::new(),
+            body: Vec::new(),
+        }
+    }
+
+    fn ok() -> Self {
+        Self::status(StatusCode::OK)
+    }
+
+    fn not_found() -> Self {
+        Self::text(StatusCode::NOT_FOUND, "Not Found")
+    }
+
+    fn text(status: StatusCode, text: impl Into<String>) -> Self {
+        let mut reply = Self::status(status);
+        reply.headers.insert(
+            http::header::CONTENT_TYPE,
+            HeaderValue::from_static("text/plain;charset=utf-8"),
+        );
+        reply.body = text.into().into_bytes();
+        reply
+    }
+
+    fn xml(text: &str) -> Self {
+        let mut reply = Self::text(StatusCode::OK, text);
+        reply.headers.insert(
+            http::header::CONTENT_TYPE,
+            HeaderValue::from_static("application/xml;charset=utf-8"),
+        );
+        reply
+    }
+
+    fn json(value: &Value) -> Self {
+        let mut reply = Self::ok();
+        reply.headers.insert(
+            http::header::CONTENT_TYPE,
+            HeaderValue::from_static("application/json;charset=utf-8"),
+        );
+        reply.body = value.to_string().into_bytes();
+        reply
+    }
+
+    fn created(location: &str) -> Self {
+        let mut reply = Self::status(StatusCode::CREATED);
+        if let Ok(location) = HeaderValue::from_str(location) {
+            reply.headers.insert(http::header::LOCATION, location);
+        }
+        reply
+    }
+}
+
+/// One request as seen by the router.
+pub(super) struct Incoming<'a> {
+    pub(super) method: &'a Method,
+    pub(super) segments: Vec<String>,
+    pub(super) headers: &'a HeaderMap,
+    pub(super) params: Vec<(String, String)>,
+    pub(super) body: &'a [u8],
+}
+
+impl Incoming<'_> {
+    fn param(&self, name: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    fn body_text(&self) -> String {
+        String::from_utf8_lossy(self.body).into_owned()
+    }
+}
+
+pub(super) fn now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_millis() as u64)
+        .unwrap_or_default()
+}
+
+/// `folder/app` from any of `folder/app`, `/folder/app/`.
+pub(super) fn normalize(path: &str) -> String {
+    path.trim_matches('/').to_owned()
+}
+
+fn parent_of(path: &str) -> &str {
+    path.rsplit_once('/').map_or("", |(parent, _)| parent)
+}
+
+fn name_of(path: &str) -> &str {
+    path.rsplit_once('/').map_or(path, |(_, name)| name)
+}
+
+fn join(parent: &str, name: &str) -> String {
+    if parent.is_empty() {
+        name.to_owned()
+    } else {
+        format!("{parent}/{name}")
+    }
+}
+
+fn percent_decode(segment: &str) -> String {
+    let bytes = segment.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = bytes
+            .get(i + 1..i + 3)
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match (bytes[i], hex) {
+            (b'%', Some(byte)) => {
+                out.push(byte);
+                i += 3;
+            }
+            (byte, _) => {
+                out.push(byte);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+/// Parse `allBuilds[...]{from,to}` out of a `tree` query.
+fn all_builds_range(tree: &str) -> Option<(usize, usize)> {
+    let rest = &tree[tree.find("allBuilds")?..];
+    let open = rest.find('{')?;
+    let close = rest[open..].find('}')? + open;
+    let (from, to) = rest[open + 1..close].split_once(',')?;
+    Some((from.trim().parse().ok()?, to.trim().parse().ok()?))
+}
+
+impl State {
+    pub(super) fn new(base: Url) -> Self {
+        Self {
+            base,
+            jobs: BTreeMap::new(),
+            queue: BTreeMap::new(),
+            next_queue_id: 1,
+            nodes: BTreeMap::new(),
+            views: BTreeMap::new(),
+            crumb: None,
+            failures: Vec::new(),
+            requests: Vec::new(),
+        }
+    }
+
+    /// Insert `path`, creating missing parent folders.
+    pub(super) fn insert_job(&mut self, path: &str, job: Job) {
+        let parent = parent_of(path);
+        if !parent.is_empty() && !self.jobs.contains_key(parent) {
+            self.insert_job(parent, Job::new(FOLDER_CLASS));
+        }
+        self.jobs.insert(path.to_owned(), job);
+    }
+
+    pub(super) fn enqueue(&mut self, job: &str, parameters: Vec<(String, String)>) -> u64 {
+        let id = self.next_queue_id;
+        self.next_queue_id += 1;
+        self.queue.insert(
+            id,
+            QueueEntry {
+                job: job.to_owned(),
+                parameters,
+                cancelled: false,
+                build: None,
+                in_queue_since: now_millis(),
+            },
+        );
+        id
+    }
+
+    /// Path segments relative to the base URL, percent-decoded.
+    pub(super) fn relative_segments(&self, url: &Url) -> Vec<String> {
+        let base: Vec<&str> = self
+            .base
+            .path_segments()
+            .map(|segments| segments.filter(|s| !s.is_empty()).collect())
+            .unwrap_or_default();
+        let segments: Vec<&str> = url
+            .path_segments()
+            .map(|segments| segments.filter(|s| !s.is_empty()).collect())
+            .unwrap_or_default();
+        let skip = if segments.starts_with(&base) {
+            base.len()
+        } else {
+            0
+        };
+        segments[skip..]
+            .iter()
+            .map(|segment| percent_decode(segment))
+            .collect()
+    }
+
+    pub(super) fn handle(&mut self, req: Incoming<'_>) -> Reply {
+        let path = format!("/{}", req.segments.join("/"));
+        self.requests.push(format!("{} {path}", req.method));
+
+        if let Some(at) = self
+            .failures
+            .iter()
+            .position(|failure| failure.method == *req.method && failure.path == path)
+        {
+            let failure = self.failures.remove(at);
+            return Reply::text(failure.status, "injected failure");
+        }
+
+        if req.method != Method::GET
+            && let Some(crumb) = &self.crumb
+        {
+            let sent = req
+                .headers
+                .get("Jenkins-Crumb")
+                .and_then(|value| value.to_str().ok());
+            if sent != Some(crumb.as_str()) {
+                return Reply::text(
+                    StatusCode::FORBIDDEN,
+                    "No valid crumb was included in the request",
+                );
+            }
+        }
+
+        let segments: Vec<&str> = req.segments.iter().map(String::as_str).collect();
+        match (req.method.clone(), segments.as_slice()) {
+            (Method::GET, ["api", "json"]) => self.root_json(),
+            (Method::GET, ["crumbIssuer", "api", "json"]) => match &self.crumb {
+                Some(crumb) => Reply::json(&json!({
+                    "_class": "hudson.security.csrf.DefaultCrumbIssuer",
+                    "crumbRequestField": "Jenkins-Crumb",
+                    "crumb": crumb,
+                })),
+                None => Reply::not_found(),
+            },
+            (Method::GET, ["whoAmI", "api", "json"]) => Reply::json(&json!({
+                "_class": "hudson.security.WhoAmI",
+                "name": "fake",
+                "authenticated": true,
+                "anonymous": false,
+                "authorities": ["authenticated"],
+            })),
+            (Method::POST, ["createItem"]) => self.create_item("", &req),
+            (_, ["queue", rest @ ..]) => self.queue_route(req.method, rest, &req),
+            (_, ["computer", rest @ ..]) => self.computer_route(req.method, rest),
+            (Method::GET, ["view", name, "api", "json"]) => self.view_json(name),
+            (_, ["job", ..]) => {
+                let mut job = String::new();
+                let mut rest = segments.as_slice();
+                while let ["job", name, tail @ ..] = rest {
+                    job = join(&job, name);
+                    rest = tail;
+                }
+                self.job_route(req.method, &job, rest, &req)
+            }
+            _ => Reply::not_found(),
+        }
+    }
+
+    fn url_for(&self, path: &str) -> String {
+        let mut url = self.base.to_string();
+        for name in path.split('/').filter(|name| !name.is_empty()) {
+            url.push_str("job/");
+            url.push_str(name);
+            url.push('/');
+        }
+        url
+    }
+
+    fn children(&self, parent: &str) -> Vec<Value> {
+        self.jobs
+            .iter()
+            .filter(|(path, _)| parent_of(path) == parent)
+            .map(|(path, job)| {
+                json!({
+                    "_class": job.class,
+                    "name": name_of(path),
+                    "url": self.url_for(path),
+                    "color": (!job.is_folder()).then(|| job.color()),
+                })
+            })
+            .collect()
+    }
+
+    fn root_json(&self) -> Reply {
+        let views: Vec<Value> = self
+            .views
+            .keys()
+            .map(|name| json!({ "name": name, "url": format!("{}view/{name}/", self.base) }))
+            .collect();
+        Reply::json(&json!({
+            "_class": "hudson.model.Hudson",
+            "mode": "NORMAL",
+            "nodeDescription": "the fake Jenkins controller",
+            "numExecutors": 2,
+            "useCrumbs": self.crumb.is_some(),
+            "jobs": self.children(""),
+            "views": views,
+        }))
+    }
+
+    fn build_ref(&self, path: &str, build: Option<&Build>) -> Value {
+        build.map_or(Value::Null, |build| {
+            json!({
+                "_class": "hudson.model.FreeStyleBuild",
+                "number": build.number,
+                "url": format!("{}{}/", self.url_for(path), build.number),
+            })
+        })
+    }
+
+    fn job_json(&self, path: &str, job: &Job, tree: Option<&str>) -> Value {
+        let mut info = json!({
+            "_class": job.class,
+            "name": name_of(path),
+            "fullName": path,
+            "displayName": name_of(path),
+            "url": self.url_for(path),
+            "description": job.description,
+        });
+        if job.is_folder() {
+            info["jobs"] = Value::Array(self.children(path));
+            return info;
+        }
+
+        let in_queue = self
+            .queue
+            .values()
+            .any(|entry| entry.job == path && !entry.cancelled && entry.build.is_none());
+        let newest_first: Vec<&Build> = job.builds.values().rev().collect();
+        info["color"] = json!(job.color());
+        info["buildable"] = json!(!job.disabled);
+        info["inQueue"] = json!(in_queue);
+        info["nextBuildNumber"] = json!(job.next_build_number);
+        info["builds"] = Value::Array(
+            newest_first
+                .iter()
+                .map(|build| self.build_ref(path, Some(build)))
+                .collect(),
+        );
+        for selector in BUILD_SELECTORS {
+            info[selector] = self.build_ref(path, job.select(selector));
+        }
+        if let Some(tree) = tree.filter(|tree| tree.contains("allBuilds")) {
+            let (from, to) = all_builds_range(tree).unwrap_or((0, newest_first.len()));
+            let page = newest_first
+                .iter()
+                .skip(from)
+                .take(to.saturating_sub(from))
+                .map(|build| self.build_json(path, build))
+                .collect();
+            info["allBuilds"] = Value::Array(page);
+        }
+        info["property"] = json!([]);
+        info
+    }
+
+    fn build_json(&self, path: &str, build: &Build) -> Value {
+        let parameters: Vec<Value> = build
+            .parameters
+            .iter()
+            .map(|(name, value)| {
+                json!({
+                    "_class": "hudson.model.StringParameterValue",
+                    "name": name,
+                    "value": value,
+                })
+            })
+            .collect();
+        let mut actions = vec![json!({
+            "_class": "hudson.model.CauseAction",
+            "causes": [{
+                "_class": "hudson.model.Cause$UserIdCause",
+                "shortDescription": "Started by user fake",
+                "userId": "fake",
+                "userName": "fake",
+            }],
+        })];
+        if !parameters.is_empty() {
+            actions.push(json!({
+                "_class": "hudson.model.ParametersAction",
+                "parameters": parameters,
+            }));
+        }
+        json!({
+            "_class": "hudson.model.FreeStyleBuild",
+            "number": build.number,
+            "id": build.number.to_string(),
+            "url": format!("{}{}/", self.url_for(path), build.number),
+            "displayName": format!("#{}", build.number),
+            "fullDisplayName": format!("{path} #{}", build.number),
+            "result": build.result,
+            "building": build.building,
+            "timestamp": build.timestamp,
+            "duration": build.duration,
+            "queueId": build.queue_id,
+            "actions": actions,
+            "artifacts": [],
+            "changeSets": [],
+            "culprits": [],
+        })
+    }
+
+    fn queue_json(&self, id: u64, entry: &QueueEntry) -> Value {
+        let executable = entry.build.map(|number| {
+            json!({
+                "_class": "hudson.model.FreeStyleBuild",
+                "number": number,
+                "url": format!("{}{number}/", self.url_for(&entry.job)),
+            })
+        });
+        let why = (!entry.cancelled && entry.build.is_none())
+            .then_some("Waiting for next available executor");
+        let params: String = entry
+            .parameters
+            .iter()
+            .map(|(name, value)| format!("\n{name}={value}"))
+            .collect();
+        json!({
+            "_class": if entry.cancelled || entry.build.is_some() {
+                "hudson.model.Queue$LeftItem"
+            } else {
+                "hudson.model.Queue$WaitingItem"
+            },
+            "id": id,
+            "blocked": false,
+            "buildable": entry.build.is_none() && !entry.cancelled,
+            "stuck": false,
+            "cancelled": entry.cancelled,
+            "why": why,
+            "params": params,
+            "inQueueSince": entry.in_queue_since,
+            "url": format!("queue/item/{id}/"),
+            "task": {
+                "_class": self.jobs.get(&entry.job).map_or(FREESTYLE_CLASS, |job| job.class),
+                "name": name_of(&entry.job),
+                "url": self.url_for(&entry.job),
+            },
+            "executable": executable,
+        })
+    }
+
+    fn queue_route(&mut self, method: &Method, rest: &[&str], req: &Incoming<'_>) -> Reply {
+        match (method.clone(), rest) {
+            (Method::GET, ["api", "json"]) => {
+                let items: Vec<Value> = self
+                    .queue
+                    .iter()
+                    .filter(|(_, entry)| !entry.cancelled && entry.build.is_none())
+                    .map(|(id, entry)| self.queue_json(*id, entry))
+                    .collect();
+                Reply::json(&json!({ "_class": "hudson.model.Queue", "items": items }))
+            }
+            (Method::GET, ["item", id, "api", "json"]) => id
+                .parse()
+                .ok()
+                .and_then(|id| self.queue.get(&id).map(|entry| self.queue_json(id, entry)))
+                .map_or_else(Reply::not_found, |item| Reply::json(&item)),
+            (Method::POST, ["cancelItem"]) => {
+                let entry = req
+                    .param("id")
+                    .and_then(|id| id.parse().ok())
+                    .and_then(|id: u64| self.queue.get_mut(&id));
+                match entry {
+                    Some(entry) if entry.build.is_none() => {
+                        entry.cancelled = true;
+                        Reply::status(StatusCode::NO_CONTENT)
+                    }
+                    _ => Reply::not_found(),
+                }
+            }
+            _ => Reply::not_found(),
+        }
+    }
+
+    fn computer_json(&self, name: &str, node: &Node) -> Value {
+        json!({
+            "_class": if name == "(built-in)" {
+                "hudson.model.Hudson$MasterComputer"
+            } else {
+                "hudson.slaves.SlaveComputer"
+            },
+            "displayName": name,
+            "offline": node.offline,
+            "temporarilyOffline": node.offline,
+            "offlineCauseReason": node.offline_reason.clone().unwrap_or_default(),
+            "idle": true,
+            "numExecutors": node.executors,
+            "executors": (0..node.executors).map(|_| json!({ "idle": true })).collect::<Vec<_>>(),
+        })
+    }
+
+    fn computer_route(&mut self, method: &Method, rest: &[&str]) -> Reply {
+        match (method.clone(), rest) {
+            (Method::GET, ["api", "json"]) => {
+                let computers: Vec<Value> = self
+                    .nodes
+                    .iter()
+                    .map(|(name, node)| self.computer_json(name, node))
+                    .collect();
+                let total: u32 = self
+                    .nodes
+                    .values()
+                    .filter(|node| !node.offline)
+                    .map(|node| node.executors)
+                    .sum();
+                Reply::json(&json!({
+                    "_class": "hudson.model.ComputerSet",
+                    "displayName": "Nodes",
+                    "busyExecutors": 0,
+                    "totalExecutors": total,
+                    "computer": computers,
+                }))
+            }
+            (Method::GET, [name, "api", "json"]) => {
+                self.nodes.get(*name).map_or_else(Reply::not_found, |node| {
+                    Reply::json(&self.computer_json(name, node))
+                })
+            }
+            (Method::POST, [name, "toggleOffline"]) => match self.nodes.get_mut(*name) {
+                Some(node) => {
+                    node.offline = !node.offline;
+                    Reply::ok()
+                }
+                None => Reply::not_found(),
+            },
+            (Method::POST, [name, "doDelete"]) => match self.nodes.remove(*name) {
+                Some(_) => Reply::ok(),
+                None => Reply::not_found(),
+            },
+            _ => Reply::not_found(),
+        }
+    }
+
+    fn view_json(&self, name: &str) -> Reply {
+        let Some(jobs) = self.views.get(name) else {
+            return Reply::not_found();
+        };
+        let jobs: Vec<Value> = jobs
+            .iter()
+            .filter_map(|path| {
+                let job = self.jobs.get(path)?;
+                Some(json!({
+                    "_class": job.class,
+                    "name": name_of(path),
+                    "url": self.url_for(path),
+                    "color": (!job.is_folder()).then(|| job.color()),
+                }))
+            })
+            .collect();
+        Reply::json(&json!({
+            "_class": "hudson.model.ListView",
+            "name": name,
+            "url": format!("{}view/{name}/", self.base),
+            "jobs": jobs,
+        }))
+    }
+
+    fn create_item(&mut self, parent: &str, req: &Incoming<'_>) -> Reply {
+        let Some(name) = req.param("name") else {
+            return Reply::text(
+                StatusCode::BAD_REQUEST,
+                "Query parameter 'name' is required",
+            );
+        };
+        let path = join(parent, name);
+        if self.jobs.contains_key(&path) {
+            return Reply::text(
+                StatusCode::BAD_REQUEST,
+                format!("A job already exists with the name '{name}'"),
+            );
+        }
+
+        let job = if req.param("mode") == Some("copy") {
+            let from = req.param("from").map(normalize).unwrap_or_default();
+            let Some(source) = self.jobs.get(&from) else {
+                return Reply::text(StatusCode::BAD_REQUEST, format!("No such job: {from}"));
+            };
+            let mut copy = Job::new(source.class);
+            copy.description = source.description.clone();
+            copy.config_xml = source.config_xml.clone();
+            // Jenkins disables copies until they are saved once.
+            copy.disabled = true;
+            copy
+        } else if req.param("mode") == Some(FOLDER_CLASS) {
+            Job::new(FOLDER_CLASS)
+        } else {
+            let xml = req.body_text();
+            let class = if xml.contains(FOLDER_CLASS) {
+                FOLDER_CLASS
+            } else if xml.contains("flow-definition") {
+                PIPELINE_CLASS
+            } else {
+                FREESTYLE_CLASS
+            };
+            let mut job = Job::new(class);
+            job.config_xml = xml;
+            job
+        };
+        self.jobs.insert(path, job);
+        Reply::ok()
+    }
+
+    fn move_job(&mut self, from: &str, destination: &str) -> Reply {
+        let destination = normalize(destination);
+        if !destination.is_empty() && !self.jobs.get(&destination).is_some_and(Job::is_folder) {
+            return Reply::text(StatusCode::BAD_REQUEST, "No such destination folder");
+        }
+        self.rename_subtree(from, &join(&destination, name_of(from)))
+    }
+
+    fn rename_subtree(&mut self, from: &str, to: &str) -> Reply {
+        if self.jobs.contains_key(to) {
+            return Reply::text(StatusCode::BAD_REQUEST, format!("`{to}` already exists"));
+        }
+        let prefix = format!("{from}/");
+        let moved: Vec<String> = self
+            .jobs
+            .keys()
+            .filter(|path| *path == from || path.starts_with(&prefix))
+            .cloned()
+            .collect();
+        for path in moved {
+            if let Some(job) = self.jobs.remove(&path) {
+                self.jobs
+                    .insert(format!("{to}{}", &path[from.len()..]), job);
+            }
+        }
+        Reply::ok()
+    }
+
+    fn trigger(&mut self, path: &str, req: &Incoming<'_>) -> Reply {
+        if self.jobs.get(path).is_some_and(|job| job.disabled) {
+            return Reply::text(StatusCode::CONFLICT, format!("{path} is disabled"));
+        }
+        let parameters = req
+            .params
+            .iter()
+            .filter(|(name, _)| name != "delay" && name != "token")
+            .cloned()
+            .collect();
+        let id = self.enqueue(path, parameters);
+        Reply::created(&format!("{}queue/item/{id}/", self.base))
+    }
+
+    fn job_route(
+        &mut self,
+        method: &Method,
+        path: &str,
+        rest: &[&str],
+        req: &Incoming<'_>,
+    ) -> Reply {
+        let Some(job) = self.jobs.get(path) else {
+            return Reply::not_found();
+        };
+        let tree = req.param("tree");
+
+        match (method.clone(), rest) {
+            (Method::GET, [] | ["api", "json"]) => Reply::json(&self.job_json(path, job, tree)),
+            (Method::GET, ["config.xml"]) => Reply::xml(&job.config_xml),
+            (Method::POST, ["config.xml"]) => {
+                let xml = req.body_text();
+                if let Some(job) = self.jobs.get_mut(path) {
+                    job.config_xml = xml;
+                }
+                Reply::ok()
+            }
+            (Method::POST, ["createItem"]) if job.is_folder() => {
+                let parent = path.to_owned();
+                self.create_item(&parent, req)
+            }
+            (Method::POST, ["build" | "buildWithParameters"]) if !job.is_folder() => {
+                self.trigger(path, req)
+            }
+            (Method::POST, [action @ ("enable" | "disable")]) => {
+                if let Some(job) = self.jobs.get_mut(path) {
+                    job.disabled = *action == "disable";
+                }
+                Reply::ok()
+            }
+            (Method::POST, ["submitDescription"]) => {
+                let description = req.param("description").map(str::to_owned);
+                if let Some(job) = self.jobs.get_mut(path) {
+                    job.description = description;
+                }
+                Reply::ok()
+            }
+            (Method::POST, ["doDelete"]) => {
+                let prefix = format!("{path}/");
+                self.jobs
+                    .retain(|other, _| other != path && !other.starts_with(&prefix));
+                Reply::ok()
+            }
+            (Method::POST, ["doRename" | "confirmRename"]) => match req.param("newName") {
+                Some(new_name) => self.rename_subtree(path, &join(parent_of(path), new_name)),
+                None => Reply::text(StatusCode::BAD_REQUEST, "newName is required"),
+            },
+            (Method::POST, ["move", "move"]) => {
+                let destination = req.param("destination").unwrap_or("/").to_owned();
+                self.move_job(path, &destination)
+            }
+            (_, [selector, tail @ ..]) => self.build_route(method, path, selector, tail, req),
+            _ => Reply::not_found(),
+        }
+    }
+
+    fn build_route(
+        &mut self,
+        method: &Method,
+        path: &str,
+        selector: &str,
+        tail: &[&str],
+        req: &Incoming<'_>,
+    ) -> Reply {
+        let Some(build) = self.jobs.get(path).and_then(|job| job.select(selector)) else {
+            return Reply::not_found();
+        };
+        let number = build.number;
+
+        match (method.clone(), tail) {
+            (Method::GET, [] | ["api", "json"]) => Reply::json(&self.build_json(path, build)),
+            (Method::GET, ["consoleText"]) => Reply::text(StatusCode::OK, build.log.clone()),
+            (Method::GET, ["logText", "progressiveText"]) => {
+                let start = req
+                    .param("start")
+                    .and_then(|start| start.parse::<usize>().ok())
+                    .unwrap_or(0)
+                    .min(build.log.len());
+                // Offsets are byte offsets and may fall inside a multi-byte character.
+                let mut reply = Reply::text(StatusCode::OK, "");
+                reply.body = build.log.as_bytes()[start..].to_vec();
+                reply
+                    .headers
+                    .insert("X-Text-Size", HeaderValue::from(build.log.len()));
+                if build.building {
+                    reply
+                        .headers
+                        .insert("X-More-Data", HeaderValue::from_static("true"));
+                }
+                reply
+            }
+            (Method::POST, ["stop" | "term" | "kill"]) => {
+                if let Some(build) = self.build_mut(path, number)
+                    && build.building
+                {
+                    build.building = false;
+                    build.result = Some(BuildResult::Aborted);
+                    build.log.push_str("Aborted by fake\nFinished: ABORTED\n");
+                }
+                Reply::ok()
+            }
+            (Method::POST, ["doDelete"]) => {
+                if let Some(job) = self.jobs.get_mut(path) {
+                    job.builds.remove(&number);
+                }
+                Reply::ok()
+            }
+            _ => Reply::not_found(),
+        }
+    }
+
+    pub(super) fn build_mut(&mut self, path: &str, number: u64) -> Option<&mut Build> {
+        self.jobs.get_mut(path)?.builds.get_mut(&number)
+    }
+}
@@ -5,16 +5,64 @@ use crate::{
     util::proxy_env::load_proxy_env,
 };
 use async_trait::async_trait;
+use futures_core::Stream;
+use futures_util::{StreamExt, stream};
+use http::{HeaderMap, StatusCode};
+use http_body_util::BodyDataStream;
 use reqx::{
     Client, Error as ReqxError, RetryPolicy, StatusPolicy,
     TransportErrorKind as ReqxTransportErrorKind,
 };
-use std::{sync::Arc, time::Duration};
+use std::{pin::Pin, sync::Arc, time::Duration};
+
+/// Response body delivered chunk by chunk.
+pub type AsyncBodyStream = Pin<Box<dyn Stream<Item = Result<Vec<u8>, Error>> + Send>>;
+
+/// Response whose body has not been read yet.
+#[non_exhaustive]
+pub struct AsyncStreamResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: AsyncBodyStream,
+    pub meta: ResponseMeta,
+}
+
+impl AsyncStreamResponse {
+    #[must_use]
+    pub fn new(status: StatusCode, headers: HeaderMap, body: AsyncBodyStream) -> Self {
+        Self {
+            status,
+            headers,
+            body,
+            meta: ResponseMeta::default(),
+        }
+    }
+}
+
+impl From<TransportResponse> for AsyncStreamResponse {
+    fn from(resp: TransportResponse) -> Self {
+        Self {
+            status: resp.status,
+            headers: resp.headers,
+            body: Box::pin(stream::iter(
+                (!resp.body.is_empty()).then_some(Ok(resp.body)),
+            )),
+            meta: resp.meta,
+        }
+    }
+}
 
 /// Trait implemented by any async HTTP layer.
 #[async_trait]
 pub trait AsyncTransport: Send + Sync + 'static {
     async fn send(&self, req: TransportRequest) -> Result<TransportResponse, Error>;
+
+    /// Send a request without buffering the response body.
+    ///
+    /// The default implementation buffers through [`AsyncTransport::send`].
+    async fn send_stream(&self, req: TransportRequest) -> Result<AsyncStreamResponse, Error> {
+        self.send(req).await.map(AsyncStreamResponse::from)
+    }
 }
 
 pub type DynAsyncTransport = Arc<dyn AsyncTransport>;
@@ -24,6 +72,10 @@ impl<T: AsyncTransport + ?Sized> AsyncTransport for Arc<T> {
     async fn send(&self, req: TransportRequest) -> Result<TransportResponse, Error> {
         (**self).send(req).await
     }
+
+    async fn send_stream(&self, req: TransportRequest) -> Result<AsyncStreamResponse, Error> {
+        (**self).send_stream(req).await
+    }
 }
 
 /// Default async transport built on `reqx`.
@@ -105,9 +157,11 @@ fn map_reqx_error(err: ReqxError, method: http::Method, path: Box<str>) -> Error
     }
 }
 
-#[async_trait]
-impl AsyncTransport for ReqxAsync {
-    async fn send(&self, req: TransportRequest) -> Result<TransportResponse, Error> {
+impl ReqxAsync {
+    fn request(
+        &self,
+        req: TransportRequest,
+    ) -> Result<(reqx::RequestBuilder<'_>, http::Method, Box<str>), Error> {
         let TransportRequest {
             method,
             url,
@@ -140,6 +194,14 @@ impl AsyncTransport for ReqxAsync {
                 .map_err(|err| map_reqx_error(err, method.clone(), path.clone()))?;
         }
 
+        Ok((request, method, path))
+    }
+}
+
+#[async_trait]
+impl AsyncTransport for ReqxAsync {
+    async fn send(&self, req: TransportRequest) -> Result<TransportResponse, Error> {
+        let (request, method, path) = self.request(req)?;
         let resp = request
             .send()
             .await
@@ -152,4 +214,27 @@ impl AsyncTransport for ReqxAsync {
             meta: ResponseMeta::default(),
         })
     }
+
+    async fn send_stream(&self, req: TransportRequest) -> Result<AsyncStreamResponse, Error> {
+        let (request, method, path) = self.request(req)?;
+        let resp = request
+            .send_stream()
+            .await
+            .map_err(|err| map_reqx_error(err, method.clone(), path.clone()))?;
+
+        let status = resp.status();
+        let headers = resp.headers().clone();
+        let body = BodyDataStream::new(resp.into_body()).map(move |chunk| {
+            chunk
+                .map(|bytes| bytes.to_vec())
+                .map_err(|err| map_reqx_error(err, method.clone(), path.clone()))
+        });
+
+        Ok(AsyncStreamResponse {
+            status,
+            headers,
+            body: Box::pin(body),
+            meta: ResponseMeta::default(),
+        })
+    }
 }
@@ -4,16 +4,61 @@ use crate::{
     error::{Error, TransportErrorKind},
     util::proxy_env::load_proxy_env,
 };
+use http::{HeaderMap, StatusCode};
 use reqx::{
     Error as ReqxError, RetryPolicy, StatusPolicy, TransportErrorKind as ReqxTransportErrorKind,
-    blocking::Client,
+    blocking::{Client, RequestBuilder, ResponseStream},
 };
 use std::sync::Arc;
 use std::time::Duration;
 
+const STREAM_CHUNK_BYTES: usize = 64 * 1024;
+
+/// Response body delivered chunk by chunk.
+pub type BlockingBodyStream = Box<dyn Iterator<Item = Result<Vec<u8>, Error>> + Send>;
+
+/// Response whose body has not been read yet.
+#[non_exhaustive]
+pub struct BlockingStreamResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: BlockingBodyStream,
+    pub meta: ResponseMeta,
+}
+
+impl BlockingStreamResponse {
+    #[must_use]
+    pub fn new(status: StatusCode, headers: HeaderMap, body: BlockingBodyStream) -> Self {
+        Self {
+            status,
+            headers,
+            body,
+            meta: ResponseMeta::default(),
+        }
+    }
+}
+
+impl From<TransportResponse> for BlockingStreamResponse {
+    fn from(resp: TransportResponse) -> Self {
+        Self {
+            status: resp.status,
+            headers: resp.headers,
+            body: Box::new((!resp.body.is_empty()).then_some(Ok(resp.body)).into_iter()),
+            meta: resp.meta,
+        }
+    }
+}
+
 /// Trait implemented by any blocking HTTP layer.
 pub trait BlockingTransport: Send + Sync + 'static {
     fn send(&self, req: TransportRequest) -> Result<TransportResponse, Error>;
+
+    /// Send a request without buffering the response body.
+    ///
+    /// The default implementation buffers through [`BlockingTransport::send`].
+    fn send_stream(&self, req: TransportRequest) -> Result<BlockingStreamResponse, Error> {
+        self.send(req).map(BlockingStreamResponse::from)
+    }
 }
 
 pub type DynBlockingTransport = Arc<dyn BlockingTransport>;
@@ -22,6 +67,10 @@ impl<T: BlockingTransport + ?Sized> BlockingTransport for Arc<T> {
     fn send(&self, req: TransportRequest) -> Result<TransportResponse, Error> {
         (**self).send(req)
     }
+
+    fn send_stream(&self, req: TransportRequest) -> Result<BlockingStreamResponse, Error> {
+        (**self).send_stream(req)
+    }
 }
 
 /// Default blocking transport built on `reqx`.
@@ -100,8 +149,11 @@ fn map_reqx_error(err: ReqxError, method: http::Method, path: Box<str>) -> Error
     }
 }
 
-impl BlockingTransport for ReqxBlocking {
-    fn send(&self, req: TransportRequest) -> Result<TransportResponse, Error> {
+impl ReqxBlocking {
+    fn request(
+        &self,
+        req: TransportRequest,
+    ) -> Result<(RequestBuilder<'_>, http::Method, Box<str>), Error> {
         let TransportRequest {
             method,
             url,
@@ -135,6 +187,50 @@ impl BlockingTransport for ReqxBlocking {
                 .map_err(|err| map_reqx_error(err, method.clone(), path.clone()))?;
         }
 
+        Ok((request, method, path))
+    }
+}
+
+/// Adapts a reqx response stream to an iterator of owned chunks.
+struct ReqxChunks {
+    stream: ResponseStream,
+    method: http::Method,
+    path: Box<str>,
+    done: bool,
+}
+
+impl Iterator for ReqxChunks {
+    type Item = Result<Vec<u8>, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let mut buf = vec![0; STREAM_CHUNK_BYTES];
+        match self.stream.read_chunk(&mut buf) {
+            Ok(0) => {
+                self.done = true;
+                None
+            }
+            Ok(read) => {
+                buf.truncate(read);
+                Some(Ok(buf))
+            }
+            Err(err) => {
+                self.done = true;
+                Some(Err(map_reqx_error(
+                    err,
+                    self.method.clone(),
+                    self.path.clone(),
+                )))
+            }
+        }
+    }
+}
+
+impl BlockingTransport for ReqxBlocking {
+    fn send(&self, req: TransportRequest) -> Result<TransportResponse, Error> {
+        let (request, method, path) = self.request(req)?;
         let response = request
             .send()
             .map_err(|err| map_reqx_error(err, method, path))?;
@@ -146,4 +242,23 @@ impl BlockingTransport for ReqxBlocking {
             meta: ResponseMeta::default(),
         })
     }
+
+    fn send_stream(&self, req: TransportRequest) -> Result<BlockingStreamResponse, Error> {
+        let (request, method, path) = self.request(req)?;
+        let stream = request
+            .send_stream()
+            .map_err(|err| map_reqx_error(err, method.clone(), path.clone()))?;
+
+        Ok(BlockingStreamResponse {
+            status: stream.status(),
+            headers: stream.headers().clone(),
+            body: Box::new(ReqxChunks {
+                stream,
+                method,
+                path,
+                done: false,
+            }),
+            meta: ResponseMeta::default(),
+        })
+    }
 }
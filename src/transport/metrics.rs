@@ -41,6 +41,9 @@ fn error_kind_label(kind: ErrorKind) -> &'static str {
         ErrorKind::Transport => "transport",
         ErrorKind::Decode => "decode",
         ErrorKind::InvalidConfig => "invalid_config",
+        ErrorKind::WaitTimeout => "wait_timeout",
+        ErrorKind::Io => "io",
+        ErrorKind::ChecksumMismatch => "checksum_mismatch",
     }
 }
 
@@ -0,0 +1,470 @@
+//! Record/replay of HTTP interactions ("cassettes"), shared by async and blocking wrappers.
+
+use crate::transport::{TransportBody, TransportRequest, TransportResponse};
+use crate::util::redact::redact_secrets;
+use crate::{Auth, Error, TransportErrorKind};
+use base64::{Engine, engine::general_purpose::STANDARD as B64};
+use http::{HeaderMap, HeaderName, HeaderValue, StatusCode};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use url::Url;
+
+/// Response headers worth keeping in a cassette; everything else (cookies, server
+/// identification, dates) is dropped.
+const KEPT_HEADERS: [&str; 6] = [
+    "content-type",
+    "location",
+    "retry-after",
+    "x-more-data",
+    "x-text-size",
+    "x-jenkins",
+];
+
+/// Stands in for the per-request multipart boundary so uploads match on replay.
+const MULTIPART_BOUNDARY: &str = "jenkins-sdk-cassette-boundary";
+
+/// `boundary` parameter of a `multipart/*` request body's `Content-Type`.
+fn multipart_boundary(body: &TransportBody) -> Option<&str> {
+    let content_type = body.content_type.as_ref()?.to_str().ok()?;
+    let (mime, params) = content_type.split_once(';')?;
+    if !mime.trim().to_ascii_lowercase().starts_with("multipart/") {
+        return None;
+    }
+    params.split(';').find_map(|param| {
+        let (name, value) = param.split_once('=')?;
+        name.trim()
+            .eq_ignore_ascii_case("boundary")
+            .then(|| value.trim().trim_matches('"'))
+            .filter(|boundary| !boundary.is_empty())
+    })
+}
+
+/// `haystack` with every occurrence of `from` replaced by `to`.
+fn replace_bytes(haystack: &[u8], from: &[u8], to: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(haystack.len());
+    let mut rest = haystack;
+    while !rest.is_empty() {
+        if rest.starts_with(from) {
+            out.extend_from_slice(to);
+            rest = &rest[from.len()..];
+        } else {
+            out.push(rest[0]);
+            rest = &rest[1..];
+        }
+    }
+    out
+}
+
+/// Whether a cassette records live traffic or replays it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CassetteMode {
+    /// Send requests to Jenkins and (over)write the cassette with every interaction.
+    Record,
+    /// Answer requests from the cassette; nothing reaches the network.
+    Replay,
+}
+
+/// On-disk format of a cassette file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CassetteFormat {
+    Json,
+    /// Requires the `yaml` feature.
+    Yaml,
+}
+
+impl CassetteFormat {
+    /// YAML for `.yaml`/`.yml` files, JSON otherwise.
+    #[must_use]
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("yaml") || ext.eq_ignore_ascii_case("yml") => {
+                Self::Yaml
+            }
+            _ => Self::Json,
+        }
+    }
+}
+
+/// Cassette configuration for `ClientBuilder::cassette` / `BlockingClientBuilder::cassette`.
+#[derive(Debug, Clone)]
+pub struct CassetteConfig {
+    /// Cassette file.
+    pub path: PathBuf,
+    pub mode: CassetteMode,
+    /// Defaults to [`CassetteFormat::from_path`].
+    pub format: CassetteFormat,
+    /// Extra strings scrubbed from recorded requests and responses, on top of the client's
+    /// auth token. Replay with the same list so scrubbed requests still match.
+    pub secrets: Vec<String>,
+}
+
+impl CassetteConfig {
+    #[must_use]
+    pub fn record(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        Self {
+            format: CassetteFormat::from_path(&path),
+            path,
+            mode: CassetteMode::Record,
+            secrets: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn replay(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        Self {
+            format: CassetteFormat::from_path(&path),
+            path,
+            mode: CassetteMode::Replay,
+            secrets: Vec::new(),
+        }
+    }
+
+    /// Read and write the cassette as `format` regardless of the file extension.
+    #[must_use]
+    pub fn format(mut self, format: CassetteFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Scrub `secret` as well (e.g. a credential value posted in a form).
+    #[must_use]
+    pub fn secret(mut self, secret: impl Into<String>) -> Self {
+        self.secrets.push(secret.into());
+        self
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CassetteFile {
+    interactions: Vec<Interaction>,
+}
+
+impl CassetteFile {
+    fn decode(
+        raw: &[u8],
+        format: CassetteFormat,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        match format {
+            CassetteFormat::Json => Ok(serde_json::from_slice(raw)?),
+            #[cfg(feature = "yaml")]
+            CassetteFormat::Yaml => Ok(serde_norway::from_slice(raw)?),
+            #[cfg(not(feature = "yaml"))]
+            CassetteFormat::Yaml => Err(YAML_DISABLED.into()),
+        }
+    }
+
+    fn encode(
+        &self,
+        format: CassetteFormat,
+    ) -> Result<Vec<u8>, Box<dyn std::error::Error + Send + Sync>> {
+        match format {
+            CassetteFormat::Json => Ok(serde_json::to_vec_pretty(self)?),
+            #[cfg(feature = "yaml")]
+            CassetteFormat::Yaml => Ok(serde_norway::to_string(self)?.into_bytes()),
+            #[cfg(not(feature = "yaml"))]
+            CassetteFormat::Yaml => Err(YAML_DISABLED.into()),
+        }
+    }
+}
+
+#[cfg(not(feature = "yaml"))]
+const YAML_DISABLED: &str = "YAML cassettes require the `yaml` feature";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Interaction {
+    request: RecordedRequest,
+    response: RecordedResponse,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct RecordedRequest {
+    method: String,
+    /// Path relative to the client base URL, so cassettes replay under any base.
+    path: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    query: Vec<(String, String)>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    form: Vec<(String, String)>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    body: Option<RecordedBody>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct RecordedResponse {
+    status: u16,
+    #[serde(default)]
+    headers: Vec<(String, String)>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    body: Option<RecordedBody>,
+}
+
+/// UTF-8 bodies are kept readable (and redacted); anything else is stored as base64.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum RecordedBody {
+    Text(String),
+    Base64(String),
+}
+
+impl RecordedBody {
+    fn into_bytes(self) -> Result<Vec<u8>, base64::DecodeError> {
+        match self {
+            Self::Text(text) => Ok(text.into_bytes()),
+            Self::Base64(encoded) => B64.decode(encoded),
+        }
+    }
+}
+
+struct Tape {
+    interactions: Vec<Interaction>,
+    /// Replay: which interactions were already served.
+    used: Vec<bool>,
+    /// Record: how many interactions the cassette file already holds.
+    saved: usize,
+}
+
+/// Cassette state shared by [`super::CassetteAsync`] and [`super::CassetteBlocking`].
+pub(crate) struct Cassette {
+    path: PathBuf,
+    mode: CassetteMode,
+    format: CassetteFormat,
+    base_path: String,
+    auth: Option<Auth>,
+    secrets: Vec<String>,
+    tape: Mutex<Tape>,
+}
+
+impl Cassette {
+    /// Load the cassette for replay, or start an empty one for recording.
+    pub(crate) fn open(
+        config: CassetteConfig,
+        base: &Url,
+        auth: Option<Auth>,
+    ) -> Result<Self, Error> {
+        #[cfg(not(feature = "yaml"))]
+        if config.format == CassetteFormat::Yaml {
+            return Err(Error::InvalidConfig {
+                message: format!("cassette {}: {YAML_DISABLED}", config.path.display()).into(),
+                source: None,
+            });
+        }
+        let interactions = match config.mode {
+            CassetteMode::Record => Vec::new(),
+            CassetteMode::Replay => {
+                let raw = std::fs::read(&config.path).map_err(|source| Error::Io {
+                    context: format!("reading cassette {}", config.path.display()).into(),
+                    source,
+                })?;
+                let file = CassetteFile::decode(&raw, config.format).map_err(|source| {
+                    Error::InvalidConfig {
+                        message: format!("invalid cassette {}", config.path.display()).into(),
+                        source: Some(source),
+                    }
+                })?;
+                file.interactions
+            }
+        };
+        Ok(Self {
+            path: config.path,
+            mode: config.mode,
+            format: config.format,
+            base_path: base.path().trim_end_matches('/').to_owned(),
+            auth,
+            secrets: config.secrets,
+            tape: Mutex::new(Tape {
+                used: vec![false; interactions.len()],
+                saved: interactions.len(),
+                interactions,
+            }),
+        })
+    }
+
+    pub(crate) fn is_replay(&self) -> bool {
+        self.mode == CassetteMode::Replay
+    }
+
+    fn redact(&self, text: &str) -> String {
+        redact_secrets(text.to_owned(), self.auth.as_ref(), &self.secrets)
+    }
+
+    fn redact_pairs(&self, pairs: &[(String, String)]) -> Vec<(String, String)> {
+        pairs
+            .iter()
+            .map(|(name, value)| (name.clone(), self.redact(value)))
+            .collect()
+    }
+
+    fn body(&self, bytes: &[u8]) -> Option<RecordedBody> {
+        if bytes.is_empty() {
+            return None;
+        }
+        Some(match std::str::from_utf8(bytes) {
+            Ok(text) => RecordedBody::Text(self.redact(text)),
+            Err(_) => RecordedBody::Base64(B64.encode(bytes)),
+        })
+    }
+
+    fn request(&self, req: &TransportRequest) -> RecordedRequest {
+        let path = req.url.path();
+        let path = path.strip_prefix(&self.base_path).unwrap_or(path);
+        RecordedRequest {
+            method: req.method.to_string(),
+            path: self.redact(path),
+            query: self.redact_pairs(&req.query),
+            form: self.redact_pairs(&req.form),
+            body: req
+                .body
+                .as_ref()
+                .and_then(|body| match multipart_boundary(body) {
+                    Some(boundary) => self.body(&replace_bytes(
+                        &body.bytes,
+                        boundary.as_bytes(),
+                        MULTIPART_BOUNDARY.as_bytes(),
+                    )),
+                    None => self.body(&body.bytes),
+                }),
+        }
+    }
+
+    /// Serve the first unused interaction matching method, path, query and body.
+    pub(crate) fn replay(&self, req: &TransportRequest) -> Result<TransportResponse, Error> {
+        let wanted = self.request(req);
+        let mut tape = self.tape.lock().unwrap_or_else(|e| e.into_inner());
+        let Tape {
+            interactions, used, ..
+        } = &mut *tape;
+        let found = interactions
+            .iter()
+            .zip(used.iter_mut())
+            .find(|(interaction, used)| !**used && interaction.request == wanted);
+        let Some((interaction, used)) = found else {
+            return Err(Error::Transport {
+                method: req.method.clone(),
+                path: req.url.path().into(),
+                kind: TransportErrorKind::Other,
+                source: format!(
+                    "no unused interaction in cassette {} matches this request",
+                    self.path.display()
+                )
+                .into(),
+            });
+        };
+        *used = true;
+
+        let recorded = interaction.response.clone();
+        let invalid = |message: String| Error::InvalidConfig {
+            message: format!("cassette {}: {message}", self.path.display()).into(),
+            source: None,
+        };
+        let status = StatusCode::from_u16(recorded.status)
+            .map_err(|_| invalid(format!("invalid status {}", recorded.status)))?;
+        let mut headers = HeaderMap::new();
+        for (name, value) in recorded.headers {
+            let (Ok(name), Ok(value)) = (
+                HeaderName::try_from(name.as_str()),
+                HeaderValue::try_from(value.as_str()),
+            ) else {
+                return Err(invalid(format!("invalid header `{name}`")));
+            };
+            headers.append(name, value);
+        }
+        let body = match recorded.body {
+            Some(body) => body
+                .into_bytes()
+                .map_err(|err| invalid(format!("invalid base64 body: {err}")))?,
+            None => Vec::new(),
+        };
+        Ok(TransportResponse::new(status, headers, body))
+    }
+
+    /// Append one interaction to the in-memory tape; see [`Self::save`].
+    pub(crate) fn record(&self, req: &TransportRequest, resp: &TransportResponse) {
+        let headers = resp
+            .headers
+            .iter()
+            .filter(|(name, _)| KEPT_HEADERS.contains(&name.as_str()))
+            .filter_map(|(name, value)| {
+                let value = value.to_str().ok()?;
+                Some((name.as_str().to_owned(), self.redact(value)))
+            })
+            .collect();
+        let interaction = Interaction {
+            request: self.request(req),
+            response: RecordedResponse {
+                status: resp.status.as_u16(),
+                headers,
+                body: self.body(&resp.body),
+            },
+        };
+
+        let mut tape = self.tape.lock().unwrap_or_else(|e| e.into_inner());
+        tape.interactions.push(interaction);
+    }
+
+    /// Encoded cassette and its interaction count, when recordings are not saved yet.
+    fn unsaved(&self) -> Result<Option<(Vec<u8>, usize)>, Error> {
+        let file = {
+            let tape = self.tape.lock().unwrap_or_else(|e| e.into_inner());
+            if self.is_replay() || tape.interactions.len() == tape.saved {
+                return Ok(None);
+            }
+            CassetteFile {
+                interactions: tape.interactions.clone(),
+            }
+        };
+        let encoded = file
+            .encode(self.format)
+            .map_err(|source| Error::InvalidConfig {
+                message: "failed to serialize cassette".into(),
+                source: Some(source),
+            })?;
+        Ok(Some((encoded, file.interactions.len())))
+    }
+
+    fn mark_saved(&self, count: usize) {
+        let mut tape = self.tape.lock().unwrap_or_else(|e| e.into_inner());
+        tape.saved = tape.saved.max(count);
+    }
+
+    fn write_error(&self) -> impl FnOnce(std::io::Error) -> Error + '_ {
+        move |source| Error::Io {
+            context: format!("writing cassette {}", self.path.display()).into(),
+            source,
+        }
+    }
+
+    /// Write recorded interactions to the cassette file (a no-op when nothing is new).
+    pub(crate) fn save(&self) -> Result<(), Error> {
+        let Some((encoded, count)) = self.unsaved()? else {
+            return Ok(());
+        };
+        std::fs::write(&self.path, encoded).map_err(self.write_error())?;
+        self.mark_saved(count);
+        Ok(())
+    }
+
+    /// [`Self::save`] without blocking the runtime.
+    #[cfg(feature = "async")]
+    pub(crate) async fn save_async(&self) -> Result<(), Error> {
+        let Some((encoded, count)) = self.unsaved()? else {
+            return Ok(());
+        };
+        tokio::fs::write(&self.path, encoded)
+            .await
+            .map_err(self.write_error())?;
+        self.mark_saved(count);
+        Ok(())
+    }
+}
+
+/// Last resort for recordings nobody saved explicitly; errors cannot be reported here.
+impl Drop for Cassette {
+    fn drop(&mut self) {
+        let _ = self.save();
+    }
+}
@@ -0,0 +1,42 @@
+//! Record/replay wrapper (async).
+
+use super::cassette::Cassette;
+use crate::{
+    Error,
+    transport::{
+        TransportRequest, TransportResponse,
+        async_transport::{AsyncStreamResponse, AsyncTransport, DynAsyncTransport},
+    },
+};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// Records interactions of `inner`, or answers from the cassette without calling it.
+#[derive(Clone)]
+pub struct CassetteAsync {
+    inner: DynAsyncTransport,
+    cassette: Arc<Cassette>,
+}
+
+impl CassetteAsync {
+    pub(crate) fn new(inner: DynAsyncTransport, cassette: Arc<Cassette>) -> Self {
+        Self { inner, cassette }
+    }
+}
+
+#[async_trait]
+impl AsyncTransport for CassetteAsync {
+    async fn send(&self, req: TransportRequest) -> Result<TransportResponse, Error> {
+        if self.cassette.is_replay() {
+            return self.cassette.replay(&req);
+        }
+        let resp = self.inner.send(req.clone()).await?;
+        self.cassette.record(&req, &resp);
+        Ok(resp)
+    }
+
+    /// Streams are buffered so the whole body ends up in the cassette.
+    async fn send_stream(&self, req: TransportRequest) -> Result<AsyncStreamResponse, Error> {
+        self.send(req).await.map(AsyncStreamResponse::from)
+    }
+}
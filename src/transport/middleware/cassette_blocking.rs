@@ -0,0 +1,40 @@
+//! Record/replay wrapper (blocking).
+
+use super::cassette::Cassette;
+use crate::{
+    Error,
+    transport::{
+        TransportRequest, TransportResponse,
+        blocking_transport::{BlockingStreamResponse, BlockingTransport, DynBlockingTransport},
+    },
+};
+use std::sync::Arc;
+
+/// Records interactions of `inner`, or answers from the cassette without calling it.
+#[derive(Clone)]
+pub struct CassetteBlocking {
+    inner: DynBlockingTransport,
+    cassette: Arc<Cassette>,
+}
+
+impl CassetteBlocking {
+    pub(crate) fn new(inner: DynBlockingTransport, cassette: Arc<Cassette>) -> Self {
+        Self { inner, cassette }
+    }
+}
+
+impl BlockingTransport for CassetteBlocking {
+    fn send(&self, req: TransportRequest) -> Result<TransportResponse, Error> {
+        if self.cassette.is_replay() {
+            return self.cassette.replay(&req);
+        }
+        let resp = self.inner.send(req.clone())?;
+        self.cassette.record(&req, &resp);
+        Ok(resp)
+    }
+
+    /// Streams are buffered so the whole body ends up in the cassette.
+    fn send_stream(&self, req: TransportRequest) -> Result<BlockingStreamResponse, Error> {
+        self.send(req).map(BlockingStreamResponse::from)
+    }
+}
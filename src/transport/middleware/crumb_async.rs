@@ -7,7 +7,10 @@
 use super::retry::parse_retry_after;
 use crate::{
     Auth, BodySnippetConfig, Error, HttpError,
-    transport::{TransportRequest, async_transport::AsyncTransport},
+    transport::{
+        TransportRequest,
+        async_transport::{AsyncStreamResponse, AsyncTransport},
+    },
     util::{
         diagnostics,
         redact::redact_text,
@@ -144,14 +147,8 @@ impl<T: AsyncTransport> CrumbAsync<T> {
             ts: Instant::now(),
         })
     }
-}
 
-#[async_trait]
-impl<T: AsyncTransport> AsyncTransport for CrumbAsync<T> {
-    async fn send(
-        &self,
-        mut req: TransportRequest,
-    ) -> Result<crate::transport::TransportResponse, Error> {
+    async fn apply_crumb(&self, req: &mut TransportRequest) -> Result<(), Error> {
         // Non-GET calls need a crumb.
         if req.method != Method::GET {
             let mut guard = self.cache.write().await;
@@ -179,7 +176,22 @@ impl<T: AsyncTransport> AsyncTransport for CrumbAsync<T> {
                 req.headers.insert(name, value);
             }
         }
+        Ok(())
+    }
+}
 
+#[async_trait]
+impl<T: AsyncTransport> AsyncTransport for CrumbAsync<T> {
+    async fn send(
+        &self,
+        mut req: TransportRequest,
+    ) -> Result<crate::transport::TransportResponse, Error> {
+        self.apply_crumb(&mut req).await?;
         self.inner.send(req).await
     }
+
+    async fn send_stream(&self, mut req: TransportRequest) -> Result<AsyncStreamResponse, Error> {
+        self.apply_crumb(&mut req).await?;
+        self.inner.send_stream(req).await
+    }
 }
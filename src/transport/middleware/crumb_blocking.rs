@@ -3,7 +3,10 @@
 use super::retry::parse_retry_after;
 use crate::{
     Auth, BodySnippetConfig, Error, HttpError,
-    transport::{TransportRequest, blocking_transport::BlockingTransport},
+    transport::{
+        TransportRequest,
+        blocking_transport::{BlockingStreamResponse, BlockingTransport},
+    },
     util::{
         diagnostics,
         redact::redact_text,
@@ -131,13 +134,8 @@ impl<T: BlockingTransport> CrumbBlocking<T> {
             ts: Instant::now(),
         })
     }
-}
 
-impl<T: BlockingTransport> BlockingTransport for CrumbBlocking<T> {
-    fn send(
-        &self,
-        mut req: TransportRequest,
-    ) -> Result<crate::transport::TransportResponse, Error> {
+    fn apply_crumb(&self, req: &mut TransportRequest) -> Result<(), Error> {
         if req.method != Method::GET {
             let mut guard = match self.cache.lock() {
                 Ok(guard) => guard,
@@ -167,7 +165,21 @@ impl<T: BlockingTransport> BlockingTransport for CrumbBlocking<T> {
                 req.headers.insert(name, value);
             }
         }
+        Ok(())
+    }
+}
 
+impl<T: BlockingTransport> BlockingTransport for CrumbBlocking<T> {
+    fn send(
+        &self,
+        mut req: TransportRequest,
+    ) -> Result<crate::transport::TransportResponse, Error> {
+        self.apply_crumb(&mut req)?;
         self.inner.send(req)
     }
+
+    fn send_stream(&self, mut req: TransportRequest) -> Result<BlockingStreamResponse, Error> {
+        self.apply_crumb(&mut req)?;
+        self.inner.send_stream(req)
+    }
 }
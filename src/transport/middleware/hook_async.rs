@@ -1,4 +1,7 @@
-use crate::transport::{TransportRequest, TransportResponse, async_transport::AsyncTransport};
+use crate::transport::{
+    TransportRequest, TransportResponse,
+    async_transport::{AsyncStreamResponse, AsyncTransport},
+};
 use crate::{Error, RequestHook, RequestHookContext};
 use async_trait::async_trait;
 
@@ -13,11 +16,8 @@ impl<T> HookAsync<T> {
     pub fn new(inner: T, hook: RequestHook) -> Self {
         Self { inner, hook }
     }
-}
 
-#[async_trait]
-impl<T: AsyncTransport> AsyncTransport for HookAsync<T> {
-    async fn send(&self, mut req: TransportRequest) -> Result<TransportResponse, Error> {
+    fn run_hook(&self, req: &mut TransportRequest) -> Result<(), Error> {
         let body = req.body.as_ref();
         let body_bytes = body.map(|b| b.bytes.as_slice());
         let content_type = body.and_then(|b| b.content_type.as_ref());
@@ -30,8 +30,19 @@ impl<T: AsyncTransport> AsyncTransport for HookAsync<T> {
             form: &req.form,
             body: body_bytes,
             content_type,
-        })?;
+        })
+    }
+}
 
+#[async_trait]
+impl<T: AsyncTransport> AsyncTransport for HookAsync<T> {
+    async fn send(&self, mut req: TransportRequest) -> Result<TransportResponse, Error> {
+        self.run_hook(&mut req)?;
         self.inner.send(req).await
     }
+
+    async fn send_stream(&self, mut req: TransportRequest) -> Result<AsyncStreamResponse, Error> {
+        self.run_hook(&mut req)?;
+        self.inner.send_stream(req).await
+    }
 }
@@ -1,5 +1,6 @@
 use crate::transport::{
-    TransportRequest, TransportResponse, blocking_transport::BlockingTransport,
+    TransportRequest, TransportResponse,
+    blocking_transport::{BlockingStreamResponse, BlockingTransport},
 };
 use crate::{Error, RequestHook, RequestHookContext};
 
@@ -14,10 +15,8 @@ impl<T> HookBlocking<T> {
     pub fn new(inner: T, hook: RequestHook) -> Self {
         Self { inner, hook }
     }
-}
 
-impl<T: BlockingTransport> BlockingTransport for HookBlocking<T> {
-    fn send(&self, mut req: TransportRequest) -> Result<TransportResponse, Error> {
+    fn run_hook(&self, req: &mut TransportRequest) -> Result<(), Error> {
         let body = req.body.as_ref();
         let body_bytes = body.map(|b| b.bytes.as_slice());
         let content_type = body.and_then(|b| b.content_type.as_ref());
@@ -30,8 +29,18 @@ impl<T: BlockingTransport> BlockingTransport for HookBlocking<T> {
             form: &req.form,
             body: body_bytes,
             content_type,
-        })?;
+        })
+    }
+}
 
+impl<T: BlockingTransport> BlockingTransport for HookBlocking<T> {
+    fn send(&self, mut req: TransportRequest) -> Result<TransportResponse, Error> {
+        self.run_hook(&mut req)?;
         self.inner.send(req)
     }
+
+    fn send_stream(&self, mut req: TransportRequest) -> Result<BlockingStreamResponse, Error> {
+        self.run_hook(&mut req)?;
+        self.inner.send_stream(req)
+    }
 }
@@ -17,6 +17,13 @@ pub mod crumb_async;
 #[cfg(feature = "blocking")]
 pub mod crumb_blocking;
 
+pub mod cassette;
+
+#[cfg(feature = "async")]
+pub mod cassette_async;
+#[cfg(feature = "blocking")]
+pub mod cassette_blocking;
+
 #[cfg(feature = "async")]
 pub use retry_async::RetryAsync;
 #[cfg(feature = "blocking")]
@@ -33,3 +40,10 @@ pub use hook_blocking::HookBlocking;
 pub use crumb_async::CrumbAsync;
 #[cfg(feature = "blocking")]
 pub use crumb_blocking::CrumbBlocking;
+
+pub use cassette::{CassetteConfig, CassetteFormat, CassetteMode};
+
+#[cfg(feature = "async")]
+pub use cassette_async::CassetteAsync;
+#[cfg(feature = "blocking")]
+pub use cassette_blocking::CassetteBlocking;
@@ -69,6 +69,25 @@ pub(crate) fn backoff_delay(config: &RetryConfig, attempt: usize) -> Duration {
     scaled.min(config.max_delay)
 }
 
+/// Delay before retry number `attempt`, honouring `Retry-After` when a response is available.
+pub(crate) fn retry_delay(
+    config: &RetryConfig,
+    attempt: usize,
+    headers: Option<&HeaderMap>,
+) -> Duration {
+    headers
+        .filter(|_| config.respect_retry_after)
+        .and_then(|headers| parse_retry_after(headers, SystemTime::now()))
+        .unwrap_or_else(|| {
+            let cap = backoff_delay(config, attempt);
+            if config.jitter {
+                jitter_delay(cap)
+            } else {
+                cap
+            }
+        })
+}
+
 pub(crate) fn parse_retry_after(headers: &HeaderMap, now: SystemTime) -> Option<Duration> {
     let value = headers.get(http::header::RETRY_AFTER)?;
     let text = value.to_str().ok()?.trim();
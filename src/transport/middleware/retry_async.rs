@@ -1,18 +1,16 @@
 //! Conservative retry wrapper (async).
 
-use super::retry::{
-    RetryConfig, backoff_delay, is_idempotent, is_retryable_status, jitter_delay, parse_retry_after,
-};
+use super::retry::{RetryConfig, is_idempotent, is_retryable_status, retry_delay};
 use crate::{
     Error, TransportErrorKind,
     transport::{
         ResponseMeta, TransportRequest, TransportResponse,
-        async_transport::{AsyncTransport, DynAsyncTransport},
+        async_transport::{AsyncStreamResponse, AsyncTransport, DynAsyncTransport},
     },
 };
 use async_trait::async_trait;
-use http::Method;
-use std::time::SystemTime;
+use http::{HeaderMap, Method, StatusCode};
+use std::time::Duration;
 use tokio::time::sleep;
 
 #[derive(Clone)]
@@ -34,6 +32,27 @@ impl RetryAsync {
     fn should_retry_error(&self, err: &Error) -> bool {
         matches!(err, Error::Transport { kind, .. } if matches!(kind, TransportErrorKind::Timeout | TransportErrorKind::Connect))
     }
+
+    /// Decide whether an attempt should be retried and how long to wait first.
+    fn next_retry(
+        &self,
+        can_retry: bool,
+        retries: usize,
+        outcome: Result<(StatusCode, &HeaderMap), &Error>,
+    ) -> Option<Duration> {
+        if !can_retry || retries >= self.config.max_retries {
+            return None;
+        }
+        match outcome {
+            Ok((status, headers)) if is_retryable_status(status) => {
+                Some(retry_delay(&self.config, retries + 1, Some(headers)))
+            }
+            Err(err) if self.should_retry_error(err) => {
+                Some(retry_delay(&self.config, retries + 1, None))
+            }
+            _ => None,
+        }
+    }
 }
 
 #[async_trait]
@@ -42,64 +61,48 @@ impl AsyncTransport for RetryAsync {
         let can_retry = self.should_retry_method(&req.method);
 
         let mut retries = 0usize;
-        let request = req;
         loop {
-            let result = self.inner.send(request.clone()).await;
-
-            match result {
-                Ok(mut resp) => {
-                    if can_retry
-                        && retries < self.config.max_retries
-                        && is_retryable_status(resp.status)
-                    {
-                        let now = SystemTime::now();
-                        let retry_after = self
-                            .config
-                            .respect_retry_after
-                            .then(|| parse_retry_after(&resp.headers, now))
-                            .flatten();
+            let result = self.inner.send(req.clone()).await;
+            let outcome = result.as_ref().map(|resp| (resp.status, &resp.headers));
+            if let Some(delay) = self.next_retry(can_retry, retries, outcome) {
+                if !delay.is_zero() {
+                    sleep(delay).await;
+                }
+                retries += 1;
+                continue;
+            }
 
-                        let delay = retry_after.unwrap_or_else(|| {
-                            let cap = backoff_delay(&self.config, retries + 1);
-                            if self.config.jitter {
-                                jitter_delay(cap)
-                            } else {
-                                cap
-                            }
-                        });
+            return result.map(|mut resp| {
+                resp.meta = ResponseMeta {
+                    retries: resp.meta.retries.saturating_add(retries),
+                };
+                resp
+            });
+        }
+    }
 
-                        if !delay.is_zero() {
-                            sleep(delay).await;
-                        }
-                        retries += 1;
-                        continue;
-                    }
+    async fn send_stream(&self, req: TransportRequest) -> Result<AsyncStreamResponse, Error> {
+        let can_retry = self.should_retry_method(&req.method);
 
-                    resp.meta = ResponseMeta {
-                        retries: resp.meta.retries.saturating_add(retries),
-                    };
-                    return Ok(resp);
-                }
-                Err(err) => {
-                    if can_retry
-                        && retries < self.config.max_retries
-                        && self.should_retry_error(&err)
-                    {
-                        let cap = backoff_delay(&self.config, retries + 1);
-                        let delay = if self.config.jitter {
-                            jitter_delay(cap)
-                        } else {
-                            cap
-                        };
-                        if !delay.is_zero() {
-                            sleep(delay).await;
-                        }
-                        retries += 1;
-                        continue;
-                    }
-                    return Err(err);
+        let mut retries = 0usize;
+        loop {
+            let result = self.inner.send_stream(req.clone()).await;
+            let outcome = result.as_ref().map(|resp| (resp.status, &resp.headers));
+            if let Some(delay) = self.next_retry(can_retry, retries, outcome) {
+                drop(result);
+                if !delay.is_zero() {
+                    sleep(delay).await;
                 }
+                retries += 1;
+                continue;
             }
+
+            return result.map(|mut resp| {
+                resp.meta = ResponseMeta {
+                    retries: resp.meta.retries.saturating_add(retries),
+                };
+                resp
+            });
         }
     }
 }
@@ -3,6 +3,10 @@
 //! * Async transport uses `reqx`.
 //! * Blocking transport also uses `reqx`.
 //! * Common request/response types stay transport-agnostic.
+//!
+//! The transport traits and request/response types are re-exported at the crate root so
+//! callers can plug their own HTTP stack (or a test double) in with
+//! `ClientBuilder::transport` / `BlockingClientBuilder::transport`.
 
 use http::{HeaderMap, HeaderValue, Method, StatusCode};
 use std::time::Duration;
@@ -18,12 +22,17 @@ pub mod async_transport;
 #[cfg(feature = "blocking")]
 pub mod blocking_transport;
 
+/// Bookkeeping filled in by the middleware stack.
 #[derive(Clone, Debug, Default)]
+#[non_exhaustive]
 pub struct ResponseMeta {
+    /// Retries performed by the retry layer before this response.
     pub retries: usize,
 }
 
+/// Fully buffered HTTP response returned by a transport.
 #[derive(Clone, Debug)]
+#[non_exhaustive]
 pub struct TransportResponse {
     pub status: StatusCode,
     pub headers: HeaderMap,
@@ -31,13 +40,32 @@ pub struct TransportResponse {
     pub meta: ResponseMeta,
 }
 
+impl TransportResponse {
+    #[must_use]
+    pub fn new(status: StatusCode, headers: HeaderMap, body: impl Into<Vec<u8>>) -> Self {
+        Self {
+            status,
+            headers,
+            body: body.into(),
+            meta: ResponseMeta::default(),
+        }
+    }
+}
+
+/// Raw request body; mutually exclusive with [`TransportRequest::form`].
 #[derive(Clone, Debug)]
+#[non_exhaustive]
 pub struct TransportBody {
     pub bytes: Vec<u8>,
     pub content_type: Option<HeaderValue>,
 }
 
+/// Request handed to a transport, with auth and default headers already applied.
+///
+/// `query` and `form` are not encoded yet: `query` goes to the URL query string, `form`
+/// to an `application/x-www-form-urlencoded` body.
 #[derive(Clone, Debug)]
+#[non_exhaustive]
 pub struct TransportRequest {
     pub method: Method,
     pub url: Url,
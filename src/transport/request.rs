@@ -16,6 +16,134 @@ impl RequestBody {
             content_type: Some(content_type),
         }
     }
+
+    /// `multipart/form-data` body; encoded up front so retries can resend it.
+    #[must_use]
+    pub fn multipart(form: Multipart) -> Self {
+        let (bytes, boundary) = form.encode();
+        let content_type =
+            HeaderValue::from_str(&format!("multipart/form-data; boundary={boundary}"))
+                .expect("multipart boundary is ASCII");
+        Self::bytes_with_content_type(bytes, content_type)
+    }
+}
+
+#[derive(Clone, Debug)]
+enum MultipartPart {
+    Text {
+        name: String,
+        value: String,
+    },
+    File {
+        name: String,
+        file_name: String,
+        bytes: Vec<u8>,
+    },
+}
+
+/// Fields and files of a `multipart/form-data` body.
+#[derive(Clone, Debug, Default)]
+pub struct Multipart {
+    parts: Vec<MultipartPart>,
+}
+
+impl Multipart {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn text(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.parts.push(MultipartPart::Text {
+            name: name.into(),
+            value: value.into(),
+        });
+        self
+    }
+
+    #[must_use]
+    pub fn file(
+        mut self,
+        name: impl Into<String>,
+        file_name: impl Into<String>,
+        bytes: Vec<u8>,
+    ) -> Self {
+        self.parts.push(MultipartPart::File {
+            name: name.into(),
+            file_name: file_name.into(),
+            bytes,
+        });
+        self
+    }
+
+    fn encode(&self) -> (Vec<u8>, String) {
+        let boundary = self.boundary();
+        let mut out = Vec::new();
+        for part in &self.parts {
+            out.extend_from_slice(format!("--{boundary}\r\n").as_bytes());
+            match part {
+                MultipartPart::Text { name, value } => {
+                    out.extend_from_slice(
+                        format!(
+                            "Content-Disposition: form-data; name=\"{}\"\r\n\r\n",
+                            quote(name)
+                        )
+                        .as_bytes(),
+                    );
+                    out.extend_from_slice(value.as_bytes());
+                }
+                MultipartPart::File {
+                    name,
+                    file_name,
+                    bytes,
+                } => {
+                    out.extend_from_slice(
+                        format!(
+                            "Content-Disposition: form-data; name=\"{}\"; filename=\"{}\"\r\n\
+                             Content-Type: application/octet-stream\r\n\r\n",
+                            quote(name),
+                            quote(file_name)
+                        )
+                        .as_bytes(),
+                    );
+                    out.extend_from_slice(bytes);
+                }
+            }
+            out.extend_from_slice(b"\r\n");
+        }
+        out.extend_from_slice(format!("--{boundary}--\r\n").as_bytes());
+        (out, boundary)
+    }
+
+    /// A boundary that does not occur in any part.
+    fn boundary(&self) -> String {
+        let seed = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_nanos());
+        (0u32..)
+            .map(|attempt| format!("jenkins-sdk-{seed:x}-{attempt:x}"))
+            .find(|boundary| {
+                !self.parts.iter().any(|part| {
+                    let bytes = match part {
+                        MultipartPart::Text { value, .. } => value.as_bytes(),
+                        MultipartPart::File { bytes, .. } => bytes,
+                    };
+                    bytes
+                        .windows(boundary.len())
+                        .any(|window| window == boundary.as_bytes())
+                })
+            })
+            .expect("an unused boundary exists")
+    }
+}
+
+/// Escape a `Content-Disposition` parameter the way browsers do.
+fn quote(value: &str) -> String {
+    value
+        .replace('"', "%22")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
 }
 
 #[derive(Clone, Debug)]
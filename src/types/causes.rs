@@ -0,0 +1,80 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// What a [`BuildCause`] represents, derived from its `_class`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CauseKind {
+    /// Started by a user (`Cause$UserIdCause`).
+    User,
+    /// Started by another build (`Cause$UpstreamCause`, Pipeline `BuildUpstreamCause`).
+    Upstream,
+    /// Started by a cron trigger (`TimerTrigger$TimerTriggerCause`).
+    Timer,
+    /// Started by SCM polling or a push hook (`SCMTrigger$SCMTriggerCause`).
+    Scm,
+    /// Started through the remote build token (`Cause$RemoteCause`).
+    Remote,
+    /// Any other cause; carries the `_class` (empty when missing).
+    Unknown(String),
+}
+
+/// One entry of a build's `CauseAction.causes`.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct BuildCause {
+    #[serde(default, rename = "_class")]
+    pub class: Option<String>,
+    /// Human readable summary, e.g. `Started by user Alice`.
+    #[serde(default)]
+    pub short_description: Option<String>,
+    #[serde(default)]
+    pub user_id: Option<String>,
+    #[serde(default)]
+    pub user_name: Option<String>,
+    /// Full name of the upstream job.
+    #[serde(default)]
+    pub upstream_project: Option<String>,
+    #[serde(default)]
+    pub upstream_build: Option<u64>,
+    /// Upstream job URL relative to the Jenkins root (`job/a/`).
+    #[serde(default)]
+    pub upstream_url: Option<String>,
+    /// Remote caller address of a remote cause.
+    #[serde(default)]
+    pub addr: Option<String>,
+    #[serde(default)]
+    pub note: Option<String>,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+impl BuildCause {
+    #[must_use]
+    pub fn kind(&self) -> CauseKind {
+        let class = self.class.as_deref().unwrap_or_default();
+        let name = class.rsplit(['.', '$']).next().unwrap_or_default();
+        match name {
+            "UserIdCause" | "UserCause" => CauseKind::User,
+            "UpstreamCause" | "BuildUpstreamCause" => CauseKind::Upstream,
+            "TimerTriggerCause" => CauseKind::Timer,
+            "SCMTriggerCause" => CauseKind::Scm,
+            "RemoteCause" => CauseKind::Remote,
+            _ if class.is_empty() && self.user_id.is_some() => CauseKind::User,
+            _ if class.is_empty() && self.upstream_project.is_some() => CauseKind::Upstream,
+            _ => CauseKind::Unknown(class.to_owned()),
+        }
+    }
+
+    /// `<upstream project>#<upstream build>` for upstream causes.
+    #[must_use]
+    pub fn upstream(&self) -> Option<String> {
+        Some(format!(
+            "{}#{}",
+            self.upstream_project.as_deref()?,
+            self.upstream_build?
+        ))
+    }
+}
@@ -0,0 +1,139 @@
+use crate::PersonUser;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// One SCM changeset of a build (`changeSets[]`, or `changeSet` for freestyle builds).
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct ChangeSet {
+    #[serde(default, rename = "_class")]
+    pub class: Option<String>,
+    /// SCM kind, e.g. `git`.
+    #[serde(default)]
+    pub kind: Option<String>,
+    #[serde(default)]
+    pub items: Vec<ChangeSetItem>,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+/// One commit of a [`ChangeSet`].
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct ChangeSetItem {
+    /// Commit id (git SHA); see [`ChangeSetItem::revision`].
+    #[serde(default)]
+    pub commit_id: Option<String>,
+    /// Id reported by SCMs without `commitId` (e.g. Subversion revisions).
+    #[serde(default)]
+    pub id: Option<String>,
+    /// Commit time in milliseconds since the epoch (`None` when the SCM reports `-1`).
+    #[serde(default, deserialize_with = "deserialize_opt_timestamp")]
+    pub timestamp: Option<u64>,
+    #[serde(default)]
+    pub author: Option<PersonUser>,
+    #[serde(default)]
+    pub author_email: Option<String>,
+    /// First line of the commit message.
+    #[serde(default)]
+    pub msg: Option<String>,
+    /// Full commit message.
+    #[serde(default)]
+    pub comment: Option<String>,
+    #[serde(default)]
+    pub affected_paths: Vec<String>,
+    #[serde(default)]
+    pub paths: Vec<AffectedPath>,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+impl ChangeSetItem {
+    /// `commitId`, falling back to `id`.
+    #[must_use]
+    pub fn revision(&self) -> Option<&str> {
+        self.commit_id.as_deref().or(self.id.as_deref())
+    }
+}
+
+/// `ChangeLogSet.Entry.getTimestamp()` exports `-1` for unknown commit times.
+fn deserialize_opt_timestamp<'de, D>(deserializer: D) -> Result<Option<u64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = Option::<i64>::deserialize(deserializer)?;
+    Ok(value.and_then(|millis| u64::try_from(millis).ok()))
+}
+
+/// File touched by a commit, with its edit type.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct AffectedPath {
+    #[serde(default)]
+    pub edit_type: Option<EditType>,
+    #[serde(default)]
+    pub file: String,
+}
+
+/// How a commit changed a file.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+#[non_exhaustive]
+pub enum EditType {
+    Add,
+    Edit,
+    Delete,
+    /// Edit type string not known to this SDK.
+    Unknown(String),
+}
+
+impl EditType {
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Add => "add",
+            Self::Edit => "edit",
+            Self::Delete => "delete",
+            Self::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for EditType {
+    fn from(value: &str) -> Self {
+        match value {
+            "add" => Self::Add,
+            "edit" => Self::Edit,
+            "delete" => Self::Delete,
+            other => Self::Unknown(other.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EditType {
+    fn from(value: String) -> Self {
+        match Self::from(value.as_str()) {
+            Self::Unknown(_) => Self::Unknown(value),
+            known => known,
+        }
+    }
+}
+
+impl From<EditType> for String {
+    fn from(value: EditType) -> Self {
+        match value {
+            EditType::Unknown(value) => value,
+            known => known.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for EditType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
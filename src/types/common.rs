@@ -220,6 +220,64 @@ impl From<String> for ComputerName {
     }
 }
 
+/// A Pipeline flow node id (stage or step), as used in `/execution/node/<id>/`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct FlowNodeId(String);
+
+impl FlowNodeId {
+    #[must_use]
+    pub fn new(value: impl Into<String>) -> Self {
+        Self(value.into())
+    }
+
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<&str> for FlowNodeId {
+    fn from(value: &str) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<String> for FlowNodeId {
+    fn from(value: String) -> Self {
+        Self::new(value)
+    }
+}
+
+/// A Pipeline `input` step id, as used in `/input/<id>/`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct InputId(String);
+
+impl InputId {
+    #[must_use]
+    pub fn new(value: impl Into<String>) -> Self {
+        Self(value.into())
+    }
+
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<&str> for InputId {
+    fn from(value: &str) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<String> for InputId {
+    fn from(value: String) -> Self {
+        Self::new(value)
+    }
+}
+
 /// A Jenkins job entry in `GET /api/json`.
 #[derive(Debug, Serialize, Deserialize)]
 #[non_exhaustive]
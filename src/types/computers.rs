@@ -1,5 +1,6 @@
+use crate::{ComputerName, SecretString};
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
+use serde_json::{Value, json};
 use std::collections::BTreeMap;
 
 /// Computers list payload (`GET /computer/api/json`).
@@ -64,3 +65,460 @@ pub struct ComputerInfo {
     #[serde(flatten)]
     pub extra: BTreeMap<String, Value>,
 }
+
+/// `Usage` of a node: any job, or only jobs tied to its labels.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NodeMode {
+    /// Use this node as much as possible.
+    #[default]
+    Normal,
+    /// Only build jobs with label expressions matching this node.
+    Exclusive,
+}
+
+impl NodeMode {
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Normal => "NORMAL",
+            Self::Exclusive => "EXCLUSIVE",
+        }
+    }
+}
+
+/// When Jenkins keeps the agent connected.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RetentionStrategy {
+    /// Keep the agent online as much as possible.
+    #[default]
+    Always,
+    /// Connect when jobs wait for `in_demand_delay` minutes, disconnect after `idle_delay`
+    /// idle minutes.
+    Demand {
+        in_demand_delay: u64,
+        idle_delay: u64,
+    },
+}
+
+impl RetentionStrategy {
+    fn to_json(self) -> Value {
+        match self {
+            Self::Always => described("hudson.slaves.RetentionStrategy$Always", json!({})),
+            Self::Demand {
+                in_demand_delay,
+                idle_delay,
+            } => described(
+                "hudson.slaves.RetentionStrategy$Demand",
+                json!({ "inDemandDelay": in_demand_delay, "idleDelay": idle_delay }),
+            ),
+        }
+    }
+}
+
+/// Inbound (JNLP) launcher: the agent connects to the controller.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct InboundLauncher {
+    web_socket: bool,
+    work_dir: Option<String>,
+}
+
+impl InboundLauncher {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Connect over WebSocket instead of the TCP agent port.
+    #[must_use]
+    pub fn web_socket(mut self, enabled: bool) -> Self {
+        self.web_socket = enabled;
+        self
+    }
+
+    /// Remoting work directory; defaults to the node's remote root directory.
+    #[must_use]
+    pub fn work_dir(mut self, path: impl Into<String>) -> Self {
+        self.work_dir = Some(path.into());
+        self
+    }
+
+    fn to_json(&self) -> Value {
+        described(
+            "hudson.slaves.JNLPLauncher",
+            json!({
+                "webSocket": self.web_socket,
+                "workDirSettings": {
+                    "disabled": false,
+                    "workDirPath": self.work_dir.as_deref().unwrap_or_default(),
+                    "internalDir": "remoting",
+                    "failIfWorkDirIsMissing": false,
+                },
+            }),
+        )
+    }
+}
+
+/// Host key check of the SSH launcher.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SshHostKeyVerification {
+    /// Trust the key seen on first connection, then require it.
+    #[default]
+    TrustOnFirstUse,
+    /// Require an entry in the controller's `~/.ssh/known_hosts`.
+    KnownHosts,
+    /// Accept any key.
+    NonVerifying,
+}
+
+impl SshHostKeyVerification {
+    fn to_json(self) -> Value {
+        const PREFIX: &str = "hudson.plugins.sshslaves.verifiers.";
+        match self {
+            Self::TrustOnFirstUse => described(
+                &format!("{PREFIX}ManuallyTrustedKeyVerificationStrategy"),
+                json!({ "requireInitialManualTrust": false }),
+            ),
+            Self::KnownHosts => described(
+                &format!("{PREFIX}KnownHostsFileKeyVerificationStrategy"),
+                json!({}),
+            ),
+            Self::NonVerifying => described(
+                &format!("{PREFIX}NonVerifyingKeyVerificationStrategy"),
+                json!({}),
+            ),
+        }
+    }
+}
+
+/// SSH launcher (SSH Build Agents plugin): the controller connects to the agent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SshLauncher {
+    host: String,
+    port: u16,
+    credentials_id: String,
+    host_key_verification: SshHostKeyVerification,
+}
+
+impl SshLauncher {
+    /// Port 22, with `credentials_id` naming an SSH key or username/password credential.
+    #[must_use]
+    pub fn new(host: impl Into<String>, credentials_id: impl Into<String>) -> Self {
+        Self {
+            host: host.into(),
+            port: 22,
+            credentials_id: credentials_id.into(),
+            host_key_verification: SshHostKeyVerification::default(),
+        }
+    }
+
+    #[must_use]
+    pub fn port(mut self, port: u16) -> Self {
+        self.port = port;
+        self
+    }
+
+    #[must_use]
+    pub fn host_key_verification(mut self, verification: SshHostKeyVerification) -> Self {
+        self.host_key_verification = verification;
+        self
+    }
+
+    fn to_json(&self) -> Value {
+        described(
+            "hudson.plugins.sshslaves.SSHLauncher",
+            json!({
+                "host": self.host,
+                "port": self.port,
+                "credentialsId": self.credentials_id,
+                "sshHostKeyVerificationStrategy": self.host_key_verification.to_json(),
+            }),
+        )
+    }
+}
+
+/// How the controller starts the agent process.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NodeLauncher {
+    Inbound(InboundLauncher),
+    Ssh(SshLauncher),
+}
+
+impl Default for NodeLauncher {
+    fn default() -> Self {
+        Self::Inbound(InboundLauncher::default())
+    }
+}
+
+impl From<InboundLauncher> for NodeLauncher {
+    fn from(value: InboundLauncher) -> Self {
+        Self::Inbound(value)
+    }
+}
+
+impl From<SshLauncher> for NodeLauncher {
+    fn from(value: SshLauncher) -> Self {
+        Self::Ssh(value)
+    }
+}
+
+impl NodeLauncher {
+    fn to_json(&self) -> Value {
+        match self {
+            Self::Inbound(launcher) => launcher.to_json(),
+            Self::Ssh(launcher) => launcher.to_json(),
+        }
+    }
+}
+
+/// Permanent agent (`hudson.slaves.DumbSlave`) for `ComputersService::create`.
+///
+/// Defaults: one executor, [`NodeMode::Normal`], [`RetentionStrategy::Always`] and an
+/// [`InboundLauncher`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NodeSpec {
+    name: ComputerName,
+    description: String,
+    remote_fs: String,
+    executors: u32,
+    labels: Vec<String>,
+    mode: NodeMode,
+    retention: RetentionStrategy,
+    launcher: NodeLauncher,
+    env: BTreeMap<String, String>,
+    /// `(descriptor@installation, home)`
+    tool_locations: Vec<(String, String)>,
+}
+
+impl NodeSpec {
+    /// Node `name` with remote root directory `remote_fs`.
+    #[must_use]
+    pub fn new(name: impl Into<ComputerName>, remote_fs: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            description: String::new(),
+            remote_fs: remote_fs.into(),
+            executors: 1,
+            labels: Vec::new(),
+            mode: NodeMode::default(),
+            retention: RetentionStrategy::default(),
+            launcher: NodeLauncher::default(),
+            env: BTreeMap::new(),
+            tool_locations: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = description.into();
+        self
+    }
+
+    #[must_use]
+    pub fn executors(mut self, executors: u32) -> Self {
+        self.executors = executors;
+        self
+    }
+
+    #[must_use]
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.labels.push(label.into());
+        self
+    }
+
+    #[must_use]
+    pub fn labels<I, S>(mut self, labels: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.labels.extend(labels.into_iter().map(Into::into));
+        self
+    }
+
+    #[must_use]
+    pub fn mode(mut self, mode: NodeMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    #[must_use]
+    pub fn retention(mut self, retention: RetentionStrategy) -> Self {
+        self.retention = retention;
+        self
+    }
+
+    #[must_use]
+    pub fn launcher(mut self, launcher: impl Into<NodeLauncher>) -> Self {
+        self.launcher = launcher.into();
+        self
+    }
+
+    /// Environment variable of the node (`EnvironmentVariablesNodeProperty`).
+    #[must_use]
+    pub fn env(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.env.insert(key.into(), value.into());
+        self
+    }
+
+    /// Tool home on this node (`ToolLocationNodeProperty`), e.g.
+    /// `tool_location("hudson.model.JDK$DescriptorImpl", "jdk17", "/opt/jdk-17")`.
+    #[must_use]
+    pub fn tool_location(
+        mut self,
+        descriptor: impl AsRef<str>,
+        installation: impl AsRef<str>,
+        home: impl Into<String>,
+    ) -> Self {
+        self.tool_locations.push((
+            format!("{}@{}", descriptor.as_ref(), installation.as_ref()),
+            home.into(),
+        ));
+        self
+    }
+
+    #[must_use]
+    pub fn name(&self) -> &ComputerName {
+        &self.name
+    }
+
+    /// Form fields of `POST /computer/doCreateItem`.
+    pub(crate) fn to_form(&self) -> Vec<(&'static str, String)> {
+        let launcher = self.launcher.to_json();
+        let retention = self.retention.to_json();
+        let mut properties = json!({ "stapler-class-bag": "true" });
+        if !self.env.is_empty() {
+            let env: Vec<Value> = self
+                .env
+                .iter()
+                .map(|(key, value)| json!({ "key": key, "value": value }))
+                .collect();
+            properties["hudson-slaves-EnvironmentVariablesNodeProperty"] = json!({ "env": env });
+        }
+        if !self.tool_locations.is_empty() {
+            let locations: Vec<Value> = self
+                .tool_locations
+                .iter()
+                .map(|(key, home)| json!({ "key": key, "home": home }))
+                .collect();
+            properties["hudson-tools-ToolLocationNodeProperty"] = json!({ "locations": locations });
+        }
+        let form = json!({
+            "name": self.name.as_str(),
+            "nodeDescription": self.description,
+            "numExecutors": self.executors,
+            "remoteFS": self.remote_fs,
+            "labelString": self.labels.join(" "),
+            "mode": self.mode.as_str(),
+            "": [launcher["$class"], retention["$class"]],
+            "launcher": launcher,
+            "retentionStrategy": retention,
+            "nodeProperties": properties,
+            "type": DUMB_SLAVE,
+        });
+        vec![
+            ("name", self.name.as_str().to_owned()),
+            ("type", DUMB_SLAVE.to_owned()),
+            ("json", form.to_string()),
+        ]
+    }
+}
+
+const DUMB_SLAVE: &str = "hudson.slaves.DumbSlave";
+
+/// Stapler form object for a describable: `fields` plus its class under both keys.
+fn described(class: &str, mut fields: Value) -> Value {
+    fields["stapler-class"] = json!(class);
+    fields["$class"] = json!(class);
+    fields
+}
+
+/// Inbound agent launch parameters from `/computer/<name>/jenkins-agent.jnlp`.
+///
+/// `Debug` never prints the secret.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct JnlpLaunchInfo {
+    pub name: String,
+    pub secret: SecretString,
+    /// Controller root URL (`-url`).
+    pub url: Option<String>,
+    /// `-workDir`, when the node has a remoting work directory.
+    pub work_dir: Option<String>,
+    /// `-internalDir` below the work directory, usually `remoting`.
+    pub internal_dir: Option<String>,
+    pub fail_if_work_dir_is_missing: bool,
+    /// Connect over WebSocket (`-webSocket`).
+    pub web_socket: bool,
+    /// `HOST:PORT` of the TCP agent listener override (`-tunnel`).
+    pub tunnel: Option<String>,
+    /// Descriptor arguments after the secret and node name, in order.
+    pub options: Vec<String>,
+}
+
+impl JnlpLaunchInfo {
+    /// Read the `<application-desc>` arguments (`<secret> <name> [options]`).
+    pub(crate) fn from_arguments(arguments: &[String]) -> Option<Self> {
+        let [secret, name, options @ ..] = arguments else {
+            return None;
+        };
+        let mut info = Self {
+            name: name.clone(),
+            secret: SecretString::new(secret.clone()),
+            url: None,
+            work_dir: None,
+            internal_dir: None,
+            fail_if_work_dir_is_missing: false,
+            web_socket: false,
+            tunnel: None,
+            options: options.to_vec(),
+        };
+        let mut options = options.iter();
+        while let Some(option) = options.next() {
+            match option.as_str() {
+                "-url" => info.url = options.next().cloned(),
+                "-workDir" => info.work_dir = options.next().cloned(),
+                "-internalDir" => info.internal_dir = options.next().cloned(),
+                "-tunnel" => info.tunnel = options.next().cloned(),
+                "-webSocket" => info.web_socket = true,
+                "-failIfWorkDirIsMissing" => info.fail_if_work_dir_is_missing = true,
+                _ => {}
+            }
+        }
+        Some(info)
+    }
+
+    /// Arguments for `java -jar agent.jar`, secret included.
+    #[must_use]
+    pub fn agent_args(&self) -> Vec<String> {
+        let mut args = Vec::new();
+        if let Some(url) = &self.url {
+            args.extend(["-url".to_owned(), url.clone()]);
+        }
+        args.extend([
+            "-secret".to_owned(),
+            self.secret.expose().to_owned(),
+            "-name".to_owned(),
+            self.name.clone(),
+        ]);
+        if let Some(work_dir) = &self.work_dir {
+            args.extend(["-workDir".to_owned(), work_dir.clone()]);
+        }
+        if let Some(internal_dir) = &self.internal_dir {
+            args.extend(["-internalDir".to_owned(), internal_dir.clone()]);
+        }
+        if self.fail_if_work_dir_is_missing {
+            args.push("-failIfWorkDirIsMissing".to_owned());
+        }
+        if self.web_socket {
+            args.push("-webSocket".to_owned());
+        }
+        if let Some(tunnel) = &self.tunnel {
+            args.extend(["-tunnel".to_owned(), tunnel.clone()]);
+        }
+        args
+    }
+}
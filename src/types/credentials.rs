@@ -0,0 +1,269 @@
+use crate::util::xml;
+use crate::{JobPath, SecretString};
+use base64::{Engine, engine::general_purpose::STANDARD as B64};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// Credentials store and domain, e.g. the system store or a folder's store.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CredentialStore {
+    folder: Option<JobPath>,
+    domain: String,
+}
+
+impl CredentialStore {
+    /// `/credentials/store/system/domain/_/`
+    #[must_use]
+    pub fn system() -> Self {
+        Self {
+            folder: None,
+            domain: "_".to_owned(),
+        }
+    }
+
+    /// `/job/<folder>/credentials/store/folder/domain/_/`
+    #[must_use]
+    pub fn folder(path: impl Into<JobPath>) -> Self {
+        Self {
+            folder: Some(path.into()),
+            domain: "_".to_owned(),
+        }
+    }
+
+    /// Use a named domain instead of the global one (`_`).
+    #[must_use]
+    pub fn domain(mut self, domain: impl Into<String>) -> Self {
+        self.domain = domain.into();
+        self
+    }
+
+    pub(crate) fn url_segments(&self) -> Vec<String> {
+        let mut segments: Vec<String> = match &self.folder {
+            Some(folder) => folder.url_segments().map(str::to_owned).collect(),
+            None => Vec::new(),
+        };
+        let store = if self.folder.is_some() {
+            "folder"
+        } else {
+            "system"
+        };
+        segments.extend(["credentials", "store", store, "domain", &self.domain].map(str::to_owned));
+        segments
+    }
+}
+
+/// Credential as listed by a store domain: metadata only, never the secret.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct CredentialInfo {
+    pub id: String,
+    #[serde(default)]
+    pub display_name: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Human readable type, e.g. `Username with password`.
+    #[serde(default)]
+    pub type_name: Option<String>,
+    #[serde(default)]
+    pub full_name: Option<String>,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+/// Where a credential may be used.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CredentialScope {
+    /// Available to jobs and the controller.
+    #[default]
+    Global,
+    /// Controller-only (e.g. agent launchers), hidden from jobs.
+    System,
+}
+
+impl CredentialScope {
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Global => "GLOBAL",
+            Self::System => "SYSTEM",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum CredentialKind {
+    UsernamePassword {
+        username: String,
+        password: SecretString,
+    },
+    SecretText {
+        secret: SecretString,
+    },
+    SshPrivateKey {
+        username: String,
+        private_key: SecretString,
+        passphrase: Option<SecretString>,
+    },
+    SecretFile {
+        file_name: String,
+        /// Base64 of the file content.
+        content: SecretString,
+    },
+}
+
+/// Credential to create or replace, rendered to the `config.xml` of its plugin type.
+///
+/// `Debug` never prints secret values.
+#[derive(Debug, Clone)]
+pub struct CredentialSpec {
+    id: String,
+    description: Option<String>,
+    scope: CredentialScope,
+    kind: CredentialKind,
+}
+
+impl CredentialSpec {
+    fn new(id: impl Into<String>, kind: CredentialKind) -> Self {
+        Self {
+            id: id.into(),
+            description: None,
+            scope: CredentialScope::default(),
+            kind,
+        }
+    }
+
+    /// Username with password (Credentials plugin).
+    #[must_use]
+    pub fn username_password(
+        id: impl Into<String>,
+        username: impl Into<String>,
+        password: SecretString,
+    ) -> Self {
+        Self::new(
+            id,
+            CredentialKind::UsernamePassword {
+                username: username.into(),
+                password,
+            },
+        )
+    }
+
+    /// Secret text (Plain Credentials plugin).
+    #[must_use]
+    pub fn secret_text(id: impl Into<String>, secret: SecretString) -> Self {
+        Self::new(id, CredentialKind::SecretText { secret })
+    }
+
+    /// SSH username with private key entered directly (SSH Credentials plugin).
+    #[must_use]
+    pub fn ssh_private_key(
+        id: impl Into<String>,
+        username: impl Into<String>,
+        private_key: SecretString,
+    ) -> Self {
+        Self::new(
+            id,
+            CredentialKind::SshPrivateKey {
+                username: username.into(),
+                private_key,
+                passphrase: None,
+            },
+        )
+    }
+
+    /// Secret file (Plain Credentials plugin).
+    #[must_use]
+    pub fn secret_file(
+        id: impl Into<String>,
+        file_name: impl Into<String>,
+        content: impl AsRef<[u8]>,
+    ) -> Self {
+        Self::new(
+            id,
+            CredentialKind::SecretFile {
+                file_name: file_name.into(),
+                content: SecretString::new(B64.encode(content)),
+            },
+        )
+    }
+
+    #[must_use]
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    #[must_use]
+    pub fn scope(mut self, scope: CredentialScope) -> Self {
+        self.scope = scope;
+        self
+    }
+
+    /// Passphrase of an SSH private key; ignored for other types.
+    #[must_use]
+    pub fn passphrase(mut self, passphrase: SecretString) -> Self {
+        if let CredentialKind::SshPrivateKey { passphrase: p, .. } = &mut self.kind {
+            *p = Some(passphrase);
+        }
+        self
+    }
+
+    #[must_use]
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub(crate) fn to_xml(&self) -> Vec<u8> {
+        let (class, fields) = match &self.kind {
+            CredentialKind::UsernamePassword { username, password } => (
+                "com.cloudbees.plugins.credentials.impl.UsernamePasswordCredentialsImpl",
+                format!(
+                    "  <username>{}</username>\n  <password>{}</password>\n",
+                    xml::escape(username),
+                    xml::escape(password.expose())
+                ),
+            ),
+            CredentialKind::SecretText { secret } => (
+                "org.jenkinsci.plugins.plaincredentials.impl.StringCredentialsImpl",
+                format!("  <secret>{}</secret>\n", xml::escape(secret.expose())),
+            ),
+            CredentialKind::SshPrivateKey {
+                username,
+                private_key,
+                passphrase,
+            } => (
+                "com.cloudbees.jenkins.plugins.sshcredentials.impl.BasicSSHUserPrivateKey",
+                format!(
+                    "  <username>{}</username>\n  <privateKeySource class=\"com.cloudbees.jenkins.plugins.sshcredentials.impl.BasicSSHUserPrivateKey$DirectEntryPrivateKeySource\">\n    <privateKey>{}</privateKey>\n  </privateKeySource>\n  <passphrase>{}</passphrase>\n",
+                    xml::escape(username),
+                    xml::escape(private_key.expose()),
+                    passphrase
+                        .as_ref()
+                        .map(|p| xml::escape(p.expose()))
+                        .unwrap_or_default()
+                ),
+            ),
+            CredentialKind::SecretFile { file_name, content } => (
+                "org.jenkinsci.plugins.plaincredentials.impl.FileCredentialsImpl",
+                format!(
+                    "  <fileName>{}</fileName>\n  <secretBytes>{}</secretBytes>\n",
+                    xml::escape(file_name),
+                    content.expose()
+                ),
+            ),
+        };
+        format!(
+            "<{class}>\n  <scope>{}</scope>\n  <id>{}</id>\n  <description>{}</description>\n{fields}</{class}>\n",
+            self.scope.as_str(),
+            xml::escape(&self.id),
+            self.description
+                .as_deref()
+                .map(xml::escape)
+                .unwrap_or_default()
+        )
+        .into_bytes()
+    }
+}
@@ -1,7 +1,15 @@
-use crate::QueueItemId;
+use crate::{
+    BuildCause, ChangeSet, ChangeSetItem, JobPath, ParameterDefinition, PersonUser, QueueItem,
+    QueueItemId,
+};
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::collections::BTreeMap;
+use std::fmt;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 /// Result of triggering a build.
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -13,6 +21,337 @@ pub struct TriggeredBuild {
     pub location: Option<Box<str>>,
 }
 
+/// Build result reported by Jenkins (`BuildInfo::result`).
+///
+/// Known variants follow the order of Jenkins' `hudson.model.Result` ordinals
+/// (`SUCCESS` < `UNSTABLE` < `FAILURE` < `NOT_BUILT` < `ABORTED`); unrecognised values are
+/// kept verbatim in [`BuildResult::Unknown`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum BuildResult {
+    Success,
+    Unstable,
+    Failure,
+    NotBuilt,
+    Aborted,
+    Unknown(String),
+}
+
+impl BuildResult {
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Success => "SUCCESS",
+            Self::Unstable => "UNSTABLE",
+            Self::Failure => "FAILURE",
+            Self::NotBuilt => "NOT_BUILT",
+            Self::Aborted => "ABORTED",
+            Self::Unknown(value) => value,
+        }
+    }
+
+    /// Jenkins ordinal of the result (`None` for unknown values).
+    #[must_use]
+    pub fn ordinal(&self) -> Option<u8> {
+        match self {
+            Self::Success => Some(0),
+            Self::Unstable => Some(1),
+            Self::Failure => Some(2),
+            Self::NotBuilt => Some(3),
+            Self::Aborted => Some(4),
+            Self::Unknown(_) => None,
+        }
+    }
+
+    #[must_use]
+    pub fn is_success(&self) -> bool {
+        matches!(self, Self::Success)
+    }
+
+    /// `true` when both results are known and `self` has a higher ordinal than `other`.
+    #[must_use]
+    pub fn is_worse_than(&self, other: &Self) -> bool {
+        matches!((self.ordinal(), other.ordinal()), (Some(a), Some(b)) if a > b)
+    }
+
+    /// `true` when both results are known and `self` has a lower ordinal than `other`.
+    #[must_use]
+    pub fn is_better_than(&self, other: &Self) -> bool {
+        other.is_worse_than(self)
+    }
+}
+
+impl From<&str> for BuildResult {
+    fn from(value: &str) -> Self {
+        match value {
+            "SUCCESS" => Self::Success,
+            "UNSTABLE" => Self::Unstable,
+            "FAILURE" => Self::Failure,
+            "NOT_BUILT" => Self::NotBuilt,
+            "ABORTED" => Self::Aborted,
+            other => Self::Unknown(other.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BuildResult {
+    fn from(value: String) -> Self {
+        match Self::from(value.as_str()) {
+            Self::Unknown(_) => Self::Unknown(value),
+            known => known,
+        }
+    }
+}
+
+impl From<BuildResult> for String {
+    fn from(value: BuildResult) -> Self {
+        match value {
+            BuildResult::Unknown(value) => value,
+            known => known.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for BuildResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Last build result implied by a job ball `color` (for example `blue`, `red_anime`).
+///
+/// Returns `None` for colors that carry no result (`disabled`, `grey`, unknown values).
+fn result_from_color(color: &str) -> Option<BuildResult> {
+    let color = color.strip_suffix("_anime").unwrap_or(color);
+    match color {
+        "blue" => Some(BuildResult::Success),
+        "yellow" => Some(BuildResult::Unstable),
+        "red" => Some(BuildResult::Failure),
+        "notbuilt" => Some(BuildResult::NotBuilt),
+        "aborted" => Some(BuildResult::Aborted),
+        _ => None,
+    }
+}
+
+/// Polling configuration for `wait_for_build` / `trigger_and_wait`.
+#[derive(Debug, Clone)]
+pub struct WaitConfig {
+    /// Delay between two consecutive polls of the queue item or build.
+    pub poll_interval: Duration,
+    /// Overall deadline for the wait. `None` waits until the build finishes.
+    pub timeout: Option<Duration>,
+}
+
+impl Default for WaitConfig {
+    fn default() -> Self {
+        Self {
+            poll_interval: Duration::from_secs(2),
+            timeout: None,
+        }
+    }
+}
+
+impl WaitConfig {
+    #[must_use]
+    pub fn new(poll_interval: Duration, timeout: Duration) -> Self {
+        Self {
+            poll_interval,
+            timeout: Some(timeout),
+        }
+    }
+}
+
+/// Polling configuration for `follow_console` / `follow_console_lines`.
+#[derive(Debug, Clone)]
+pub struct ConsoleFollowConfig {
+    /// Byte offset to start reading from (`0` streams the whole log).
+    pub start: u64,
+    /// Delay between polls while the build keeps producing output.
+    pub poll_interval: Duration,
+    /// Upper bound for the delay while the build is idle (the delay doubles per empty poll).
+    pub max_poll_interval: Duration,
+}
+
+impl Default for ConsoleFollowConfig {
+    fn default() -> Self {
+        Self {
+            start: 0,
+            poll_interval: Duration::from_secs(1),
+            max_poll_interval: Duration::from_secs(10),
+        }
+    }
+}
+
+impl ConsoleFollowConfig {
+    #[must_use]
+    pub fn new(poll_interval: Duration, max_poll_interval: Duration) -> Self {
+        Self {
+            poll_interval,
+            max_poll_interval,
+            ..Self::default()
+        }
+    }
+
+    /// Resume from a previously observed `X-Text-Size` offset.
+    #[must_use]
+    pub fn start(mut self, offset: u64) -> Self {
+        self.start = offset;
+        self
+    }
+}
+
+/// Paging and filters for `builds` (build history, newest first).
+#[derive(Debug, Clone)]
+pub struct BuildQuery {
+    /// Builds fetched per `allBuilds{from,to}` request.
+    pub page_size: usize,
+    /// Stop after this many matching builds.
+    pub limit: Option<usize>,
+    /// Keep only builds with one of these results (empty keeps all, including running ones).
+    pub results: Vec<BuildResult>,
+    /// Keep only builds started at or after this time; paging stops at the first older build.
+    pub since: Option<SystemTime>,
+    /// Keep only builds started before this time.
+    pub until: Option<SystemTime>,
+    /// Keep only builds whose recorded parameters have these values.
+    pub parameters: Vec<(String, String)>,
+}
+
+impl Default for BuildQuery {
+    fn default() -> Self {
+        Self {
+            page_size: 100,
+            limit: None,
+            results: Vec::new(),
+            since: None,
+            until: None,
+            parameters: Vec::new(),
+        }
+    }
+}
+
+impl BuildQuery {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    #[must_use]
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Add an accepted result.
+    #[must_use]
+    pub fn result(mut self, result: BuildResult) -> Self {
+        self.results.push(result);
+        self
+    }
+
+    #[must_use]
+    pub fn since(mut self, since: SystemTime) -> Self {
+        self.since = Some(since);
+        self
+    }
+
+    #[must_use]
+    pub fn until(mut self, until: SystemTime) -> Self {
+        self.until = Some(until);
+        self
+    }
+
+    /// Require a parameter value.
+    #[must_use]
+    pub fn parameter(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.parameters.push((name.into(), value.into()));
+        self
+    }
+
+    /// Whether `build` started before [`Self::since`].
+    #[must_use]
+    pub fn is_before_window(&self, build: &BuildInfo) -> bool {
+        match (self.since, build.timestamp) {
+            (Some(since), Some(timestamp)) => timestamp < unix_millis(since),
+            _ => false,
+        }
+    }
+
+    /// Whether `build` passes every filter.
+    #[must_use]
+    pub fn matches(&self, build: &BuildInfo) -> bool {
+        if !self.results.is_empty()
+            && !build
+                .result
+                .as_ref()
+                .is_some_and(|result| self.results.contains(result))
+        {
+            return false;
+        }
+        if self.is_before_window(build) {
+            return false;
+        }
+        if let (Some(until), Some(timestamp)) = (self.until, build.timestamp)
+            && timestamp >= unix_millis(until)
+        {
+            return false;
+        }
+        if self.parameters.is_empty() {
+            return true;
+        }
+        let recorded = build.parameters();
+        self.parameters.iter().all(|(name, value)| {
+            recorded
+                .iter()
+                .any(|p| &p.name == name && p.form_value().as_deref() == Some(value.as_str()))
+        })
+    }
+}
+
+fn unix_millis(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH).map_or(0, |elapsed| {
+        u64::try_from(elapsed.as_millis()).unwrap_or(u64::MAX)
+    })
+}
+
+/// Final state observed by `wait_for_build` / `trigger_and_wait`.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum BuildOutcome {
+    /// The build started and finished; carries the final build payload.
+    Completed(BuildInfo),
+    /// The queue item was cancelled before any build started.
+    Cancelled(QueueItem),
+}
+
+impl BuildOutcome {
+    /// Final build payload when the build ran to completion.
+    #[must_use]
+    pub fn build_info(&self) -> Option<&BuildInfo> {
+        match self {
+            Self::Completed(info) => Some(info),
+            Self::Cancelled(_) => None,
+        }
+    }
+
+    /// Final build result when the build ran to completion.
+    #[must_use]
+    pub fn result(&self) -> Option<&BuildResult> {
+        self.build_info()?.result.as_ref()
+    }
+
+    #[must_use]
+    pub fn is_cancelled(&self) -> bool {
+        matches!(self, Self::Cancelled(_))
+    }
+}
+
 /// Path to an artifact within a build.
 ///
 /// The SDK will translate a path like `a/b.txt` into URL segments: `.../artifact/a/b.txt`.
@@ -48,6 +387,111 @@ impl From<String> for ArtifactPath {
     }
 }
 
+/// Progress snapshot reported while an artifact download is running.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct DownloadProgress {
+    /// Bytes of the artifact present at the destination, including a resumed prefix.
+    pub downloaded: u64,
+    /// Full artifact size when the server reported it.
+    pub total: Option<u64>,
+}
+
+/// Callback invoked after every chunk written by an artifact download.
+pub type DownloadProgressFn = Arc<dyn Fn(DownloadProgress) + Send + Sync>;
+
+/// Options for `download_artifact_to_writer` / `download_artifact_to_path`.
+#[derive(Clone, Default)]
+pub struct DownloadConfig {
+    /// Byte offset already present at the destination; sent as `Range: bytes=<offset>-`.
+    ///
+    /// Servers that ignore the range send the whole artifact again; the prefix is then skipped.
+    pub resume_from: Option<u64>,
+    /// Expected MD5 digest (hex) of the complete artifact.
+    pub expected_md5: Option<String>,
+    /// Verify against the MD5 Jenkins recorded in the build's fingerprints.
+    pub verify_fingerprint: bool,
+    /// Progress callback, see [`DownloadProgress`].
+    pub progress: Option<DownloadProgressFn>,
+}
+
+impl fmt::Debug for DownloadConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DownloadConfig")
+            .field("resume_from", &self.resume_from)
+            .field("expected_md5", &self.expected_md5)
+            .field("verify_fingerprint", &self.verify_fingerprint)
+            .field("progress", &self.progress.is_some())
+            .finish()
+    }
+}
+
+impl DownloadConfig {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Continue a partial download that already holds `offset` bytes.
+    #[must_use]
+    pub fn resume_from(mut self, offset: u64) -> Self {
+        self.resume_from = Some(offset);
+        self
+    }
+
+    /// Fail with [`crate::Error::ChecksumMismatch`] unless the artifact hashes to `md5`.
+    #[must_use]
+    pub fn expected_md5(mut self, md5: impl Into<String>) -> Self {
+        self.expected_md5 = Some(md5.into());
+        self
+    }
+
+    /// Look up the expected MD5 in the build's Jenkins fingerprints.
+    #[must_use]
+    pub fn verify_fingerprint(mut self) -> Self {
+        self.verify_fingerprint = true;
+        self
+    }
+
+    /// Report progress after every chunk written.
+    #[must_use]
+    pub fn on_progress<F>(mut self, progress: F) -> Self
+    where
+        F: Fn(DownloadProgress) + Send + Sync + 'static,
+    {
+        self.progress = Some(Arc::new(progress));
+        self
+    }
+}
+
+/// Result of a streaming artifact download.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct DownloadSummary {
+    /// Bytes written to the destination by this call.
+    pub bytes_written: u64,
+    /// Size of the artifact at the destination (resumed prefix included).
+    pub total_bytes: u64,
+    /// Offset the server resumed from (`0` when the full artifact was sent).
+    pub resumed_from: u64,
+    /// MD5 of the complete artifact (hex), when it could be computed.
+    pub md5: Option<String>,
+}
+
+/// One entry of a build's `fingerprint` list.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct Fingerprint {
+    #[serde(default)]
+    pub file_name: Option<String>,
+    /// MD5 digest (hex) of the fingerprinted file.
+    #[serde(default)]
+    pub hash: Option<String>,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
 /// Result of `.../logText/progressiveText`.
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[non_exhaustive]
@@ -65,6 +509,9 @@ pub struct ProgressiveText {
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
 pub struct JobSummary {
+    /// Job class, e.g. `org.jenkinsci.plugins.workflow.job.WorkflowJob`.
+    #[serde(default, rename = "_class")]
+    pub class: Option<String>,
     pub name: String,
     #[serde(default)]
     pub url: Option<String>,
@@ -74,6 +521,128 @@ pub struct JobSummary {
     pub extra: BTreeMap<String, Value>,
 }
 
+impl JobSummary {
+    #[must_use]
+    pub fn kind(&self) -> JobKind {
+        JobKind::from_class(self.class.as_deref().unwrap_or_default())
+    }
+
+    /// Last build result implied by the job `color`, if any.
+    #[must_use]
+    pub fn last_result(&self) -> Option<BuildResult> {
+        self.color.as_deref().and_then(result_from_color)
+    }
+
+    /// Whether the job `color` indicates a build in progress (`*_anime`).
+    #[must_use]
+    pub fn is_building(&self) -> bool {
+        self.color.as_deref().is_some_and(|c| c.ends_with("_anime"))
+    }
+}
+
+/// What a job is, derived from its `_class`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum JobKind {
+    Freestyle,
+    /// Pipeline job (`WorkflowJob`), including the branch jobs of a multibranch project.
+    Pipeline,
+    Folder,
+    /// Organization folder (GitHub organization, Bitbucket team, ...).
+    OrganizationFolder,
+    /// Multibranch project; its children are branch and pull request jobs.
+    Multibranch,
+    /// Any other job class; carries the `_class` (empty when missing).
+    Unknown(String),
+}
+
+impl JobKind {
+    #[must_use]
+    pub fn from_class(class: &str) -> Self {
+        match class {
+            "hudson.model.FreeStyleProject" => Self::Freestyle,
+            "org.jenkinsci.plugins.workflow.job.WorkflowJob" => Self::Pipeline,
+            "com.cloudbees.hudson.plugins.folder.Folder" => Self::Folder,
+            "jenkins.branch.OrganizationFolder" => Self::OrganizationFolder,
+            other if other.ends_with("MultiBranchProject") => Self::Multibranch,
+            other => Self::Unknown(other.to_owned()),
+        }
+    }
+
+    /// Whether jobs of this kind contain other jobs.
+    #[must_use]
+    pub fn is_container(&self) -> bool {
+        matches!(
+            self,
+            Self::Folder | Self::OrganizationFolder | Self::Multibranch
+        )
+    }
+}
+
+/// Options for `walk_jobs`.
+#[derive(Debug, Clone)]
+pub struct JobWalkConfig {
+    /// Folder to start from (`None` walks from the Jenkins root).
+    pub root: Option<JobPath>,
+    /// Deepest level to visit, counted from `root` (`Some(1)` lists only its direct children).
+    pub max_depth: Option<usize>,
+    /// Only yield jobs whose full path matches one of these Ant-style globs (empty yields all).
+    pub include: Vec<String>,
+    /// Skip jobs whose full path matches one of these globs, together with their children.
+    pub exclude: Vec<String>,
+    /// Folder levels fetched per request through nested `jobs[...]` trees.
+    pub levels_per_request: usize,
+}
+
+impl Default for JobWalkConfig {
+    fn default() -> Self {
+        Self {
+            root: None,
+            max_depth: None,
+            include: Vec::new(),
+            exclude: Vec::new(),
+            levels_per_request: 3,
+        }
+    }
+}
+
+impl JobWalkConfig {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn root(mut self, root: impl Into<JobPath>) -> Self {
+        self.root = Some(root.into());
+        self
+    }
+
+    #[must_use]
+    pub fn max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = Some(max_depth);
+        self
+    }
+
+    #[must_use]
+    pub fn include(mut self, pattern: impl Into<String>) -> Self {
+        self.include.push(pattern.into());
+        self
+    }
+
+    #[must_use]
+    pub fn exclude(mut self, pattern: impl Into<String>) -> Self {
+        self.exclude.push(pattern.into());
+        self
+    }
+
+    #[must_use]
+    pub fn levels_per_request(mut self, levels: usize) -> Self {
+        self.levels_per_request = levels;
+        self
+    }
+}
+
 /// Job list payload (for example `GET /api/json?tree=jobs[...]`).
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
 #[non_exhaustive]
@@ -111,6 +680,36 @@ pub struct JobInfo {
     pub extra: BTreeMap<String, Value>,
 }
 
+impl JobInfo {
+    /// Last build result implied by the job `color`, if any.
+    #[must_use]
+    pub fn last_result(&self) -> Option<BuildResult> {
+        self.color.as_deref().and_then(result_from_color)
+    }
+
+    /// Whether the job `color` indicates a build in progress (`*_anime`).
+    #[must_use]
+    pub fn is_building(&self) -> bool {
+        self.color.as_deref().is_some_and(|c| c.ends_with("_anime"))
+    }
+
+    /// Parameters declared by the job's `ParametersDefinitionProperty` (empty when the job
+    /// is not parameterized, or when `property` was not part of the `tree`).
+    #[must_use]
+    pub fn parameter_definitions(&self) -> Vec<ParameterDefinition> {
+        let Some(Value::Array(properties)) = self.extra.get("property") else {
+            return Vec::new();
+        };
+        properties
+            .iter()
+            .filter_map(|property| property.get("parameterDefinitions"))
+            .filter_map(Value::as_array)
+            .flatten()
+            .map(ParameterDefinition::from_value_lossy)
+            .collect()
+    }
+}
+
 /// Build-level API payload (for example `GET /job/<name>/<build>/api/json`).
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -119,7 +718,7 @@ pub struct BuildInfo {
     #[serde(default)]
     pub number: Option<u64>,
     #[serde(default)]
-    pub result: Option<String>,
+    pub result: Option<BuildResult>,
     #[serde(default)]
     pub building: Option<bool>,
     #[serde(default)]
@@ -132,6 +731,320 @@ pub struct BuildInfo {
     pub duration: Option<u64>,
     #[serde(default)]
     pub timestamp: Option<u64>,
+    #[serde(default)]
+    pub artifacts: Vec<Artifact>,
+    /// Changesets of Pipeline (and other multi-SCM) builds.
+    #[serde(default)]
+    pub change_sets: Vec<ChangeSet>,
+    /// Changeset of freestyle builds.
+    #[serde(default)]
+    pub change_set: Option<ChangeSet>,
+    /// Users who committed changes since the last successful build.
+    #[serde(default)]
+    pub culprits: Vec<PersonUser>,
     #[serde(flatten)]
     pub extra: BTreeMap<String, Value>,
 }
+
+impl BuildInfo {
+    /// Causes recorded by the build's `CauseAction` (empty when `actions` was not part of
+    /// the `tree`).
+    #[must_use]
+    pub fn causes(&self) -> Vec<BuildCause> {
+        self.actions_field("causes")
+    }
+
+    /// Every changeset, whichever of `changeSets` / `changeSet` the build reports.
+    pub fn all_change_sets(&self) -> impl Iterator<Item = &ChangeSet> {
+        self.change_sets.iter().chain(self.change_set.as_ref())
+    }
+
+    /// Every commit included in the build, in changeset order.
+    pub fn commits(&self) -> impl Iterator<Item = &ChangeSetItem> {
+        self.all_change_sets().flat_map(|set| set.items.iter())
+    }
+
+    /// Parameters recorded by the build's `ParametersAction` (empty for unparameterized
+    /// builds, or when `actions` was not part of the `tree`).
+    #[must_use]
+    pub fn parameters(&self) -> Vec<BuildParameter> {
+        self.actions_field("parameters")
+    }
+
+    /// Concatenated `field` lists of every entry in `actions`.
+    fn actions_field<T: DeserializeOwned>(&self, field: &str) -> Vec<T> {
+        let Some(Value::Array(actions)) = self.extra.get("actions") else {
+            return Vec::new();
+        };
+        actions
+            .iter()
+            .filter_map(|action| action.get(field))
+            .filter_map(|items| serde_json::from_value::<Vec<T>>(items.clone()).ok())
+            .flatten()
+            .collect()
+    }
+}
+
+/// One parameter value of a build (`ParametersAction.parameters`).
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct BuildParameter {
+    /// Value class, e.g. `hudson.model.StringParameterValue`.
+    #[serde(default, rename = "_class")]
+    pub class: Option<String>,
+    #[serde(default)]
+    pub name: String,
+    /// Not exported for password and file parameters.
+    #[serde(default)]
+    pub value: Option<Value>,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+impl BuildParameter {
+    /// Value as submitted to `buildWithParameters`, when Jenkins exported one.
+    ///
+    /// Run parameters (`{"jobName": .., "number": ..}`) become `<job>#<number>`; other
+    /// object and array values have no form equivalent and yield `None`.
+    #[must_use]
+    pub fn form_value(&self) -> Option<String> {
+        match self.value.as_ref()? {
+            Value::Null | Value::Array(_) => None,
+            Value::String(value) => Some(value.clone()),
+            Value::Bool(value) => Some(value.to_string()),
+            Value::Number(value) => Some(value.to_string()),
+            Value::Object(object) => {
+                let job = object.get("jobName")?.as_str()?;
+                match object.get("number")? {
+                    Value::Number(number) => Some(format!("{job}#{number}")),
+                    Value::String(number) => Some(format!("{job}#{number}")),
+                    _ => None,
+                }
+            }
+        }
+    }
+}
+
+/// One entry of a build's `artifacts` list.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct Artifact {
+    #[serde(default)]
+    pub file_name: String,
+    /// Path relative to the build's artifact root, e.g. `target/app.jar`.
+    #[serde(default)]
+    pub relative_path: String,
+    /// Shortest unambiguous path, as shown in the Jenkins UI.
+    #[serde(default)]
+    pub display_path: Option<String>,
+}
+
+impl Artifact {
+    /// Path to pass to `download_artifact` and friends.
+    #[must_use]
+    pub fn path(&self) -> ArtifactPath {
+        ArtifactPath::new(self.relative_path.as_str())
+    }
+}
+
+/// Artifact written by `mirror_artifacts`.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct MirroredArtifact {
+    pub artifact: Artifact,
+    /// Local file the artifact was written to.
+    pub path: PathBuf,
+    pub summary: DownloadSummary,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CauseKind, EditType, ParameterType};
+
+    #[test]
+    fn build_result_round_trips_known_and_unknown_values() {
+        let parsed: Vec<BuildResult> =
+            serde_json::from_str(r#"["SUCCESS","NOT_BUILT","CUSTOM"]"#).unwrap();
+        assert_eq!(
+            parsed,
+            vec![
+                BuildResult::Success,
+                BuildResult::NotBuilt,
+                BuildResult::Unknown("CUSTOM".to_owned())
+            ]
+        );
+        assert_eq!(
+            serde_json::to_string(&parsed).unwrap(),
+            r#"["SUCCESS","NOT_BUILT","CUSTOM"]"#
+        );
+    }
+
+    #[test]
+    fn job_parameter_definitions_are_typed() {
+        let job: JobInfo = serde_json::from_value(serde_json::json!({
+            "property": [
+                { "_class": "jenkins.model.BuildDiscarderProperty" },
+                {
+                    "_class": "hudson.model.ParametersDefinitionProperty",
+                    "parameterDefinitions": [
+                        {
+                            "name": "TARGET",
+                            "type": "ChoiceParameterDefinition",
+                            "choices": ["staging", "prod"],
+                            "defaultParameterValue": { "name": "TARGET", "value": "staging" }
+                        },
+                        {
+                            "name": "DRY_RUN",
+                            "type": "BooleanParameterDefinition",
+                            "defaultParameterValue": { "name": "DRY_RUN", "value": true }
+                        },
+                        { "name": "LIST", "type": "ExtendedChoiceParameterDefinition" },
+                        {
+                            "name": "VERSION",
+                            "type": "ChoiceParameterDefinition",
+                            "choices": ["1.0"],
+                            "defaultParameterValue": "1.0"
+                        }
+                    ]
+                }
+            ]
+        }))
+        .unwrap();
+
+        let definitions = job.parameter_definitions();
+        assert_eq!(definitions.len(), 4);
+        assert_eq!(definitions[0].parameter_type, Some(ParameterType::Choice));
+        assert_eq!(definitions[0].default_value().as_deref(), Some("staging"));
+        assert!(definitions[0].check_value("prod").is_none());
+        assert!(definitions[0].check_value("production").is_some());
+        assert_eq!(definitions[1].default_value().as_deref(), Some("true"));
+        assert!(definitions[1].check_value("yes").is_some());
+        assert_eq!(
+            definitions[2].parameter_type,
+            Some(ParameterType::Unknown(
+                "ExtendedChoiceParameterDefinition".to_owned()
+            ))
+        );
+        assert_eq!(definitions[3].name, "VERSION");
+        assert_eq!(definitions[3].parameter_type, Some(ParameterType::Choice));
+        assert!(definitions[3].default_parameter_value.is_none());
+        assert_eq!(
+            definitions[3].extra.get("defaultParameterValue"),
+            Some(&serde_json::json!("1.0"))
+        );
+        assert!(definitions[3].check_value("1.0").is_none());
+        assert!(definitions[3].check_value("2.0").is_some());
+    }
+
+    #[test]
+    fn build_info_exposes_causes_changes_and_culprits() {
+        let build: BuildInfo = serde_json::from_value(serde_json::json!({
+            "_class": "org.jenkinsci.plugins.workflow.job.WorkflowRun",
+            "number": 42,
+            "actions": [
+                {
+                    "_class": "hudson.model.CauseAction",
+                    "causes": [
+                        {
+                            "_class": "org.jenkinsci.plugins.workflow.support.steps.build.BuildUpstreamCause",
+                            "shortDescription": "Started by upstream project \"libs/core\" build number 7",
+                            "upstreamBuild": 7,
+                            "upstreamProject": "libs/core",
+                            "upstreamUrl": "job/libs/job/core/"
+                        },
+                        {
+                            "_class": "hudson.model.Cause$UserIdCause",
+                            "userId": "alice",
+                            "userName": "Alice"
+                        },
+                        { "_class": "jenkins.branch.BranchIndexingCause" }
+                    ]
+                },
+                { "_class": "hudson.model.ParametersAction", "parameters": [] }
+            ],
+            "changeSets": [{
+                "_class": "hudson.plugins.git.GitChangeSetList",
+                "kind": "git",
+                "items": [{
+                    "commitId": "4f2c1a9",
+                    "timestamp": 1_700_000_000_000u64,
+                    "author": { "fullName": "Bob", "absoluteUrl": "http://jenkins/user/bob" },
+                    "authorEmail": "bob@example.com",
+                    "msg": "Fix flaky test",
+                    "affectedPaths": ["src/lib.rs"],
+                    "paths": [{ "editType": "edit", "file": "src/lib.rs" }]
+                }, {
+                    "id": "r1234",
+                    "timestamp": -1,
+                    "msg": "Imported from an SCM without commit times"
+                }]
+            }],
+            "culprits": [{ "fullName": "Bob", "absoluteUrl": "http://jenkins/user/bob" }]
+        }))
+        .unwrap();
+
+        let causes = build.causes();
+        assert_eq!(causes[0].kind(), CauseKind::Upstream);
+        assert_eq!(causes[0].upstream().as_deref(), Some("libs/core#7"));
+        assert_eq!(causes[1].kind(), CauseKind::User);
+        assert_eq!(causes[1].user_name.as_deref(), Some("Alice"));
+        assert_eq!(
+            causes[2].kind(),
+            CauseKind::Unknown("jenkins.branch.BranchIndexingCause".to_owned())
+        );
+
+        let commits: Vec<_> = build.commits().collect();
+        assert_eq!(commits.len(), 2);
+        assert_eq!(commits[0].revision(), Some("4f2c1a9"));
+        assert_eq!(commits[0].timestamp, Some(1_700_000_000_000));
+        assert_eq!(commits[1].revision(), Some("r1234"));
+        assert_eq!(commits[1].timestamp, None);
+        assert_eq!(
+            commits[0]
+                .author
+                .as_ref()
+                .and_then(|a| a.full_name.as_deref()),
+            Some("Bob")
+        );
+        assert_eq!(commits[0].paths[0].edit_type, Some(EditType::Edit));
+        assert_eq!(build.culprits[0].full_name.as_deref(), Some("Bob"));
+    }
+
+    #[test]
+    fn build_parameter_form_values_never_stringify_json() {
+        let parameter = |value: serde_json::Value| BuildParameter {
+            value: Some(value),
+            ..BuildParameter::default()
+        };
+        let run = parameter(serde_json::json!({ "jobName": "libs/core", "number": 42 }));
+        assert_eq!(run.form_value().as_deref(), Some("libs/core#42"));
+        assert_eq!(
+            parameter(serde_json::json!(3)).form_value().as_deref(),
+            Some("3")
+        );
+        assert_eq!(parameter(serde_json::json!({ "id": 1 })).form_value(), None);
+        assert_eq!(parameter(serde_json::json!(["a", "b"])).form_value(), None);
+    }
+
+    #[test]
+    fn build_result_ordering_follows_jenkins_ordinals() {
+        assert!(BuildResult::Failure.is_worse_than(&BuildResult::Unstable));
+        assert!(BuildResult::Aborted.is_worse_than(&BuildResult::NotBuilt));
+        assert!(BuildResult::Success.is_better_than(&BuildResult::Unstable));
+        assert!(!BuildResult::Success.is_worse_than(&BuildResult::Success));
+        assert!(!BuildResult::Unknown("X".into()).is_worse_than(&BuildResult::Success));
+    }
+
+    #[test]
+    fn job_color_maps_to_last_result() {
+        let job = JobSummary {
+            color: Some("red_anime".to_owned()),
+            ..JobSummary::default()
+        };
+        assert_eq!(job.last_result(), Some(BuildResult::Failure));
+        assert!(job.is_building());
+        assert_eq!(result_from_color("disabled"), None);
+    }
+}
@@ -1,19 +1,39 @@
 //! Shared request/response types.
 
+pub mod causes;
+pub mod changes;
 pub mod common;
 pub mod computers;
+pub mod credentials;
 pub mod jobs;
+pub mod multibranch;
+pub mod parameters;
 pub mod people;
+pub mod pipeline;
+pub mod plugin_manifest;
+pub mod plugins;
 pub mod queue;
+pub mod script;
 pub mod system;
+pub mod test_report;
 pub mod users;
 pub mod views;
 
+pub use causes::*;
+pub use changes::*;
 pub use common::*;
 pub use computers::*;
+pub use credentials::*;
 pub use jobs::*;
+pub use multibranch::*;
+pub use parameters::*;
 pub use people::*;
+pub use pipeline::*;
+pub use plugin_manifest::*;
+pub use plugins::*;
 pub use queue::*;
+pub use script::*;
 pub use system::*;
+pub use test_report::*;
 pub use users::*;
 pub use views::*;
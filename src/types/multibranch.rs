@@ -0,0 +1,111 @@
+use crate::{BuildInfo, BuildResult};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// Which tab of a multibranch project a branch job is listed under.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BranchKind {
+    /// Regular branch (the `default` category).
+    #[default]
+    Branch,
+    /// Pull / merge request (the `change-requests` category).
+    PullRequest,
+    /// Tag (the `tags` category).
+    Tag,
+}
+
+impl BranchKind {
+    /// Kind for a branch API category (view) name.
+    #[must_use]
+    pub fn from_category(category: &str) -> Option<Self> {
+        match category {
+            "default" => Some(Self::Branch),
+            "change-requests" => Some(Self::PullRequest),
+            "tags" => Some(Self::Tag),
+            _ => None,
+        }
+    }
+}
+
+/// Whether a branch job can still be built.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BranchState {
+    Active,
+    /// The multibranch project itself is disabled, so none of its branches build.
+    Disabled,
+    /// The branch disappeared from the repository; the job is kept by the orphaned item
+    /// strategy but cannot be built anymore.
+    Orphaned,
+}
+
+/// Branch, pull request or tag job of a multibranch project.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct BranchJob {
+    /// Job name (URL-encoded branch name, e.g. `feature%2Flogin`, or `PR-42`).
+    pub name: String,
+    /// Branch name as shown in the UI.
+    #[serde(default)]
+    pub display_name: Option<String>,
+    #[serde(default)]
+    pub url: Option<String>,
+    #[serde(default)]
+    pub color: Option<String>,
+    #[serde(default)]
+    pub buildable: Option<bool>,
+    #[serde(default)]
+    pub last_build: Option<BuildInfo>,
+    /// Filled from the project's category views, not by Jenkins on the job itself.
+    #[serde(skip)]
+    pub kind: BranchKind,
+    /// Filled from the project's own `disabled` flag, not by Jenkins on the job itself.
+    #[serde(skip)]
+    pub project_disabled: bool,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+impl BranchJob {
+    /// State derived from what Jenkins exports for branch jobs.
+    ///
+    /// Branch jobs cannot be disabled one by one. branch-api reports a branch that is gone
+    /// from the repository as `color: "disabled"` and `buildable: false`, which maps to
+    /// `Orphaned`. Branches of a disabled project look the same and map to `Disabled` when
+    /// the listing saw the project's `disabled` flag; older branch-api versions do not
+    /// export it, in which case they are reported as `Orphaned` too.
+    #[must_use]
+    pub fn state(&self) -> BranchState {
+        let unbuildable = self.buildable == Some(false)
+            || self
+                .color
+                .as_deref()
+                .is_some_and(|color| color.starts_with("disabled"));
+        match (unbuildable, self.project_disabled) {
+            (false, _) => BranchState::Active,
+            (true, true) => BranchState::Disabled,
+            (true, false) => BranchState::Orphaned,
+        }
+    }
+
+    /// Result of the last build; `None` while it is running or when there is none.
+    #[must_use]
+    pub fn last_result(&self) -> Option<&BuildResult> {
+        self.last_build.as_ref()?.result.as_ref()
+    }
+
+    #[must_use]
+    pub fn is_building(&self) -> bool {
+        self.last_build
+            .as_ref()
+            .is_some_and(|build| build.building == Some(true))
+    }
+
+    #[must_use]
+    pub fn is_pull_request(&self) -> bool {
+        self.kind == BranchKind::PullRequest
+    }
+}
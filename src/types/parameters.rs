@@ -0,0 +1,258 @@
+use crate::{BuildParameter, Error};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io::Read;
+use std::path::PathBuf;
+
+/// Kind of a job parameter, from the definition's `type` (`StringParameterDefinition`, ...).
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+#[non_exhaustive]
+pub enum ParameterType {
+    String,
+    Boolean,
+    Choice,
+    Password,
+    /// Multi-line string.
+    Text,
+    File,
+    /// Reference to a build of another job (`<job>#<number>`).
+    Run,
+    /// Definition type not known to this SDK (plugin-provided parameters).
+    Unknown(String),
+}
+
+impl ParameterType {
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::String => "StringParameterDefinition",
+            Self::Boolean => "BooleanParameterDefinition",
+            Self::Choice => "ChoiceParameterDefinition",
+            Self::Password => "PasswordParameterDefinition",
+            Self::Text => "TextParameterDefinition",
+            Self::File => "FileParameterDefinition",
+            Self::Run => "RunParameterDefinition",
+            Self::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<&str> for ParameterType {
+    fn from(value: &str) -> Self {
+        match value {
+            "StringParameterDefinition" => Self::String,
+            "BooleanParameterDefinition" => Self::Boolean,
+            "ChoiceParameterDefinition" => Self::Choice,
+            "PasswordParameterDefinition" => Self::Password,
+            "TextParameterDefinition" => Self::Text,
+            "FileParameterDefinition" => Self::File,
+            "RunParameterDefinition" => Self::Run,
+            other => Self::Unknown(other.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ParameterType {
+    fn from(value: String) -> Self {
+        match Self::from(value.as_str()) {
+            Self::Unknown(_) => Self::Unknown(value),
+            known => known,
+        }
+    }
+}
+
+impl From<ParameterType> for String {
+    fn from(value: ParameterType) -> Self {
+        match value {
+            ParameterType::Unknown(value) => value,
+            known => known.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ParameterType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// One entry of a job's `property[].parameterDefinitions`.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct ParameterDefinition {
+    #[serde(default)]
+    pub name: String,
+    #[serde(default, rename = "type")]
+    pub parameter_type: Option<ParameterType>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub default_parameter_value: Option<BuildParameter>,
+    /// Allowed values of a choice parameter, the first one being the default.
+    #[serde(default)]
+    pub choices: Vec<String>,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+impl ParameterDefinition {
+    /// Decode one `parameterDefinitions` entry.
+    ///
+    /// Entries that do not fit the typed shape (plugin parameters with their own
+    /// `defaultParameterValue` layout, for example) are decoded field by field: fields that
+    /// fail to decode stay in `extra` as raw JSON, everything else (notably `type` and
+    /// `choices`) is kept so value checks still apply.
+    pub(crate) fn from_value_lossy(value: &Value) -> Self {
+        if let Ok(definition) = serde_json::from_value(value.clone()) {
+            return definition;
+        }
+        let mut extra: BTreeMap<String, Value> = value
+            .as_object()
+            .map(|object| object.clone().into_iter().collect())
+            .unwrap_or_default();
+        Self {
+            name: take_field(&mut extra, "name").unwrap_or_default(),
+            parameter_type: take_field(&mut extra, "type"),
+            description: take_field(&mut extra, "description"),
+            default_parameter_value: take_field(&mut extra, "defaultParameterValue"),
+            choices: take_field(&mut extra, "choices").unwrap_or_default(),
+            extra,
+        }
+    }
+
+    /// Default value as it would be submitted to `buildWithParameters`.
+    #[must_use]
+    pub fn default_value(&self) -> Option<String> {
+        self.default_parameter_value
+            .as_ref()
+            .and_then(BuildParameter::form_value)
+            .or_else(|| self.choices.first().cloned())
+    }
+
+    /// Why `value` would be rejected or ignored by Jenkins, if it would be.
+    ///
+    /// Only choice and boolean parameters constrain their values.
+    #[must_use]
+    pub fn check_value(&self, value: &str) -> Option<String> {
+        match self.parameter_type.as_ref()? {
+            ParameterType::Choice if !self.choices.iter().any(|choice| choice == value) => {
+                Some(format!(
+                    "`{value}` is not one of the choices of `{}` ({})",
+                    self.name,
+                    self.choices.join(", ")
+                ))
+            }
+            ParameterType::Boolean if value != "true" && value != "false" => Some(format!(
+                "`{}` is a boolean parameter, got `{value}`",
+                self.name
+            )),
+            _ => None,
+        }
+    }
+}
+
+/// Remove `key` from `extra` and decode it; values that do not decode stay in `extra`.
+fn take_field<T: DeserializeOwned>(extra: &mut BTreeMap<String, Value>, key: &str) -> Option<T> {
+    let raw = extra.remove(key)?;
+    if raw.is_null() {
+        return None;
+    }
+    match serde_json::from_value(raw.clone()) {
+        Ok(value) => Some(value),
+        Err(_) => {
+            extra.insert(key.to_owned(), raw);
+            None
+        }
+    }
+}
+
+/// Where the content of a [`FileParameter`] comes from.
+#[derive(Clone)]
+pub(crate) enum FileSource {
+    Bytes(Vec<u8>),
+    /// Read when the build is triggered.
+    Path(PathBuf),
+}
+
+/// File uploaded for a `FileParameterDefinition` by `build_with_files`.
+#[derive(Clone)]
+#[non_exhaustive]
+pub struct FileParameter {
+    /// Parameter name.
+    pub name: String,
+    /// File name reported to Jenkins.
+    pub file_name: String,
+    pub(crate) source: FileSource,
+}
+
+impl FileParameter {
+    #[must_use]
+    pub fn bytes(
+        name: impl Into<String>,
+        file_name: impl Into<String>,
+        bytes: impl Into<Vec<u8>>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            file_name: file_name.into(),
+            source: FileSource::Bytes(bytes.into()),
+        }
+    }
+
+    /// Upload the file at `path` (read when the build is triggered), named after its last
+    /// path component.
+    #[must_use]
+    pub fn path(name: impl Into<String>, path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let file_name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        Self {
+            name: name.into(),
+            file_name,
+            source: FileSource::Path(path),
+        }
+    }
+
+    /// Read `reader` to the end now and upload its content.
+    pub fn reader(
+        name: impl Into<String>,
+        file_name: impl Into<String>,
+        mut reader: impl Read,
+    ) -> Result<Self, Error> {
+        let name = name.into();
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).map_err(|source| Error::Io {
+            context: format!("reading file parameter `{name}`").into_boxed_str(),
+            source,
+        })?;
+        Ok(Self::bytes(name, file_name, bytes))
+    }
+
+    /// Override the file name reported to Jenkins.
+    #[must_use]
+    pub fn file_name(mut self, file_name: impl Into<String>) -> Self {
+        self.file_name = file_name.into();
+        self
+    }
+}
+
+impl fmt::Debug for FileParameter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug = f.debug_struct("FileParameter");
+        debug
+            .field("name", &self.name)
+            .field("file_name", &self.file_name);
+        match &self.source {
+            FileSource::Bytes(bytes) => debug.field("bytes", &bytes.len()),
+            FileSource::Path(path) => debug.field("path", path),
+        };
+        debug.finish()
+    }
+}
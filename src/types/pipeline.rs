@@ -0,0 +1,286 @@
+use crate::{FlowNodeId, InputId};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::time::Duration;
+
+/// Status reported by the Workflow REST API for runs, stages and steps.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+#[non_exhaustive]
+pub enum StageStatus {
+    Success,
+    Unstable,
+    Failed,
+    Aborted,
+    InProgress,
+    /// Blocked on an `input` step.
+    PausedPendingInput,
+    NotExecuted,
+    /// Status string not known to this SDK.
+    Unknown(String),
+}
+
+impl StageStatus {
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Success => "SUCCESS",
+            Self::Unstable => "UNSTABLE",
+            Self::Failed => "FAILED",
+            Self::Aborted => "ABORTED",
+            Self::InProgress => "IN_PROGRESS",
+            Self::PausedPendingInput => "PAUSED_PENDING_INPUT",
+            Self::NotExecuted => "NOT_EXECUTED",
+            Self::Unknown(value) => value,
+        }
+    }
+
+    /// Still running or waiting for input.
+    #[must_use]
+    pub fn is_running(&self) -> bool {
+        matches!(self, Self::InProgress | Self::PausedPendingInput)
+    }
+
+    #[must_use]
+    pub fn is_failed(&self) -> bool {
+        matches!(self, Self::Failed)
+    }
+}
+
+impl From<&str> for StageStatus {
+    fn from(value: &str) -> Self {
+        match value {
+            "SUCCESS" => Self::Success,
+            "UNSTABLE" => Self::Unstable,
+            "FAILED" => Self::Failed,
+            "ABORTED" => Self::Aborted,
+            "IN_PROGRESS" => Self::InProgress,
+            "PAUSED_PENDING_INPUT" => Self::PausedPendingInput,
+            "NOT_EXECUTED" => Self::NotExecuted,
+            other => Self::Unknown(other.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StageStatus {
+    fn from(value: String) -> Self {
+        match Self::from(value.as_str()) {
+            Self::Unknown(_) => Self::Unknown(value),
+            known => known,
+        }
+    }
+}
+
+impl From<StageStatus> for String {
+    fn from(value: StageStatus) -> Self {
+        match value {
+            StageStatus::Unknown(value) => value,
+            known => known.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for StageStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Error attached to a failed stage or step.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct PipelineError {
+    #[serde(default)]
+    pub message: Option<String>,
+    /// Java exception class.
+    #[serde(default, rename = "type")]
+    pub error_type: Option<String>,
+}
+
+/// One Pipeline run (`/job/<name>/<build>/wfapi/describe`, or an entry of `/wfapi/runs`).
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct PipelineRun {
+    /// Build number.
+    #[serde(default)]
+    pub id: Option<String>,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub status: Option<StageStatus>,
+    #[serde(default)]
+    pub start_time_millis: Option<u64>,
+    #[serde(default)]
+    pub end_time_millis: Option<u64>,
+    #[serde(default)]
+    pub duration_millis: Option<u64>,
+    #[serde(default)]
+    pub queue_duration_millis: Option<u64>,
+    #[serde(default)]
+    pub pause_duration_millis: Option<u64>,
+    #[serde(default)]
+    pub stages: Vec<PipelineStage>,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+impl PipelineRun {
+    #[must_use]
+    pub fn duration(&self) -> Option<Duration> {
+        self.duration_millis.map(Duration::from_millis)
+    }
+
+    /// First stage reported as `FAILED`.
+    #[must_use]
+    pub fn failed_stage(&self) -> Option<&PipelineStage> {
+        self.stages
+            .iter()
+            .find(|stage| stage.status.as_ref().is_some_and(StageStatus::is_failed))
+    }
+}
+
+/// One stage of a Pipeline run.
+///
+/// `stage_flow_nodes` (the stage's steps) is only filled by
+/// `/execution/node/<id>/wfapi/describe`.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct PipelineStage {
+    #[serde(default)]
+    pub id: Option<FlowNodeId>,
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Agent the stage ran on (empty for the controller).
+    #[serde(default)]
+    pub exec_node: Option<String>,
+    #[serde(default)]
+    pub status: Option<StageStatus>,
+    #[serde(default)]
+    pub start_time_millis: Option<u64>,
+    #[serde(default)]
+    pub duration_millis: Option<u64>,
+    #[serde(default)]
+    pub pause_duration_millis: Option<u64>,
+    #[serde(default)]
+    pub error: Option<PipelineError>,
+    #[serde(default)]
+    pub stage_flow_nodes: Vec<PipelineStep>,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+impl PipelineStage {
+    #[must_use]
+    pub fn duration(&self) -> Option<Duration> {
+        self.duration_millis.map(Duration::from_millis)
+    }
+}
+
+/// One step (flow node) within a stage.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct PipelineStep {
+    #[serde(default)]
+    pub id: Option<FlowNodeId>,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub exec_node: Option<String>,
+    #[serde(default)]
+    pub status: Option<StageStatus>,
+    /// Step argument summary, e.g. the script of an `sh` step.
+    #[serde(default)]
+    pub parameter_description: Option<String>,
+    #[serde(default)]
+    pub start_time_millis: Option<u64>,
+    #[serde(default)]
+    pub duration_millis: Option<u64>,
+    #[serde(default)]
+    pub pause_duration_millis: Option<u64>,
+    #[serde(default)]
+    pub error: Option<PipelineError>,
+    #[serde(default)]
+    pub parent_nodes: Vec<FlowNodeId>,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+/// Log of one flow node (`/execution/node/<id>/wfapi/log`).
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct PipelineNodeLog {
+    #[serde(default)]
+    pub node_id: Option<FlowNodeId>,
+    #[serde(default)]
+    pub node_status: Option<StageStatus>,
+    #[serde(default)]
+    pub length: Option<u64>,
+    /// `true` when `text` was truncated; the full log is at `console_url`.
+    #[serde(default)]
+    pub has_more: bool,
+    #[serde(default)]
+    pub text: String,
+    #[serde(default)]
+    pub console_url: Option<String>,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+/// `input` step waiting for an answer (`/wfapi/pendingInputActions`).
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct PendingInputAction {
+    /// Input id as used in `/input/<id>/...`.
+    #[serde(default)]
+    pub id: InputId,
+    #[serde(default)]
+    pub message: Option<String>,
+    #[serde(default)]
+    pub proceed_text: Option<String>,
+    #[serde(default)]
+    pub inputs: Vec<InputParameter>,
+    #[serde(default)]
+    pub proceed_url: Option<String>,
+    #[serde(default)]
+    pub abort_url: Option<String>,
+    #[serde(default)]
+    pub redirect_approval_url: Option<String>,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+/// Parameter requested by a pending `input` step.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct InputParameter {
+    #[serde(default)]
+    pub name: String,
+    /// Parameter definition class, e.g. `StringParameterDefinition`.
+    #[serde(default, rename = "type")]
+    pub parameter_type: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub definition: Option<Value>,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+/// Scripts of a Pipeline run as offered by the Replay page (`/job/<name>/<build>/replay`).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ReplayScripts {
+    /// The `Jenkinsfile` (or inline script) the run executed.
+    pub main_script: String,
+    /// Scripts pulled in with `load`, keyed by the form field Jenkins uses for them
+    /// (`Script1`, `Script2`, ...).
+    pub loaded_scripts: BTreeMap<String, String>,
+}
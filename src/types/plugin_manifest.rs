@@ -0,0 +1,563 @@
+use crate::util::version::compare_versions;
+use crate::{Error, PluginDependency, PluginInfo};
+use serde::Deserialize;
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, VecDeque};
+use std::fmt;
+
+/// One desired plugin; `version: None` tracks the latest update center release.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct PluginRequirement {
+    pub name: String,
+    pub version: Option<String>,
+}
+
+impl PluginRequirement {
+    #[must_use]
+    pub fn new(name: impl Into<String>, version: Option<String>) -> Self {
+        Self {
+            name: name.into(),
+            version,
+        }
+    }
+}
+
+/// Desired plugin set of a controller.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct PluginManifest {
+    pub plugins: Vec<PluginRequirement>,
+}
+
+impl PluginManifest {
+    /// Parse a `plugins.txt` as read by the official Jenkins image: one `name` or
+    /// `name:version` per line (`latest` means unpinned), `#` comments.
+    ///
+    /// `experimental` and `incrementals` versions and download URLs are rejected.
+    pub fn from_plugins_txt(text: &str) -> Result<Self, Error> {
+        let mut manifest = Self::default();
+        for (index, line) in text.lines().enumerate() {
+            let line = strip_comment(line).trim();
+            if line.is_empty() {
+                continue;
+            }
+            let mut fields = line.split(':');
+            let name = fields.next().unwrap_or_default().trim();
+            let version = fields.next().map(str::trim);
+            if fields.next().is_some() {
+                return Err(manifest_error(index, "download URLs are not supported"));
+            }
+            manifest
+                .push(name, version)
+                .map_err(|message| manifest_error(index, &message))?;
+        }
+        Ok(manifest)
+    }
+
+    /// Parse a YAML plugin manifest (requires the `yaml` feature):
+    ///
+    /// ```yaml
+    /// plugins:
+    ///   - artifactId: git
+    ///     source:
+    ///       version: 5.2.0
+    ///   - artifactId: job-dsl   # latest
+    /// ```
+    ///
+    /// or a plain `plugins:` mapping of `name: version` entries. `source.url` pins and
+    /// unquoted versions that YAML reads as floats (`1.10` would become `1.1`) are rejected.
+    #[cfg(feature = "yaml")]
+    pub fn from_yaml(text: &str) -> Result<Self, Error> {
+        use serde_norway::Value as Yaml;
+
+        let document: Yaml =
+            serde_norway::from_str(text).map_err(|source| Error::InvalidConfig {
+                message: "plugin manifest: invalid YAML".into(),
+                source: Some(Box::new(source)),
+            })?;
+        let mut manifest = Self::default();
+        match document.get("plugins") {
+            None | Some(Yaml::Null) => {}
+            Some(Yaml::Sequence(items)) => {
+                for (index, item) in items.iter().enumerate() {
+                    let name = match item.get("artifactId") {
+                        Some(Yaml::String(name)) => name.as_str(),
+                        _ => return Err(yaml_error(index, "plugin entry without artifactId")),
+                    };
+                    let source = item.get("source");
+                    if source.and_then(|source| source.get("url")).is_some() {
+                        return Err(yaml_error(
+                            index,
+                            &format!("`{name}`: download URLs are not supported"),
+                        ));
+                    }
+                    let version = source
+                        .and_then(|source| source.get("version"))
+                        .or_else(|| item.get("version"));
+                    let version = yaml_version(index, name, version)?;
+                    manifest
+                        .push(name, version.as_deref())
+                        .map_err(|message| yaml_error(index, &message))?;
+                }
+            }
+            Some(Yaml::Mapping(plugins)) => {
+                for (index, (name, version)) in plugins.iter().enumerate() {
+                    let Yaml::String(name) = name else {
+                        return Err(yaml_error(index, "plugin names must be strings"));
+                    };
+                    let version = yaml_version(index, name, Some(version))?;
+                    manifest
+                        .push(name, version.as_deref())
+                        .map_err(|message| yaml_error(index, &message))?;
+                }
+            }
+            Some(_) => {
+                return Err(Error::InvalidConfig {
+                    message: "plugin manifest: `plugins` must be a list or a mapping".into(),
+                    source: None,
+                });
+            }
+        }
+        Ok(manifest)
+    }
+
+    fn push(&mut self, name: &str, version: Option<&str>) -> Result<(), String> {
+        if name.is_empty() {
+            return Err("missing plugin name".to_owned());
+        }
+        if self.plugins.iter().any(|plugin| plugin.name == name) {
+            return Err(format!("`{name}` is listed twice"));
+        }
+        let version = match version {
+            None | Some("" | "latest") => None,
+            Some(version) if version == "experimental" || version.starts_with("incrementals") => {
+                return Err(format!("`{name}:{version}` is not supported"));
+            }
+            Some(version) => Some(version.to_owned()),
+        };
+        self.plugins
+            .push(PluginRequirement::new(name.to_owned(), version));
+        Ok(())
+    }
+}
+
+fn strip_comment(line: &str) -> &str {
+    match line.find('#') {
+        Some(0) => "",
+        Some(at) if line[..at].ends_with([' ', '\t']) => &line[..at],
+        _ => line,
+    }
+}
+
+/// Version string of a YAML manifest entry; `None` for a missing or null version.
+#[cfg(feature = "yaml")]
+fn yaml_version(
+    index: usize,
+    name: &str,
+    version: Option<&serde_norway::Value>,
+) -> Result<Option<String>, Error> {
+    use serde_norway::Value as Yaml;
+
+    match version {
+        None | Some(Yaml::Null) => Ok(None),
+        Some(Yaml::String(version)) => Ok(Some(version.clone())),
+        Some(Yaml::Number(version)) if !version.is_f64() => Ok(Some(version.to_string())),
+        Some(Yaml::Number(version)) => Err(yaml_error(
+            index,
+            &format!("`{name}`: quote version {version} so YAML keeps it verbatim"),
+        )),
+        Some(_) => Err(yaml_error(
+            index,
+            &format!("`{name}`: version must be a string"),
+        )),
+    }
+}
+
+#[cfg(feature = "yaml")]
+fn yaml_error(index: usize, message: &str) -> Error {
+    Error::InvalidConfig {
+        message: format!("plugin manifest entry {}: {message}", index + 1).into(),
+        source: None,
+    }
+}
+
+fn manifest_error(index: usize, message: &str) -> Error {
+    Error::InvalidConfig {
+        message: format!("plugin manifest line {}: {message}", index + 1).into(),
+        source: None,
+    }
+}
+
+/// Latest release of a plugin as published by an update site.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[non_exhaustive]
+pub struct CatalogPlugin {
+    pub name: String,
+    pub version: String,
+    #[serde(default)]
+    pub dependencies: Vec<PluginDependency>,
+}
+
+/// Plugin releases from an update center (`update-center.json`), used to resolve
+/// dependencies of plugins that are not installed yet.
+#[derive(Clone, Debug, Default)]
+pub struct PluginCatalog {
+    plugins: BTreeMap<String, CatalogPlugin>,
+}
+
+#[derive(Deserialize)]
+struct UpdateCenterDocument {
+    #[serde(default)]
+    plugins: BTreeMap<String, CatalogPlugin>,
+}
+
+impl PluginCatalog {
+    /// Parse `update-center.json` (or `update-center.actual.json`); the JSONP wrapper
+    /// `updateCenter.post(...);` is accepted.
+    pub fn from_update_center_json(text: &str) -> Result<Self, Error> {
+        let text = text.trim();
+        let json = text
+            .strip_prefix("updateCenter.post(")
+            .and_then(|rest| rest.trim_end_matches(';').trim_end().strip_suffix(')'))
+            .unwrap_or(text);
+        let document: UpdateCenterDocument =
+            serde_json::from_str(json).map_err(|source| Error::InvalidConfig {
+                message: "invalid update center JSON".into(),
+                source: Some(Box::new(source)),
+            })?;
+        Ok(Self {
+            plugins: document.plugins,
+        })
+    }
+
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&CatalogPlugin> {
+        self.plugins.get(name)
+    }
+}
+
+/// One step towards the desired plugin set.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PluginAction {
+    Install {
+        name: String,
+        version: String,
+    },
+    Upgrade {
+        name: String,
+        from: String,
+        to: String,
+    },
+    Downgrade {
+        name: String,
+        from: String,
+        to: String,
+    },
+    Remove {
+        name: String,
+        version: Option<String>,
+    },
+}
+
+impl PluginAction {
+    #[must_use]
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Install { name, .. }
+            | Self::Upgrade { name, .. }
+            | Self::Downgrade { name, .. }
+            | Self::Remove { name, .. } => name,
+        }
+    }
+}
+
+impl fmt::Display for PluginAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Install { name, version } => write!(f, "install {name} {version}"),
+            Self::Upgrade { name, from, to } => write!(f, "upgrade {name} {from} -> {to}"),
+            Self::Downgrade { name, from, to } => write!(f, "downgrade {name} {from} -> {to}"),
+            Self::Remove { name, version } => match version {
+                Some(version) => write!(f, "remove {name} {version}"),
+                None => write!(f, "remove {name}"),
+            },
+        }
+    }
+}
+
+/// Actions converging installed plugins onto a [`PluginManifest`].
+///
+/// `Display` prints one action per line, which doubles as dry-run output.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct PluginPlan {
+    /// Installs, upgrades and downgrades by name, then removals by name.
+    pub actions: Vec<PluginAction>,
+}
+
+struct Target {
+    version: String,
+    pinned: bool,
+}
+
+impl PluginPlan {
+    /// Resolve the manifest plus its required (non-optional) transitive dependencies and
+    /// diff the result against `installed`.
+    ///
+    /// Unpinned plugins move to the catalog release when it is newer. Dependencies keep the
+    /// installed version when it satisfies every dependent, otherwise they move to the
+    /// catalog release. Dependencies of plugins that stay at their installed version come
+    /// from the controller, all others from the catalog (which only knows the latest
+    /// release). Installed plugins outside the resolved set are removed.
+    pub fn compute(
+        manifest: &PluginManifest,
+        installed: &[PluginInfo],
+        catalog: &PluginCatalog,
+    ) -> Result<Self, Error> {
+        let installed: BTreeMap<&str, &PluginInfo> = installed
+            .iter()
+            .filter(|plugin| plugin.deleted != Some(true))
+            .map(|plugin| (plugin.short_name.as_str(), plugin))
+            .collect();
+        let installed_version = |name: &str| {
+            installed
+                .get(name)
+                .and_then(|plugin| plugin.version.clone())
+        };
+        let unresolved = |name: &str| Error::InvalidConfig {
+            message: format!("plugin `{name}` is neither installed nor in the update center")
+                .into(),
+            source: None,
+        };
+
+        let mut targets: BTreeMap<String, Target> = BTreeMap::new();
+        let mut pending = VecDeque::new();
+        for wanted in &manifest.plugins {
+            let latest = catalog
+                .get(&wanted.name)
+                .map(|plugin| plugin.version.clone());
+            let version = match (&wanted.version, latest, installed_version(&wanted.name)) {
+                (Some(pinned), _, _) => pinned.clone(),
+                (None, Some(latest), Some(current)) => max_version(latest, current),
+                (None, Some(version), None) | (None, None, Some(version)) => version,
+                (None, None, None) => return Err(unresolved(&wanted.name)),
+            };
+            targets.insert(
+                wanted.name.clone(),
+                Target {
+                    version,
+                    pinned: wanted.version.is_some(),
+                },
+            );
+            pending.push_back(wanted.name.clone());
+        }
+
+        while let Some(name) = pending.pop_front() {
+            let version = targets[&name].version.clone();
+            let dependencies = match installed.get(name.as_str()) {
+                Some(plugin) if plugin.version.as_deref() == Some(version.as_str()) => {
+                    plugin.dependencies.clone()
+                }
+                _ => catalog
+                    .get(&name)
+                    .map(|plugin| plugin.dependencies.clone())
+                    .unwrap_or_default(),
+            };
+
+            for dependency in dependencies.iter().filter(|dep| !dep.is_optional()) {
+                let dep = dependency.short_name.as_str();
+                let minimum = dependency.version.clone().unwrap_or_default();
+                let satisfies = |version: &str| compare_versions(version, &minimum).is_ge();
+                let latest = catalog.get(dep).map(|plugin| plugin.version.clone());
+
+                if let Some(target) = targets.get_mut(dep) {
+                    if satisfies(&target.version) {
+                        continue;
+                    }
+                    if target.pinned {
+                        return Err(Error::InvalidConfig {
+                            message: format!(
+                                "`{name}` {version} needs `{dep}` >= {minimum}, \
+                                 but the manifest pins {}",
+                                target.version
+                            )
+                            .into(),
+                            source: None,
+                        });
+                    }
+                    target.version = latest
+                        .filter(|latest| satisfies(latest))
+                        .unwrap_or(minimum.clone());
+                } else {
+                    let version = match (installed_version(dep), latest) {
+                        (Some(current), _) if satisfies(&current) => current,
+                        (_, Some(latest)) => max_version(latest, minimum.clone()),
+                        (Some(_), None) if !minimum.is_empty() => minimum.clone(),
+                        _ => return Err(unresolved(dep)),
+                    };
+                    targets.insert(
+                        dep.to_owned(),
+                        Target {
+                            version,
+                            pinned: false,
+                        },
+                    );
+                }
+                pending.push_back(dep.to_owned());
+            }
+        }
+
+        let mut actions = Vec::new();
+        for (name, target) in &targets {
+            let to = target.version.clone();
+            let Some(from) = installed
+                .get(name.as_str())
+                .map(|plugin| plugin.version.clone())
+            else {
+                actions.push(PluginAction::Install {
+                    name: name.clone(),
+                    version: to,
+                });
+                continue;
+            };
+            let from = from.unwrap_or_default();
+            let name = name.clone();
+            match compare_versions(&from, &to) {
+                Ordering::Less => actions.push(PluginAction::Upgrade { name, from, to }),
+                Ordering::Greater => actions.push(PluginAction::Downgrade { name, from, to }),
+                Ordering::Equal => {}
+            }
+        }
+        for (name, plugin) in &installed {
+            if !targets.contains_key(*name) {
+                actions.push(PluginAction::Remove {
+                    name: (*name).to_owned(),
+                    version: plugin.version.clone(),
+                });
+            }
+        }
+        Ok(Self { actions })
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.actions.is_empty()
+    }
+}
+
+impl fmt::Display for PluginPlan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for action in &self.actions {
+            writeln!(f, "{action}")?;
+        }
+        Ok(())
+    }
+}
+
+fn max_version(a: String, b: String) -> String {
+    if compare_versions(&a, &b).is_ge() {
+        a
+    } else {
+        b
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn installed(plugins: serde_json::Value) -> Vec<PluginInfo> {
+        serde_json::from_value(plugins).unwrap()
+    }
+
+    fn catalog() -> PluginCatalog {
+        PluginCatalog::from_update_center_json(
+            r#"updateCenter.post(
+{"plugins": {
+  "git": {"name": "git", "version": "5.2.0", "dependencies": [
+    {"name": "scm-api", "version": "600", "optional": false},
+    {"name": "credentials-binding", "version": "1.27", "optional": true}]},
+  "scm-api": {"name": "scm-api", "version": "690.v1", "dependencies": []},
+  "job-dsl": {"name": "job-dsl", "version": "1.87", "dependencies": []}
+}});"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn plugins_txt_pins_and_tracks_latest() {
+        let manifest = PluginManifest::from_plugins_txt(
+            "# core\ngit:5.2.0\njob-dsl:latest  # dsl\n\nmatrix-auth\n",
+        )
+        .unwrap();
+        assert_eq!(
+            manifest.plugins,
+            [
+                PluginRequirement::new("git", Some("5.2.0".to_owned())),
+                PluginRequirement::new("job-dsl", None),
+                PluginRequirement::new("matrix-auth", None),
+            ]
+        );
+        assert!(PluginManifest::from_plugins_txt("git\ngit:1.0\n").is_err());
+        assert!(PluginManifest::from_plugins_txt("git:experimental\n").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "yaml")]
+    fn yaml_reads_list_and_mapping_forms() {
+        let list = PluginManifest::from_yaml(
+            "plugins:\n  - artifactId: git\n    source:\n      version: \"5.2.0\"\n  - {artifactId: 'job-dsl'} # latest\n",
+        )
+        .unwrap();
+        let mapping =
+            PluginManifest::from_yaml("---\nplugins:\n  git: 5.2.0\n  job-dsl: latest\n").unwrap();
+        assert_eq!(list, mapping);
+        assert_eq!(list.plugins[0].version.as_deref(), Some("5.2.0"));
+        assert!(PluginManifest::from_yaml("plugins:\n  - source:\n      version: 1\n").is_err());
+
+        let url = PluginManifest::from_yaml(
+            "plugins:\n  - artifactId: git\n    source:\n      url: https://example.com/git.hpi\n",
+        )
+        .unwrap_err();
+        assert!(url.to_string().contains("download URLs"), "{url}");
+        let float = PluginManifest::from_yaml("plugins:\n  job-dsl: 1.10\n").unwrap_err();
+        assert!(float.to_string().contains("quote version"), "{float}");
+    }
+
+    #[test]
+    fn plan_resolves_dependencies_and_removes_the_rest() {
+        let manifest = PluginManifest::from_plugins_txt("git\njob-dsl:1.80\n").unwrap();
+        let installed = installed(serde_json::json!([
+            {"shortName": "git", "version": "5.1.0",
+             "dependencies": [{"shortName": "scm-api", "version": "600"}]},
+            {"shortName": "scm-api", "version": "650"},
+            {"shortName": "job-dsl", "version": "1.87"},
+            {"shortName": "ant", "version": "1.0"}
+        ]));
+
+        let plan = PluginPlan::compute(&manifest, &installed, &catalog()).unwrap();
+        assert_eq!(
+            plan.to_string(),
+            "upgrade git 5.1.0 -> 5.2.0\ndowngrade job-dsl 1.87 -> 1.80\nremove ant 1.0\n"
+        );
+    }
+
+    #[test]
+    fn plan_installs_missing_dependencies_and_reports_pin_conflicts() {
+        let manifest = PluginManifest::from_plugins_txt("git\n").unwrap();
+        let plan = PluginPlan::compute(&manifest, &[], &catalog()).unwrap();
+        assert_eq!(
+            plan.to_string(),
+            "install git 5.2.0\ninstall scm-api 690.v1\n"
+        );
+
+        let pinned = PluginManifest::from_plugins_txt("git\nscm-api:500\n").unwrap();
+        let err = PluginPlan::compute(&pinned, &[], &catalog()).unwrap_err();
+        assert!(err.to_string().contains("needs `scm-api` >= 600"), "{err}");
+
+        let unknown = PluginManifest::from_plugins_txt("nope\n").unwrap();
+        assert!(PluginPlan::compute(&unknown, &[], &catalog()).is_err());
+    }
+}
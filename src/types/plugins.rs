@@ -0,0 +1,242 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Installed plugin, from `GET /pluginManager/api/json`.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct PluginInfo {
+    /// Plugin id, e.g. `git`.
+    pub short_name: String,
+    #[serde(default)]
+    pub long_name: Option<String>,
+    #[serde(default)]
+    pub version: Option<String>,
+    /// Enabled in the configuration (takes effect after a restart).
+    #[serde(default)]
+    pub enabled: Option<bool>,
+    /// Currently loaded.
+    #[serde(default)]
+    pub active: Option<bool>,
+    #[serde(default)]
+    pub has_update: Option<bool>,
+    /// Uninstalled, pending a restart.
+    #[serde(default)]
+    pub deleted: Option<bool>,
+    #[serde(default)]
+    pub bundled: Option<bool>,
+    #[serde(default)]
+    pub pinned: Option<bool>,
+    #[serde(default)]
+    pub url: Option<String>,
+    #[serde(default)]
+    pub dependencies: Vec<PluginDependency>,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+impl PluginInfo {
+    #[must_use]
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.unwrap_or(false)
+    }
+
+    #[must_use]
+    pub fn is_active(&self) -> bool {
+        self.active.unwrap_or(false)
+    }
+
+    #[must_use]
+    pub fn has_update(&self) -> bool {
+        self.has_update.unwrap_or(false)
+    }
+
+    /// Required (non-optional) dependencies.
+    pub fn required_dependencies(&self) -> impl Iterator<Item = &PluginDependency> {
+        self.dependencies.iter().filter(|dep| !dep.is_optional())
+    }
+}
+
+/// Dependency declared by a plugin.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct PluginDependency {
+    /// `name` in `update-center.json`.
+    #[serde(alias = "name")]
+    pub short_name: String,
+    /// Minimum version.
+    #[serde(default)]
+    pub version: Option<String>,
+    #[serde(default)]
+    pub optional: Option<bool>,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+impl PluginDependency {
+    #[must_use]
+    pub fn is_optional(&self) -> bool {
+        self.optional.unwrap_or(false)
+    }
+}
+
+/// Update center state from `GET /updateCenter/api/json`.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct UpdateCenterStatus {
+    /// Download/installation jobs since the last restart, oldest first.
+    #[serde(default)]
+    pub jobs: Vec<UpdateCenterJob>,
+    /// `true` once an installed, updated or removed plugin needs a restart to take effect.
+    #[serde(default)]
+    pub restart_required_for_completion: Option<bool>,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+impl UpdateCenterStatus {
+    #[must_use]
+    pub fn restart_required(&self) -> bool {
+        self.restart_required_for_completion.unwrap_or(false)
+            || self.jobs.iter().any(|job| {
+                job.install_status() == Some(PluginInstallStatus::SuccessButRequiresRestart)
+            })
+    }
+
+    /// `true` while any job is still pending or installing.
+    #[must_use]
+    pub fn is_busy(&self) -> bool {
+        self.jobs.iter().any(UpdateCenterJob::is_running)
+    }
+
+    /// Installation jobs that ended in [`PluginInstallStatus::Failure`].
+    pub fn failed_installations(&self) -> impl Iterator<Item = &UpdateCenterJob> {
+        self.jobs
+            .iter()
+            .filter(|job| job.install_status() == Some(PluginInstallStatus::Failure))
+    }
+}
+
+/// One update center job (`InstallationJob`, `ConnectionCheckJob`, ...).
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct UpdateCenterJob {
+    #[serde(default)]
+    pub id: Option<u64>,
+    /// Job type, e.g. `InstallationJob`.
+    #[serde(default, rename = "type")]
+    pub job_type: Option<String>,
+    /// Plugin name for installation jobs.
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub status: Option<UpdateCenterJobStatus>,
+    #[serde(default)]
+    pub error_message: Option<String>,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+impl UpdateCenterJob {
+    #[must_use]
+    pub fn install_status(&self) -> Option<PluginInstallStatus> {
+        self.status.as_ref()?.status_type.clone()
+    }
+
+    #[must_use]
+    pub fn is_running(&self) -> bool {
+        self.install_status()
+            .is_some_and(|status| status.is_running())
+    }
+}
+
+/// `status` of an update center job.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct UpdateCenterJobStatus {
+    #[serde(default, rename = "type")]
+    pub status_type: Option<PluginInstallStatus>,
+    #[serde(default)]
+    pub success: Option<bool>,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+/// Installation job status (`UpdateCenter.DownloadJob.InstallationStatus` subclasses);
+/// unrecognised values are kept verbatim in [`PluginInstallStatus::Unknown`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+#[non_exhaustive]
+pub enum PluginInstallStatus {
+    Pending,
+    Installing,
+    Success,
+    SuccessButRequiresRestart,
+    Skipped,
+    Failure,
+    Unknown(String),
+}
+
+impl PluginInstallStatus {
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Pending => "Pending",
+            Self::Installing => "Installing",
+            Self::Success => "Success",
+            Self::SuccessButRequiresRestart => "SuccessButRequiresRestart",
+            Self::Skipped => "Skipped",
+            Self::Failure => "Failure",
+            Self::Unknown(value) => value,
+        }
+    }
+
+    #[must_use]
+    pub fn is_running(&self) -> bool {
+        matches!(self, Self::Pending | Self::Installing)
+    }
+}
+
+impl From<&str> for PluginInstallStatus {
+    fn from(value: &str) -> Self {
+        match value {
+            "Pending" => Self::Pending,
+            "Installing" => Self::Installing,
+            "Success" => Self::Success,
+            "SuccessButRequiresRestart" => Self::SuccessButRequiresRestart,
+            "Skipped" => Self::Skipped,
+            "Failure" => Self::Failure,
+            other => Self::Unknown(other.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PluginInstallStatus {
+    fn from(value: String) -> Self {
+        match Self::from(value.as_str()) {
+            Self::Unknown(_) => Self::Unknown(value),
+            known => known,
+        }
+    }
+}
+
+impl From<PluginInstallStatus> for String {
+    fn from(value: PluginInstallStatus) -> Self {
+        match value {
+            PluginInstallStatus::Unknown(value) => value,
+            known => known.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for PluginInstallStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
@@ -0,0 +1,110 @@
+use crate::Error;
+use serde::Serialize;
+use std::fmt;
+
+/// Quote `value` as a single-quoted Groovy string literal (no `${}` interpolation).
+#[must_use]
+pub fn groovy_string_literal(value: &str) -> String {
+    let mut out = String::with_capacity(value.len() + 2);
+    out.push('\'');
+    for ch in value.chars() {
+        match ch {
+            '\\' => out.push_str("\\\\"),
+            '\'' => out.push_str("\\'"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            ch if ch.is_control() => out.push_str(&format!("\\u{:04x}", ch as u32)),
+            ch => out.push(ch),
+        }
+    }
+    out.push('\'');
+    out
+}
+
+/// Script console source with caller values bound as Groovy variables.
+///
+/// Values are declared as `def <name> = ...` lines ahead of the body, quoted with
+/// [`groovy_string_literal`], so they never need to be spliced into the script text.
+///
+/// ```
+/// use jenkins_sdk::GroovyScript;
+///
+/// let script = GroovyScript::new("println(Jenkins.instance.getItemByFullName(job)?.url)")
+///     .bind("job", "team/app'; System.exit(0); '")?;
+/// assert!(script.to_string().starts_with("def job = 'team/app\\'; System.exit(0); \\''\n"));
+/// # Ok::<(), jenkins_sdk::Error>(())
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GroovyScript {
+    bindings: Vec<String>,
+    body: String,
+}
+
+impl GroovyScript {
+    #[must_use]
+    pub fn new(body: impl Into<String>) -> Self {
+        Self {
+            bindings: Vec::new(),
+            body: body.into(),
+        }
+    }
+
+    /// Bind `name` to a Groovy `String`.
+    pub fn bind(mut self, name: &str, value: &str) -> Result<Self, Error> {
+        check_identifier(name)?;
+        self.bindings
+            .push(format!("def {name} = {}", groovy_string_literal(value)));
+        Ok(self)
+    }
+
+    /// Bind `name` to `value` serialized as JSON and parsed back with `JsonSlurper` (maps,
+    /// lists, numbers, booleans and strings).
+    pub fn bind_json<T: Serialize + ?Sized>(
+        mut self,
+        name: &str,
+        value: &T,
+    ) -> Result<Self, Error> {
+        check_identifier(name)?;
+        let json = serde_json::to_string(value).map_err(|source| Error::InvalidConfig {
+            message: format!("failed to serialize script binding `{name}`").into(),
+            source: Some(Box::new(source)),
+        })?;
+        self.bindings.push(format!(
+            "def {name} = new groovy.json.JsonSlurper().parseText({})",
+            groovy_string_literal(&json)
+        ));
+        Ok(self)
+    }
+}
+
+impl fmt::Display for GroovyScript {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for binding in &self.bindings {
+            writeln!(f, "{binding}")?;
+        }
+        f.write_str(&self.body)
+    }
+}
+
+impl From<GroovyScript> for String {
+    fn from(script: GroovyScript) -> Self {
+        script.to_string()
+    }
+}
+
+fn check_identifier(name: &str) -> Result<(), Error> {
+    let mut chars = name.chars();
+    let valid = chars
+        .next()
+        .is_some_and(|first| first.is_ascii_alphabetic() || first == '_')
+        && chars.all(|ch| ch.is_ascii_alphanumeric() || ch == '_');
+    if valid {
+        Ok(())
+    } else {
+        Err(Error::InvalidConfig {
+            message: format!("`{name}` is not a valid Groovy variable name").into(),
+            source: None,
+        })
+    }
+}
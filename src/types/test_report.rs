@@ -0,0 +1,332 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+/// Status of one test case (`PASSED`, `FAILED`, `REGRESSION`, ...).
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+#[non_exhaustive]
+pub enum TestStatus {
+    Passed,
+    Skipped,
+    Failed,
+    /// Passing again after failing in the previous build.
+    Fixed,
+    /// Failing after passing in the previous build.
+    Regression,
+    /// Status string not known to this SDK.
+    Unknown(String),
+}
+
+impl TestStatus {
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Passed => "PASSED",
+            Self::Skipped => "SKIPPED",
+            Self::Failed => "FAILED",
+            Self::Fixed => "FIXED",
+            Self::Regression => "REGRESSION",
+            Self::Unknown(value) => value,
+        }
+    }
+
+    /// `FAILED` or `REGRESSION`.
+    #[must_use]
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Self::Failed | Self::Regression)
+    }
+
+    /// `PASSED` or `FIXED`.
+    #[must_use]
+    pub fn is_pass(&self) -> bool {
+        matches!(self, Self::Passed | Self::Fixed)
+    }
+}
+
+impl From<&str> for TestStatus {
+    fn from(value: &str) -> Self {
+        match value {
+            "PASSED" => Self::Passed,
+            "SKIPPED" => Self::Skipped,
+            "FAILED" => Self::Failed,
+            "FIXED" => Self::Fixed,
+            "REGRESSION" => Self::Regression,
+            other => Self::Unknown(other.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TestStatus {
+    fn from(value: String) -> Self {
+        match Self::from(value.as_str()) {
+            Self::Unknown(_) => Self::Unknown(value),
+            known => known,
+        }
+    }
+}
+
+impl From<TestStatus> for String {
+    fn from(value: TestStatus) -> Self {
+        match value {
+            TestStatus::Unknown(value) => value,
+            known => known.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for TestStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Result of `/job/<name>/<build>/testReport/api/json`.
+///
+/// Plain JUnit results carry `suites`; aggregated results (matrix, Maven) carry
+/// `child_reports` instead. [`TestReport::cases`] walks both.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct TestReport {
+    /// Total duration in seconds.
+    #[serde(default)]
+    pub duration: Option<f64>,
+    #[serde(default)]
+    pub empty: Option<bool>,
+    #[serde(default)]
+    pub fail_count: u64,
+    #[serde(default)]
+    pub pass_count: Option<u64>,
+    #[serde(default)]
+    pub skip_count: u64,
+    /// Only reported by aggregated results.
+    #[serde(default)]
+    pub total_count: Option<u64>,
+    #[serde(default)]
+    pub suites: Vec<TestSuite>,
+    #[serde(default)]
+    pub child_reports: Vec<ChildTestReport>,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+impl TestReport {
+    /// Every test case, including the ones of aggregated child reports.
+    pub fn cases(&self) -> Box<dyn Iterator<Item = &TestCase> + '_> {
+        Box::new(
+            self.suites
+                .iter()
+                .flat_map(|suite| suite.cases.iter())
+                .chain(
+                    self.child_reports
+                        .iter()
+                        .filter_map(|child| child.result.as_ref())
+                        .flat_map(TestReport::cases),
+                ),
+        )
+    }
+
+    /// Failing (`FAILED` / `REGRESSION`) test cases.
+    pub fn failures(&self) -> impl Iterator<Item = &TestCase> {
+        self.cases().filter(|case| case.is_failure())
+    }
+}
+
+/// One child of an aggregated test report.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct ChildTestReport {
+    /// Reference to the child build (`number`, `url`).
+    #[serde(default)]
+    pub child: Option<Value>,
+    #[serde(default)]
+    pub result: Option<TestReport>,
+}
+
+/// One JUnit test suite.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct TestSuite {
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Duration in seconds.
+    #[serde(default)]
+    pub duration: Option<f64>,
+    #[serde(default)]
+    pub id: Option<String>,
+    #[serde(default)]
+    pub timestamp: Option<String>,
+    #[serde(default)]
+    pub stdout: Option<String>,
+    #[serde(default)]
+    pub stderr: Option<String>,
+    #[serde(default)]
+    pub cases: Vec<TestCase>,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+/// One JUnit test case.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct TestCase {
+    #[serde(default)]
+    pub class_name: String,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub status: Option<TestStatus>,
+    /// Duration in seconds.
+    #[serde(default)]
+    pub duration: Option<f64>,
+    #[serde(default)]
+    pub error_details: Option<String>,
+    #[serde(default)]
+    pub error_stack_trace: Option<String>,
+    /// Captured stdout; Jenkins truncates large outputs.
+    #[serde(default)]
+    pub stdout: Option<String>,
+    /// Captured stderr; Jenkins truncates large outputs.
+    #[serde(default)]
+    pub stderr: Option<String>,
+    #[serde(default)]
+    pub skipped: Option<bool>,
+    #[serde(default)]
+    pub skipped_message: Option<String>,
+    /// Number of consecutive builds this test has been failing for.
+    #[serde(default)]
+    pub age: Option<u64>,
+    /// Build number this test started failing in.
+    #[serde(default)]
+    pub failed_since: Option<u64>,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+impl TestCase {
+    /// `<className>.<name>`, the key used to match cases across builds.
+    #[must_use]
+    pub fn full_name(&self) -> String {
+        if self.class_name.is_empty() {
+            self.name.clone()
+        } else {
+            format!("{}.{}", self.class_name, self.name)
+        }
+    }
+
+    #[must_use]
+    pub fn is_failure(&self) -> bool {
+        self.status.as_ref().is_some_and(TestStatus::is_failure)
+    }
+}
+
+/// Test cases whose outcome changed between two builds.
+#[derive(Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct TestReportDiff {
+    /// Failing in the current build but not in the previous one.
+    pub newly_failing: Vec<TestCase>,
+    /// Failing in the previous build and passing in the current one.
+    pub fixed: Vec<TestCase>,
+    /// Failing in both builds.
+    pub still_failing: Vec<TestCase>,
+}
+
+impl TestReportDiff {
+    /// Compare `current` against `previous`; cases are matched by [`TestCase::full_name`].
+    #[must_use]
+    pub fn between(previous: &TestReport, current: &TestReport) -> Self {
+        let failed_before: BTreeSet<String> =
+            previous.failures().map(TestCase::full_name).collect();
+
+        let mut diff = Self::default();
+        for case in current.cases() {
+            let name = case.full_name();
+            if case.is_failure() {
+                if failed_before.contains(&name) {
+                    diff.still_failing.push(case.clone());
+                } else {
+                    diff.newly_failing.push(case.clone());
+                }
+            } else if failed_before.contains(&name)
+                && case.status.as_ref().is_some_and(TestStatus::is_pass)
+            {
+                diff.fixed.push(case.clone());
+            }
+        }
+        diff
+    }
+
+    /// Whether any test started failing.
+    #[must_use]
+    pub fn has_new_failures(&self) -> bool {
+        !self.newly_failing.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report(cases: &[(&str, &str)]) -> TestReport {
+        serde_json::from_value(serde_json::json!({
+            "suites": [{
+                "name": "suite",
+                "cases": cases
+                    .iter()
+                    .map(|(name, status)| serde_json::json!({
+                        "className": "pkg.Test",
+                        "name": name,
+                        "status": status,
+                    }))
+                    .collect::<Vec<_>>(),
+            }]
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn diff_reports_new_fixed_and_persistent_failures() {
+        let previous = report(&[("a", "PASSED"), ("b", "FAILED"), ("c", "FAILED")]);
+        let current = report(&[("a", "REGRESSION"), ("b", "FIXED"), ("c", "FAILED")]);
+
+        let diff = TestReportDiff::between(&previous, &current);
+        let names = |cases: &[TestCase]| cases.iter().map(TestCase::full_name).collect::<Vec<_>>();
+
+        assert_eq!(names(&diff.newly_failing), ["pkg.Test.a"]);
+        assert_eq!(names(&diff.fixed), ["pkg.Test.b"]);
+        assert_eq!(names(&diff.still_failing), ["pkg.Test.c"]);
+        assert!(diff.has_new_failures());
+    }
+
+    #[test]
+    fn cases_include_aggregated_child_reports() {
+        let report: TestReport = serde_json::from_value(serde_json::json!({
+            "failCount": 1,
+            "totalCount": 2,
+            "childReports": [
+                { "child": { "number": 3 }, "result": { "suites": [{ "cases": [
+                    { "className": "A", "name": "x", "status": "PASSED" }
+                ]}]}},
+                { "child": { "number": 3 }, "result": { "suites": [{ "cases": [
+                    { "className": "B", "name": "y", "status": "FAILED" }
+                ]}]}}
+            ]
+        }))
+        .unwrap();
+
+        assert_eq!(report.cases().count(), 2);
+        assert_eq!(
+            report
+                .failures()
+                .map(TestCase::full_name)
+                .collect::<Vec<_>>(),
+            ["B.y"]
+        );
+    }
+}
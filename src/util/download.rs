@@ -0,0 +1,367 @@
+//! Bookkeeping shared by the async and blocking streaming artifact downloads.
+
+use crate::{
+    Artifact, ArtifactPath, DownloadConfig, DownloadProgress, DownloadSummary, Error, Fingerprint,
+};
+use http::{HeaderMap, HeaderValue, StatusCode};
+use std::path::{Path, PathBuf};
+
+/// `Range` header asking for everything after `offset`.
+pub(crate) fn range_header(offset: u64) -> HeaderValue {
+    HeaderValue::from_str(&format!("bytes={offset}-")).expect("range header is ASCII")
+}
+
+/// Whether the download needs an MD5 of the complete artifact.
+pub(crate) fn wants_checksum(config: &DownloadConfig) -> bool {
+    config.expected_md5.is_some() || config.verify_fingerprint
+}
+
+/// Pick the fingerprint recorded for `path`.
+///
+/// Fingerprints are matched on the full relative path. Records that only carry the file
+/// name are used when they agree on a single hash; otherwise the artifact is refused rather
+/// than checked against the digest of a same-named file from another directory.
+pub(crate) fn fingerprint_md5(
+    fingerprints: &[Fingerprint],
+    path: &ArtifactPath,
+) -> Result<String, Error> {
+    let segments: Vec<&str> = path.url_segments().collect();
+    let relative = segments.join("/");
+    let recorded = |name: &str| -> Vec<&str> {
+        fingerprints
+            .iter()
+            .filter(|fp| fp.file_name.as_deref() == Some(name))
+            .filter_map(|fp| fp.hash.as_deref())
+            .collect()
+    };
+    if let Some(hash) = recorded(&relative).first() {
+        return Ok((*hash).to_owned());
+    }
+
+    let file_name = segments.last().copied().unwrap_or_default();
+    let mut hashes = recorded(file_name);
+    hashes.sort_unstable();
+    hashes.dedup();
+    match hashes.as_slice() {
+        [hash] => Ok((*hash).to_owned()),
+        [] => Err(Error::InvalidConfig {
+            message: format!("no fingerprint recorded for artifact `{}`", path.as_str())
+                .into_boxed_str(),
+            source: None,
+        }),
+        _ => Err(Error::InvalidConfig {
+            message: format!(
+                "fingerprints for `{file_name}` are ambiguous; cannot verify artifact `{}`",
+                path.as_str()
+            )
+            .into_boxed_str(),
+            source: None,
+        }),
+    }
+}
+
+pub(crate) fn io_error(context: &str) -> impl FnOnce(std::io::Error) -> Error + '_ {
+    move |source| Error::Io {
+        context: context.into(),
+        source,
+    }
+}
+
+fn header_str(headers: &HeaderMap, name: http::header::HeaderName) -> Option<&str> {
+    headers.get(name)?.to_str().ok()
+}
+
+/// Tracks offsets, hashing and progress while response chunks are written out.
+pub(crate) struct DownloadTracker {
+    offset: u64,
+    skip: u64,
+    resumed_from: u64,
+    written: u64,
+    total: Option<u64>,
+    md5: Option<md5::Context>,
+    progress: Option<crate::DownloadProgressFn>,
+}
+
+impl DownloadTracker {
+    /// `prefix` is the MD5 state of the bytes already at the destination, when known.
+    pub(crate) fn start(
+        config: &DownloadConfig,
+        status: StatusCode,
+        headers: &HeaderMap,
+        prefix: Option<md5::Context>,
+    ) -> Self {
+        let offset = config.resume_from.unwrap_or(0);
+        let length = header_str(headers, http::header::CONTENT_LENGTH).and_then(|v| v.parse().ok());
+
+        if offset > 0 && status == StatusCode::PARTIAL_CONTENT {
+            // `Content-Range: bytes <start>-<end>/<total>`
+            let total = header_str(headers, http::header::CONTENT_RANGE)
+                .and_then(|v| v.rsplit_once('/'))
+                .and_then(|(_, total)| total.parse().ok())
+                .or(length.map(|len: u64| len + offset));
+            Self {
+                offset,
+                skip: 0,
+                resumed_from: offset,
+                written: 0,
+                total,
+                md5: prefix,
+                progress: config.progress.clone(),
+            }
+        } else {
+            Self {
+                offset,
+                skip: offset,
+                resumed_from: 0,
+                written: 0,
+                total: length,
+                md5: Some(md5::Context::new()),
+                progress: config.progress.clone(),
+            }
+        }
+    }
+
+    /// Fail before any of the body is read when the server resumed but the MD5 of the
+    /// bytes already at the destination is unknown, so the result could not be verified.
+    pub(crate) fn ensure_verifiable(&self, expected_md5: Option<&str>) -> Result<(), Error> {
+        if expected_md5.is_some() && self.md5.is_none() {
+            return Err(Error::InvalidConfig {
+                message: "cannot verify a resumed download without the existing prefix".into(),
+                source: None,
+            });
+        }
+        Ok(())
+    }
+
+    /// Hash a received chunk and return the part that still has to be written.
+    pub(crate) fn accept<'a>(&mut self, chunk: &'a [u8]) -> &'a [u8] {
+        if let Some(md5) = self.md5.as_mut() {
+            md5.consume(chunk);
+        }
+        let skipped = usize::try_from(self.skip).map_or(chunk.len(), |skip| skip.min(chunk.len()));
+        self.skip -= skipped as u64;
+        &chunk[skipped..]
+    }
+
+    /// Record `len` bytes as written and report progress.
+    pub(crate) fn written(&mut self, len: usize) {
+        if len == 0 {
+            return;
+        }
+        self.written += len as u64;
+        if let Some(progress) = &self.progress {
+            progress(DownloadProgress {
+                downloaded: self.downloaded(),
+                total: self.total,
+            });
+        }
+    }
+
+    /// The destination holds the first `offset` bytes either way: resumed, or skipped when
+    /// the server ignored `Range`.
+    fn downloaded(&self) -> u64 {
+        self.offset + self.written
+    }
+
+    pub(crate) fn finish(
+        mut self,
+        path: &ArtifactPath,
+        expected_md5: Option<&str>,
+    ) -> Result<DownloadSummary, Error> {
+        if self.skip > 0 {
+            return Err(Error::InvalidConfig {
+                message: format!(
+                    "resume offset is past the end of artifact `{}`",
+                    path.as_str()
+                )
+                .into_boxed_str(),
+                source: None,
+            });
+        }
+
+        let md5 = self.md5.take().map(|ctx| format!("{:x}", ctx.finalize()));
+        if let Some(expected) = expected_md5 {
+            let Some(actual) = md5.as_deref() else {
+                return Err(Error::InvalidConfig {
+                    message: "cannot verify a resumed download without the existing prefix".into(),
+                    source: None,
+                });
+            };
+            if !actual.eq_ignore_ascii_case(expected.trim()) {
+                return Err(Error::ChecksumMismatch {
+                    path: path.as_str().into(),
+                    expected: expected.trim().into(),
+                    actual: actual.into(),
+                });
+            }
+        }
+
+        Ok(DownloadSummary {
+            bytes_written: self.written,
+            total_bytes: self.downloaded(),
+            resumed_from: self.resumed_from,
+            md5,
+        })
+    }
+}
+
+/// MD5 state of an already downloaded prefix, read from the start of `file`.
+#[cfg(feature = "blocking")]
+pub(crate) fn hash_file(file: &mut std::fs::File) -> Result<md5::Context, Error> {
+    use std::io::{Read, Seek, SeekFrom};
+
+    let context = "hashing the partial download";
+    file.seek(SeekFrom::Start(0)).map_err(io_error(context))?;
+    let mut md5 = md5::Context::new();
+    let mut buf = vec![0; 64 * 1024];
+    loop {
+        let read = file.read(&mut buf).map_err(io_error(context))?;
+        if read == 0 {
+            return Ok(md5);
+        }
+        md5.consume(&buf[..read]);
+    }
+}
+
+/// MD5 state of an already downloaded prefix, read from the start of `file`.
+#[cfg(feature = "async")]
+pub(crate) async fn hash_file_async(file: &mut tokio::fs::File) -> Result<md5::Context, Error> {
+    use tokio::io::{AsyncReadExt, AsyncSeekExt};
+
+    let context = "hashing the partial download";
+    file.seek(std::io::SeekFrom::Start(0))
+        .await
+        .map_err(io_error(context))?;
+    let mut md5 = md5::Context::new();
+    let mut buf = vec![0; 64 * 1024];
+    loop {
+        let read = file.read(&mut buf).await.map_err(io_error(context))?;
+        if read == 0 {
+            return Ok(md5);
+        }
+        md5.consume(&buf[..read]);
+    }
+}
+
+/// Check that a partial file is long enough to resume from `offset`.
+pub(crate) fn check_partial_len(existing: u64, offset: u64) -> Result<(), Error> {
+    if existing < offset {
+        return Err(Error::InvalidConfig {
+            message: format!(
+                "resume offset {offset} is past the end of the partial file ({existing} bytes)"
+            )
+            .into_boxed_str(),
+            source: None,
+        });
+    }
+    Ok(())
+}
+
+/// Local file for `artifact` under `root`, refusing paths that would escape it.
+///
+/// Besides `..`, segments with `\` or `:` are refused since Windows treats them as
+/// separators or drive prefixes.
+pub(crate) fn mirror_path(root: &Path, artifact: &Artifact) -> Result<PathBuf, Error> {
+    let mut path = root.to_path_buf();
+    for segment in artifact.relative_path.split('/') {
+        match segment {
+            "" | "." => {}
+            segment if segment == ".." || segment.contains(['\\', ':']) => {
+                return Err(Error::InvalidConfig {
+                    message: format!(
+                        "artifact path `{}` escapes the mirror directory",
+                        artifact.relative_path
+                    )
+                    .into_boxed_str(),
+                    source: None,
+                });
+            }
+            segment => path.push(segment),
+        }
+    }
+    Ok(path)
+}
+
+/// Per-artifact config for a mirror run: no resume, checksum from the fingerprint list.
+pub(crate) fn mirror_config(
+    config: &DownloadConfig,
+    fingerprints: &[Fingerprint],
+    artifact: &Artifact,
+) -> Result<DownloadConfig, Error> {
+    let expected_md5 = if config.verify_fingerprint {
+        Some(fingerprint_md5(fingerprints, &artifact.path())?)
+    } else {
+        None
+    };
+    Ok(DownloadConfig {
+        resume_from: None,
+        expected_md5,
+        verify_fingerprint: false,
+        progress: config.progress.clone(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fingerprint(file_name: &str, hash: &str) -> Fingerprint {
+        Fingerprint {
+            file_name: Some(file_name.to_owned()),
+            hash: Some(hash.to_owned()),
+            ..Fingerprint::default()
+        }
+    }
+
+    #[test]
+    fn fingerprint_md5_matches_full_relative_path() {
+        let fingerprints = [
+            fingerprint("dist/linux/app.tar.gz", "aaaa"),
+            fingerprint("dist/mac/app.tar.gz", "bbbb"),
+        ];
+        let md5 = |path: &str| fingerprint_md5(&fingerprints, &ArtifactPath::new(path));
+        assert_eq!(md5("dist/linux/app.tar.gz").unwrap(), "aaaa");
+        assert_eq!(md5("dist/mac/app.tar.gz").unwrap(), "bbbb");
+        assert!(md5("dist/win/app.tar.gz").is_err());
+    }
+
+    #[test]
+    fn fingerprint_md5_refuses_ambiguous_file_names() {
+        let unique = [fingerprint("app.txt", "aaaa")];
+        assert_eq!(
+            fingerprint_md5(&unique, &ArtifactPath::new("out/app.txt")).unwrap(),
+            "aaaa"
+        );
+
+        let ambiguous = [
+            fingerprint("app.txt", "aaaa"),
+            fingerprint("app.txt", "bbbb"),
+        ];
+        let err = fingerprint_md5(&ambiguous, &ArtifactPath::new("out/app.txt")).unwrap_err();
+        assert!(err.to_string().contains("ambiguous"), "{err}");
+    }
+
+    #[test]
+    fn mirror_path_refuses_escaping_segments() {
+        let root = Path::new("mirror");
+        let artifact = |relative_path: &str| Artifact {
+            relative_path: relative_path.to_owned(),
+            ..Artifact::default()
+        };
+        assert_eq!(
+            mirror_path(root, &artifact("./dist//app.jar")).unwrap(),
+            root.join("dist").join("app.jar")
+        );
+        for escaping in [
+            "../app.jar",
+            "dist/..\\..\\app.jar",
+            "C:/app.jar",
+            "dist/C:app.jar",
+        ] {
+            assert!(
+                mirror_path(root, &artifact(escaping)).is_err(),
+                "{escaping}"
+            );
+        }
+    }
+}
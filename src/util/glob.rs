@@ -0,0 +1,72 @@
+/// Ant-style glob match, as used by Jenkins for artifact and job patterns.
+///
+/// `*` and `?` stay within one `/`-separated segment; `**` spans any number of segments
+/// (including none, so `**/*.jar` also matches `app.jar`).
+pub(crate) fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    match_from(&pattern, &text)
+}
+
+/// Whether `text` matches any of `patterns`; an empty list matches everything.
+pub(crate) fn glob_match_any<S: AsRef<str>>(patterns: &[S], text: &str) -> bool {
+    patterns.is_empty() || patterns.iter().any(|p| glob_match(p.as_ref(), text))
+}
+
+fn match_from(pattern: &[char], text: &[char]) -> bool {
+    match pattern {
+        [] => text.is_empty(),
+        ['*', '*', rest @ ..] => {
+            if let ['/', after @ ..] = rest
+                && match_from(after, text)
+            {
+                return true;
+            }
+            (0..=text.len()).any(|i| match_from(rest, &text[i..]))
+        }
+        ['*', rest @ ..] => {
+            for i in 0..=text.len() {
+                if match_from(rest, &text[i..]) {
+                    return true;
+                }
+                if text.get(i) == Some(&'/') {
+                    break;
+                }
+            }
+            false
+        }
+        ['?', rest @ ..] => {
+            matches!(text, [c, tail @ ..] if *c != '/' && match_from(rest, tail))
+        }
+        [p, rest @ ..] => matches!(text, [c, tail @ ..] if c == p && match_from(rest, tail)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn single_star_stays_within_a_segment() {
+        assert!(glob_match("*.jar", "app.jar"));
+        assert!(!glob_match("*.jar", "lib/app.jar"));
+        assert!(glob_match("lib/*.jar", "lib/app.jar"));
+        assert!(glob_match("app-?.zip", "app-1.zip"));
+        assert!(!glob_match("app-?.zip", "app-10.zip"));
+    }
+
+    #[test]
+    fn double_star_spans_segments() {
+        assert!(glob_match("**/*.jar", "app.jar"));
+        assert!(glob_match("**/*.jar", "a/b/app.jar"));
+        assert!(glob_match("target/**", "target/x/y.txt"));
+        assert!(!glob_match("target/**/*.txt", "other/x.txt"));
+    }
+
+    #[test]
+    fn empty_pattern_list_matches_everything() {
+        assert!(glob_match_any::<&str>(&[], "anything"));
+        assert!(glob_match_any(&["*.txt", "*.log"], "build.log"));
+        assert!(!glob_match_any(&["*.txt"], "build.log"));
+    }
+}
@@ -0,0 +1,118 @@
+//! Minimal scraping of Jenkins HTML forms that have no JSON API (e.g. the Replay page).
+
+/// `(name, content)` of every `<textarea>` in `html`, with the Stapler `_.` field prefix
+/// stripped and entities decoded.
+pub(crate) fn form_textareas(html: &str) -> Vec<(String, String)> {
+    let mut fields = Vec::new();
+    let mut rest = html;
+    while let Some(start) = rest.find("<textarea") {
+        rest = &rest[start + "<textarea".len()..];
+        let Some(tag_end) = rest.find('>') else {
+            break;
+        };
+        let attrs = &rest[..tag_end];
+        rest = &rest[tag_end + 1..];
+        let Some(close) = rest.find("</textarea>") else {
+            break;
+        };
+        let content = &rest[..close];
+        rest = &rest[close + "</textarea>".len()..];
+
+        if let Some(name) = attribute(attrs, "name") {
+            let name = name.strip_prefix("_.").unwrap_or(name);
+            // The browser drops one leading newline of a textarea's content.
+            let content = content.strip_prefix('\n').unwrap_or(content);
+            fields.push((unescape(name), unescape(content)));
+        }
+    }
+    fields
+}
+
+fn attribute<'a>(attrs: &'a str, name: &str) -> Option<&'a str> {
+    let mut rest = attrs;
+    loop {
+        let at = rest.find(name)?;
+        let preceded_by_space = rest[..at]
+            .chars()
+            .next_back()
+            .is_none_or(char::is_whitespace);
+        rest = &rest[at + name.len()..];
+        let value = rest.trim_start().strip_prefix('=').map(str::trim_start);
+        if let (true, Some(value)) = (preceded_by_space, value) {
+            let quote = value.chars().next()?;
+            if quote == '"' || quote == '\'' {
+                let value = &value[1..];
+                return value.find(quote).map(|end| &value[..end]);
+            }
+            let end = value.find(char::is_whitespace).unwrap_or(value.len());
+            return Some(&value[..end]);
+        }
+    }
+}
+
+/// Decode the named and numeric character references Jelly emits.
+pub(crate) fn unescape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(amp) = rest.find('&') {
+        out.push_str(&rest[..amp]);
+        rest = &rest[amp..];
+        let decoded = rest.find(';').and_then(|semi| {
+            let entity = &rest[1..semi];
+            let ch = match entity {
+                "amp" => Some('&'),
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                _ => entity
+                    .strip_prefix("#x")
+                    .or_else(|| entity.strip_prefix("#X"))
+                    .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                    .or_else(|| entity.strip_prefix('#').and_then(|dec| dec.parse().ok()))
+                    .and_then(char::from_u32),
+            };
+            ch.map(|ch| (ch, semi))
+        });
+        match decoded {
+            Some((ch, semi)) => {
+                out.push(ch);
+                rest = &rest[semi + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn textareas_are_extracted_and_decoded() {
+        let html = r#"<form><textarea class="x" name="_.mainScript" rows="20">
+node { sh 'echo &quot;a &amp;&amp; b&quot; &lt;&#62; &#x41;' }</textarea>
+<textarea name='_.Script1'>return this</textarea><textarea>ignored</textarea></form>"#;
+
+        assert_eq!(
+            form_textareas(html),
+            [
+                (
+                    "mainScript".to_owned(),
+                    "node { sh 'echo \"a && b\" <> A' }".to_owned()
+                ),
+                ("Script1".to_owned(), "return this".to_owned()),
+            ]
+        );
+    }
+
+    #[test]
+    fn unknown_entities_are_kept() {
+        assert_eq!(unescape("a &nbsp b &bogus; &amp;"), "a &nbsp b &bogus; &");
+    }
+}
@@ -1,6 +1,11 @@
 //! Internal utilities.
 
 pub(crate) mod diagnostics;
+pub(crate) mod download;
+pub(crate) mod glob;
+pub(crate) mod html;
 pub(crate) mod proxy_env;
 pub(crate) mod redact;
 pub(crate) mod url;
+pub(crate) mod version;
+pub(crate) mod xml;
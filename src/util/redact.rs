@@ -23,3 +23,14 @@ pub(crate) fn redact_text(mut text: String, auth: Option<&Auth>) -> String {
     }
     text
 }
+
+/// [`redact_text`] plus caller-supplied secrets.
+pub(crate) fn redact_secrets(text: String, auth: Option<&Auth>, extra: &[String]) -> String {
+    let mut text = redact_text(text, auth);
+    for secret in extra {
+        if !secret.is_empty() {
+            text = text.replace(secret.as_str(), "<redacted>");
+        }
+    }
+    text
+}
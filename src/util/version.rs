@@ -0,0 +1,70 @@
+use std::cmp::Ordering;
+
+/// Compare Jenkins plugin versions (`5.2.0`, `1.27`, `676.v886669a_199a_a_`, `2.0-rc1`).
+///
+/// Numeric components compare numerically and beat text components at the same position;
+/// a trailing text qualifier (`-rc1`, `-SNAPSHOT`) sorts before the plain release.
+pub(crate) fn compare_versions(a: &str, b: &str) -> Ordering {
+    let (a, b) = (components(a), components(b));
+    for (x, y) in a.iter().zip(&b) {
+        let ordering = match (x.parse::<u64>(), y.parse::<u64>()) {
+            (Ok(x), Ok(y)) => x.cmp(&y),
+            (Ok(_), Err(_)) => Ordering::Greater,
+            (Err(_), Ok(_)) => Ordering::Less,
+            (Err(_), Err(_)) => x.cmp(y),
+        };
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+    let extra_is_release = |rest: &[&str]| rest[0].parse::<u64>().is_ok();
+    match a.len().cmp(&b.len()) {
+        Ordering::Equal => Ordering::Equal,
+        Ordering::Greater if extra_is_release(&a[b.len()..]) => Ordering::Greater,
+        Ordering::Greater => Ordering::Less,
+        Ordering::Less if extra_is_release(&b[a.len()..]) => Ordering::Less,
+        Ordering::Less => Ordering::Greater,
+    }
+}
+
+/// Split on `.`, `-` and `_`, and between digits and letters (`rc10` is `rc`, `10`).
+fn components(version: &str) -> Vec<&str> {
+    let mut out = Vec::new();
+    for part in version.split(['.', '-', '_']) {
+        let mut start = 0;
+        let bytes = part.as_bytes();
+        for i in 1..bytes.len() {
+            if bytes[i].is_ascii_digit() != bytes[i - 1].is_ascii_digit() {
+                out.push(&part[start..i]);
+                start = i;
+            }
+        }
+        if start < part.len() {
+            out.push(&part[start..]);
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numeric_components_compare_numerically() {
+        assert_eq!(compare_versions("1.10", "1.9"), Ordering::Greater);
+        assert_eq!(compare_versions("5.2.0", "5.2.0"), Ordering::Equal);
+        assert_eq!(compare_versions("5.2.1", "5.2"), Ordering::Greater);
+        assert_eq!(
+            compare_versions("676.v886669a_199a_a_", "1.27"),
+            Ordering::Greater
+        );
+    }
+
+    #[test]
+    fn qualifiers_sort_before_releases() {
+        assert_eq!(compare_versions("2.0-rc1", "2.0"), Ordering::Less);
+        assert_eq!(compare_versions("2.0", "2.0-SNAPSHOT"), Ordering::Greater);
+        assert_eq!(compare_versions("2.0-rc2", "2.0-rc10"), Ordering::Less);
+    }
+}
@@ -0,0 +1,34 @@
+/// Escape text for use in XML element content or attribute values.
+pub(crate) fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            ch => out.push(ch),
+        }
+    }
+    out
+}
+
+/// Decoded text of every `<tag>...</tag>` element in `xml`, in document order.
+///
+/// Only suitable for leaf elements without attributes, such as JNLP `<argument>`s.
+pub(crate) fn element_texts(xml: &str, tag: &str) -> Vec<String> {
+    let open = format!("<{tag}>");
+    let close = format!("</{tag}>");
+    let mut texts = Vec::new();
+    let mut rest = xml;
+    while let Some(start) = rest.find(&open) {
+        rest = &rest[start + open.len()..];
+        let Some(end) = rest.find(&close) else {
+            break;
+        };
+        texts.push(super::html::unescape(&rest[..end]));
+        rest = &rest[end + close.len()..];
+    }
+    texts
+}
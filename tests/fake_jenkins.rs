@@ -0,0 +1,213 @@
+#[cfg(feature = "async")]
+mod async_fake {
+    use std::time::Duration;
+
+    use anyhow::Result;
+    use futures_util::StreamExt;
+    use http::{Method, StatusCode};
+    use jenkins_sdk::testing::FakeJenkins;
+    use jenkins_sdk::{BuildOutcome, BuildResult, ConsoleFollowConfig, Error, WaitConfig};
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn trigger_and_wait_follows_the_driven_queue_item() -> Result<()> {
+        let fake = FakeJenkins::new();
+        fake.add_job("team/app").set_next_queue_id(7);
+        let client = fake.client_builder()?.build()?;
+
+        let driver = fake.clone();
+        let wait = tokio::spawn(async move {
+            client
+                .jobs()
+                .trigger_and_wait(
+                    "team/app",
+                    WaitConfig::new(Duration::from_millis(5), Duration::from_secs(5)),
+                )
+                .await
+        });
+
+        while driver.pending_queue_items() != [7] {
+            tokio::time::sleep(Duration::from_millis(1)).await;
+        }
+        driver
+            .start_build(7, 12)
+            .append_log("team/app", 12, "compiling\n")
+            .finish_build("team/app", 12, BuildResult::Failure);
+
+        let BuildOutcome::Completed(build) = wait.await?? else {
+            panic!("queue item was not cancelled");
+        };
+        assert_eq!(build.number, Some(12));
+        assert_eq!(build.result, Some(BuildResult::Failure));
+        assert_eq!(
+            fake.requests().first().map(String::as_str),
+            Some("POST /job/team/job/app/build")
+        );
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn follow_console_reads_progressive_log() -> Result<()> {
+        let fake = FakeJenkins::new();
+        fake.add_pipeline("app");
+        let queue_item = fake.enqueue("app");
+        fake.start_build(queue_item, 1)
+            .append_log("app", 1, "step 1\n");
+        let client = fake.client_builder()?.build()?;
+
+        let mut lines = Box::pin(client.jobs().follow_console_lines(
+            "app",
+            "1",
+            ConsoleFollowConfig::new(Duration::from_millis(1), Duration::from_millis(5)),
+        ));
+        assert_eq!(
+            lines.next().await.transpose()?.as_deref(),
+            Some("Started by user fake")
+        );
+        assert_eq!(lines.next().await.transpose()?.as_deref(), Some("step 1"));
+
+        fake.append_log("app", 1, "step 2\n")
+            .finish_build("app", 1, BuildResult::Success);
+        let rest: Vec<String> = lines.map(|line| line.unwrap()).collect().await;
+        assert_eq!(rest, ["step 2", "Finished: SUCCESS"]);
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn progressive_text_offsets_are_bytes() -> Result<()> {
+        let fake = FakeJenkins::new();
+        fake.add_pipeline("app");
+        let queue_item = fake.enqueue("app");
+        fake.start_build(queue_item, 1)
+            .append_log("app", 1, "café\n");
+        let client = fake.client_builder()?.build()?;
+
+        let full = client
+            .jobs()
+            .progressive_console_text("app", "1", 0)
+            .await?;
+        let size = full.next_start.expect("X-Text-Size");
+        let text = client
+            .jobs()
+            .progressive_console_text("app", "1", size - 2)
+            .await?;
+        assert_eq!(text.text, "\u{fffd}\n");
+        assert_eq!(text.next_start, Some(size));
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn crumbs_and_injected_failures_surface_as_errors() -> Result<()> {
+        let fake = FakeJenkins::new();
+        fake.add_job("app").require_crumb("c-1");
+
+        let without_crumb = fake.client_builder()?.build()?;
+        let err = without_crumb.jobs().disable("app").await.unwrap_err();
+        assert_eq!(err.status(), Some(StatusCode::FORBIDDEN));
+
+        let client = fake
+            .client_builder()?
+            .with_crumb(Duration::from_secs(60))
+            .build()?;
+        client.jobs().disable("app").await?;
+        assert!(fake.is_disabled("app"));
+
+        let err = client.jobs().build("app").await.unwrap_err();
+        assert_eq!(err.status(), Some(StatusCode::CONFLICT));
+
+        fake.fail_next(
+            Method::GET,
+            "/job/app/api/json",
+            StatusCode::SERVICE_UNAVAILABLE,
+        );
+        let err = client.jobs().get("app", None).await.unwrap_err();
+        assert!(matches!(err, Error::Api(_)));
+        assert_eq!(err.status(), Some(StatusCode::SERVICE_UNAVAILABLE));
+        assert!(client.jobs().get("app", None).await.is_ok());
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn folders_nodes_and_views_are_modelled() -> Result<()> {
+        let fake = FakeJenkins::new();
+        fake.add_job("a/app")
+            .add_folder("b")
+            .add_node("linux-1", 4)
+            .set_node_offline("linux-1", Some("maintenance"))
+            .add_view("team", ["a/app"]);
+        let client = fake.client_builder()?.build()?;
+
+        let moved = client.jobs().move_job("a/app", "b").await?;
+        assert_eq!(moved.as_str(), "b/app");
+        assert!(fake.job_exists("b/app"));
+        assert!(!fake.job_exists("a/app"));
+
+        let computers = client.computers().list(None).await?;
+        let names: Vec<_> = computers
+            .computer
+            .iter()
+            .map(|c| (c.display_name.clone().unwrap(), c.offline))
+            .collect();
+        assert_eq!(
+            names,
+            [
+                ("(built-in)".to_owned(), Some(false)),
+                ("linux-1".to_owned(), Some(true))
+            ]
+        );
+
+        let view = client.views().get("team", None).await?;
+        assert!(view.jobs.is_empty(), "moved job left the view");
+        Ok(())
+    }
+}
+
+#[cfg(feature = "blocking")]
+mod blocking_fake {
+    use std::time::Duration;
+
+    use anyhow::Result;
+    use jenkins_sdk::testing::FakeJenkins;
+    use jenkins_sdk::{BuildOutcome, BuildResult, WaitConfig};
+
+    #[test]
+    fn blocking_wait_reports_cancelled_queue_item() -> Result<()> {
+        let fake = FakeJenkins::new();
+        fake.add_job("app");
+        let queue_item = fake.enqueue("app");
+        fake.cancel_queue_item(queue_item);
+        let client = fake.blocking_client_builder()?.build()?;
+
+        let outcome = client.jobs().wait_for_build(
+            "app",
+            queue_item.to_string(),
+            WaitConfig::new(Duration::ZERO, Duration::from_secs(1)),
+        )?;
+        assert!(matches!(outcome, BuildOutcome::Cancelled(item) if item.cancelled == Some(true)));
+        Ok(())
+    }
+
+    #[test]
+    fn blocking_triggered_parameters_reach_the_build() -> Result<()> {
+        let fake = FakeJenkins::new();
+        fake.add_job("app");
+        let client = fake.blocking_client_builder()?.build()?;
+
+        let triggered = client
+            .jobs()
+            .build_with_parameters("app", [("BRANCH", "main")])?;
+        let queue_item: u64 = triggered.queue_item_id.unwrap().as_str().parse()?;
+        fake.complete_queue_item(queue_item, 3, BuildResult::Success);
+
+        assert_eq!(
+            fake.build_parameters("app", 3),
+            [("BRANCH".to_owned(), "main".to_owned())]
+        );
+        let last = client.jobs().last_successful_build("app", None)?;
+        assert_eq!(last.number, Some(3));
+        assert_eq!(
+            client.jobs().last_console_text("app")?.lines().last(),
+            Some("Finished: SUCCESS")
+        );
+        Ok(())
+    }
+}
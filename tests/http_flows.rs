@@ -138,6 +138,7 @@ mod fixture {
 #[cfg(feature = "async")]
 mod async_computers {
     use anyhow::Result;
+    use jenkins_sdk::{NodeMode, NodeSpec, RetentionStrategy, SshLauncher};
     use wiremock::{
         Mock, MockServer, ResponseTemplate,
         matchers::{body_string_contains, header, method, path, query_param},
@@ -169,6 +170,128 @@ mod async_computers {
         server.verify().await;
         Ok(())
     }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn async_computers_create_posts_typed_node_form() -> Result<()> {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/computer/doCreateItem"))
+            .and(header("Content-Type", "application/x-www-form-urlencoded"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let spec = NodeSpec::new("build-7", "/home/jenkins")
+            .executors(4)
+            .labels(["linux", "docker"])
+            .mode(NodeMode::Exclusive)
+            .retention(RetentionStrategy::Demand {
+                in_demand_delay: 0,
+                idle_delay: 10,
+            })
+            .launcher(SshLauncher::new("10.0.0.7", "agent-ssh").port(2222))
+            .env("GRADLE_OPTS", "-Xmx2g")
+            .tool_location("hudson.model.JDK$DescriptorImpl", "jdk17", "/opt/jdk-17");
+        let client = build_async_client(server.uri())?;
+        client.computers().create(&spec).await?;
+
+        let requests = server.received_requests().await.unwrap_or_default();
+        let form: Vec<(String, String)> = url::form_urlencoded::parse(&requests[0].body)
+            .map(|(k, v)| (k.into_owned(), v.into_owned()))
+            .collect();
+        assert_eq!(form[0], ("name".to_owned(), "build-7".to_owned()));
+        assert_eq!(
+            form[1],
+            ("type".to_owned(), "hudson.slaves.DumbSlave".to_owned())
+        );
+        let json: serde_json::Value = serde_json::from_str(&form[2].1)?;
+        assert_eq!(json["numExecutors"], 4);
+        assert_eq!(json["labelString"], "linux docker");
+        assert_eq!(json["mode"], "EXCLUSIVE");
+        assert_eq!(
+            json["launcher"]["$class"],
+            "hudson.plugins.sshslaves.SSHLauncher"
+        );
+        assert_eq!(json["launcher"]["port"], 2222);
+        assert_eq!(json["launcher"]["credentialsId"], "agent-ssh");
+        assert_eq!(json["retentionStrategy"]["idleDelay"], 10);
+        assert_eq!(
+            json["nodeProperties"]["hudson-slaves-EnvironmentVariablesNodeProperty"]["env"],
+            serde_json::json!([{"key": "GRADLE_OPTS", "value": "-Xmx2g"}])
+        );
+        assert_eq!(
+            json["nodeProperties"]["hudson-tools-ToolLocationNodeProperty"]["locations"][0]["key"],
+            "hudson.model.JDK$DescriptorImpl@jdk17"
+        );
+
+        server.verify().await;
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn async_computers_launch_info_falls_back_to_slave_agent_jnlp() -> Result<()> {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/computer/edge-1/jenkins-agent.jnlp"))
+            .respond_with(ResponseTemplate::new(404))
+            .expect(2)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/computer/edge-1/slave-agent.jnlp"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                r#"<jnlp codebase="http://ci.example/computer/edge-1/" spec="1.0+">
+  <application-desc main-class="hudson.remoting.jnlp.Main">
+    <argument>5e&amp;cr3t</argument>
+    <argument>edge-1</argument>
+    <argument>-workDir</argument>
+    <argument>/srv/agent</argument>
+    <argument>-internalDir</argument>
+    <argument>remoting</argument>
+    <argument>-webSocket</argument>
+    <argument>-url</argument>
+    <argument>http://ci.example/</argument>
+  </application-desc>
+</jnlp>"#,
+            ))
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        let client = build_async_client(server.uri())?;
+        let info = client.computers().launch_info("edge-1").await?;
+        assert_eq!(info.name, "edge-1");
+        assert_eq!(info.secret.expose(), "5e&cr3t");
+        assert_eq!(info.url.as_deref(), Some("http://ci.example/"));
+        assert_eq!(info.work_dir.as_deref(), Some("/srv/agent"));
+        assert!(info.web_socket && info.tunnel.is_none());
+        assert!(!format!("{info:?}").contains("5e&cr3t"));
+        assert_eq!(
+            info.agent_args(),
+            [
+                "-url",
+                "http://ci.example/",
+                "-secret",
+                "5e&cr3t",
+                "-name",
+                "edge-1",
+                "-workDir",
+                "/srv/agent",
+                "-internalDir",
+                "remoting",
+                "-webSocket"
+            ]
+        );
+
+        let secret = client.computers().agent_secret("edge-1").await?;
+        assert_eq!(secret.expose(), "5e&cr3t");
+
+        server.verify().await;
+        Ok(())
+    }
 }
 
 #[cfg(feature = "async")]
@@ -177,7 +300,7 @@ mod async_core {
 
     use anyhow::Result;
     use http::StatusCode;
-    use jenkins_sdk::{Client, Error, RetryConfig};
+    use jenkins_sdk::{CassetteConfig, CassetteFormat, Client, Error, FileParameter, RetryConfig};
     use serde_json::json;
     use wiremock::{
         Mock, MockServer, ResponseTemplate,
@@ -185,7 +308,7 @@ mod async_core {
     };
 
     use crate::fixture::{
-        TEST_USER, build_async_authed_client, build_async_client, mock_crumb, mock_get,
+        TEST_TOKEN, TEST_USER, build_async_authed_client, build_async_client, mock_crumb, mock_get,
         mock_post_with_auth,
     };
 
@@ -450,139 +573,270 @@ mod async_core {
         server.verify().await;
         Ok(())
     }
-}
-
-#[cfg(feature = "async")]
-mod async_jobs {
-    use anyhow::Result;
-    use serde_json::json;
-    use wiremock::{
-        Mock, MockServer, ResponseTemplate,
-        matchers::{method, path, query_param},
-    };
-
-    use crate::fixture::{build_async_client, mock_get};
-
-    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
-    async fn async_jobs_info_uses_tree_query_param() -> Result<()> {
-        let server = MockServer::start().await;
-
-        Mock::given(method("GET"))
-            .and(path("/api/json"))
-            .and(query_param("tree", "jobs[name,url,color]"))
-            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
-                "jobs": []
-            })))
-            .expect(1)
-            .up_to_n_times(1)
-            .mount(&server)
-            .await;
 
-        let client = build_async_client(server.uri())?;
-
-        let jobs = client.jobs().list().await?;
-        assert!(jobs.jobs.is_empty());
+    /// Answers like Jenkins would and records `<method> <path> crumb=<..> hook=<..>`.
+    #[derive(Default)]
+    struct ScriptedTransport {
+        seen: std::sync::Mutex<Vec<String>>,
+    }
 
-        server.verify().await;
-        Ok(())
+    #[jenkins_sdk::async_trait]
+    impl jenkins_sdk::AsyncTransport for ScriptedTransport {
+        async fn send(
+            &self,
+            req: jenkins_sdk::TransportRequest,
+        ) -> std::result::Result<jenkins_sdk::TransportResponse, Error> {
+            let header = |name: &str| {
+                req.headers
+                    .get(name)
+                    .and_then(|value| value.to_str().ok())
+                    .unwrap_or("-")
+                    .to_owned()
+            };
+            let mut seen = self.seen.lock().unwrap();
+            seen.push(format!(
+                "{} {} crumb={} hook={}",
+                req.method,
+                req.url.path(),
+                header("Jenkins-Crumb"),
+                header("X-Hook")
+            ));
+            let attempt = seen.len();
+
+            let mut headers = http::HeaderMap::new();
+            let (status, body) = match req.url.path() {
+                "/crumbIssuer/api/json" => (
+                    StatusCode::OK,
+                    json!({ "crumbRequestField": "Jenkins-Crumb", "crumb": "c-1" }).to_string(),
+                ),
+                // The first trigger attempt hits a busy controller.
+                "/job/app/build" if attempt == 2 => {
+                    (StatusCode::SERVICE_UNAVAILABLE, String::new())
+                }
+                "/job/app/build" => {
+                    headers.insert(
+                        http::header::LOCATION,
+                        http::HeaderValue::from_static("http://jenkins.local/queue/item/5/"),
+                    );
+                    (StatusCode::CREATED, String::new())
+                }
+                _ => (StatusCode::NOT_FOUND, String::new()),
+            };
+            Ok(jenkins_sdk::TransportResponse::new(status, headers, body))
+        }
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
-    async fn async_jobs_progressive_console_text_parses_headers() -> Result<()> {
-        let server = MockServer::start().await;
-
-        Mock::given(method("GET"))
-            .and(path("/job/demo/1/logText/progressiveText"))
-            .and(query_param("start", "0"))
-            .respond_with(
-                ResponseTemplate::new(200)
-                    .append_header("X-Text-Size", "5")
-                    .append_header("X-More-Data", "true")
-                    .set_body_string("hello"),
-            )
-            .expect(1)
-            .up_to_n_times(1)
-            .mount(&server)
-            .await;
-
-        let client = build_async_client(server.uri())?;
+    async fn async_custom_transport_keeps_middleware_stack() -> Result<()> {
+        let transport = std::sync::Arc::new(ScriptedTransport::default());
+        let mut retry = RetryConfig::new(1, Duration::ZERO);
+        retry.retry_non_idempotent = true;
+        let client = Client::builder("http://jenkins.local")?
+            .auth_basic(TEST_USER, "token")
+            .request_hook(|ctx| {
+                ctx.headers
+                    .insert("X-Hook", http::HeaderValue::from_static("on"));
+                Ok(())
+            })
+            .retry_config(retry)
+            .with_crumb(Duration::from_secs(60))
+            .transport(transport.clone())
+            .build()?;
 
-        let chunk = client
-            .jobs()
-            .progressive_console_text("demo", "1", 0)
-            .await?;
-        assert_eq!(chunk.text, "hello");
-        assert_eq!(chunk.next_start, Some(5));
-        assert!(chunk.more_data);
+        let triggered = client.jobs().build("app").await?;
+        assert_eq!(
+            triggered.queue_item_id.as_ref().map(|id| id.as_str()),
+            Some("5")
+        );
 
-        server.verify().await;
+        assert_eq!(
+            *transport.seen.lock().unwrap(),
+            [
+                "GET /crumbIssuer/api/json crumb=- hook=on",
+                "POST /job/app/build crumb=c-1 hook=on",
+                "POST /job/app/build crumb=c-1 hook=on",
+            ]
+        );
         Ok(())
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
-    async fn async_jobs_download_artifact_splits_path_segments() -> Result<()> {
+    async fn async_cassette_records_scrubbed_and_replays_offline() -> Result<()> {
+        let cassette =
+            std::env::temp_dir().join(format!("jenkins-sdk-cassette-{}.json", std::process::id()));
         let server = MockServer::start().await;
-
+        let base = server.uri();
+        mock_crumb(&server, "c-1", 1).await;
         mock_get(
             &server,
-            "/job/demo/1/artifact/a/b%20c.txt",
-            ResponseTemplate::new(200).set_body_bytes(vec![1, 2, 3]),
+            "/job/app/api/json",
+            ResponseTemplate::new(200)
+                .set_body_json(json!({"name": "app", "description": "deploy with token"})),
+            1,
+        )
+        .await;
+        mock_post_with_auth(
+            &server,
+            "/job/app/buildWithParameters",
+            Some("c-1"),
+            Some("PASSWORD=s3cret"),
+            ResponseTemplate::new(201)
+                .insert_header("Location", format!("{base}/queue/item/5/").as_str()),
             1,
         )
         .await;
 
-        let client = build_async_client(server.uri())?;
-
-        let bytes = client
+        let builder = |config: CassetteConfig| -> Result<Client> {
+            Ok(Client::builder(base.as_str())?
+                .no_system_proxy()
+                .auth_basic(TEST_USER, TEST_TOKEN)
+                .with_crumb(Duration::from_secs(60))
+                .cassette(config.secret("s3cret"))
+                .build()?)
+        };
+
+        let recording = builder(CassetteConfig::record(&cassette))?;
+        recording.jobs().get("app", None).await?;
+        recording
             .jobs()
-            .download_artifact("demo", "1", "a/b c.txt")
+            .build_with_parameters("app", [("PASSWORD", "s3cret")])
             .await?;
-        assert_eq!(bytes, vec![1, 2, 3]);
-
         server.verify().await;
+        drop(server);
+
+        assert!(!cassette.exists(), "recordings are buffered until saved");
+        recording.save_cassette().await?;
+        let recorded = std::fs::read_to_string(&cassette)?;
+        assert!(!recorded.contains(TEST_TOKEN));
+        assert!(!recorded.contains("s3cret"));
+
+        let replaying = builder(CassetteConfig::replay(&cassette))?;
+        let job = replaying.jobs().get("app", None).await?;
+        assert_eq!(job.description.as_deref(), Some("deploy with <redacted>"));
+        let triggered = replaying
+            .jobs()
+            .build_with_parameters("app", [("PASSWORD", "s3cret")])
+            .await?;
+        assert_eq!(
+            triggered.queue_item_id.as_ref().map(|id| id.as_str()),
+            Some("5")
+        );
+
+        let err = replaying.jobs().get("app", None).await.unwrap_err();
+        assert!(matches!(err, Error::Transport { .. }), "{err}");
+        std::fs::remove_file(&cassette)?;
         Ok(())
     }
-}
 
-#[cfg(feature = "async")]
-mod async_system {
-    use anyhow::Result;
-    use wiremock::{MockServer, ResponseTemplate};
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn async_cassette_replays_multipart_uploads() -> Result<()> {
+        let cassette = std::env::temp_dir().join(format!(
+            "jenkins-sdk-cassette-multipart-{}.json",
+            std::process::id()
+        ));
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/job/release/buildWithParameters"))
+            .respond_with(ResponseTemplate::new(201).append_header("Location", "/queue/item/8/"))
+            .expect(1)
+            .mount(&server)
+            .await;
 
-    use crate::fixture::{build_async_client, mock_get};
+        let builder = |base: &str, config: CassetteConfig| -> Result<Client> {
+            Ok(Client::builder(base)?
+                .no_system_proxy()
+                .cassette(config)
+                .build()?)
+        };
+        let upload = |client: Client| async move {
+            client
+                .jobs()
+                .build_with_files(
+                    "release",
+                    [("VERSION", "1.2.3")],
+                    [FileParameter::bytes(
+                        "BUNDLE",
+                        "bundle.tar",
+                        b"tarball".to_vec(),
+                    )],
+                )
+                .await
+        };
+
+        let recording = builder(&server.uri(), CassetteConfig::record(&cassette))?;
+        upload(recording.clone()).await?;
+        recording.save_cassette().await?;
+        server.verify().await;
+        drop(server);
+
+        let recorded = std::fs::read_to_string(&cassette)?;
+        assert!(
+            recorded.contains("jenkins-sdk-cassette-boundary"),
+            "{recorded}"
+        );
+
+        let replaying = builder("http://jenkins.invalid", CassetteConfig::replay(&cassette))?;
+        let triggered = upload(replaying).await?;
+        assert_eq!(
+            triggered.queue_item_id.as_ref().map(|id| id.as_str()),
+            Some("8")
+        );
+        std::fs::remove_file(&cassette)?;
+        Ok(())
+    }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
-    async fn async_system_downloads_jnlp_jars() -> Result<()> {
-        let server = MockServer::start().await;
+    async fn async_cassette_yaml_round_trips_or_needs_the_feature() -> Result<()> {
+        let cassette =
+            std::env::temp_dir().join(format!("jenkins-sdk-cassette-{}.yaml", std::process::id()));
+        assert_eq!(
+            CassetteConfig::record(&cassette).format,
+            CassetteFormat::Yaml
+        );
+        let builder = |base: &str, config: CassetteConfig| -> Result<Client> {
+            Ok(Client::builder(base)?
+                .no_system_proxy()
+                .cassette(config)
+                .build()?)
+        };
+        if cfg!(not(feature = "yaml")) {
+            let err = builder("http://jenkins.invalid", CassetteConfig::record(&cassette))
+                .err()
+                .expect("yaml feature is off");
+            assert!(err.to_string().contains("`yaml` feature"), "{err}");
+            return Ok(());
+        }
 
+        let server = MockServer::start().await;
         mock_get(
             &server,
-            "/jnlpJars/agent.jar",
-            ResponseTemplate::new(200).set_body_bytes(vec![1]),
-            1,
-        )
-        .await;
-        mock_get(
-            &server,
-            "/jnlpJars/jenkins-cli.jar",
-            ResponseTemplate::new(200).set_body_bytes(vec![2]),
+            "/job/app/api/json",
+            ResponseTemplate::new(200).set_body_json(json!({"name": "app"})),
             1,
         )
         .await;
+        let recording = builder(&server.uri(), CassetteConfig::record(&cassette))?;
+        recording.jobs().get("app", None).await?;
+        drop(recording);
+        server.verify().await;
+        drop(server);
 
-        let client = build_async_client(server.uri())?;
-
-        assert_eq!(client.system().agent_jar().await?, vec![1]);
-        assert_eq!(client.system().cli_jar().await?, vec![2]);
+        let recorded = std::fs::read_to_string(&cassette)?;
+        assert!(recorded.starts_with("interactions:"), "{recorded}");
 
-        server.verify().await;
+        let replaying = builder("http://jenkins.invalid", CassetteConfig::replay(&cassette))?;
+        let job = replaying.jobs().get("app", None).await?;
+        assert_eq!(job.name.as_deref(), Some("app"));
+        std::fs::remove_file(&cassette)?;
         Ok(())
     }
 }
 
 #[cfg(feature = "async")]
-mod async_users {
+mod async_credentials {
     use anyhow::Result;
+    use jenkins_sdk::{CredentialScope, CredentialSpec, CredentialStore, SecretString};
+    use serde_json::json;
     use wiremock::{
         Mock, MockServer, ResponseTemplate,
         matchers::{body_string_contains, header, method, path},
@@ -591,86 +845,1816 @@ mod async_users {
     use crate::fixture::{build_async_client, mock_get};
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
-    async fn async_users_config_xml_supports_get_and_update() -> Result<()> {
+    async fn async_credentials_list_folder_store_metadata() -> Result<()> {
         let server = MockServer::start().await;
-
         mock_get(
             &server,
-            "/user/alice/config.xml",
-            ResponseTemplate::new(200).set_body_string("<u/>"),
+            "/job/team/job/infra/credentials/store/folder/domain/_/api/json",
+            ResponseTemplate::new(200).set_body_json(json!({
+                "credentials": [{
+                    "id": "deploy-key",
+                    "displayName": "deploy (ssh)",
+                    "description": "deploy",
+                    "typeName": "SSH Username with private key",
+                    "fullName": "team/infra/deploy-key"
+                }]
+            })),
             1,
         )
         .await;
 
+        let client = build_async_client(server.uri())?;
+        let store = CredentialStore::folder("team/infra");
+        let credentials = client.credentials().list(&store).await?;
+        assert_eq!(credentials.len(), 1);
+        assert_eq!(credentials[0].id, "deploy-key");
+        assert_eq!(
+            credentials[0].type_name.as_deref(),
+            Some("SSH Username with private key")
+        );
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn async_credentials_create_update_delete_in_system_store() -> Result<()> {
+        let server = MockServer::start().await;
+        let domain = "/credentials/store/system/domain/_";
+
         Mock::given(method("POST"))
-            .and(path("/user/alice/config.xml"))
+            .and(path(format!("{domain}/createCredentials")))
             .and(header("Content-Type", "application/xml"))
-            .and(body_string_contains("<u/>"))
+            .and(body_string_contains(
+                "<com.cloudbees.plugins.credentials.impl.UsernamePasswordCredentialsImpl>",
+            ))
+            .and(body_string_contains("<scope>SYSTEM</scope>"))
+            .and(body_string_contains("<password>p&lt;w&amp;d</password>"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path(format!("{domain}/credential/api-token/config.xml")))
+            .and(body_string_contains("<secret>s3cr3t</secret>"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path(format!("{domain}/credential/nexus/doDelete")))
             .respond_with(ResponseTemplate::new(200))
             .expect(1)
-            .up_to_n_times(1)
             .mount(&server)
             .await;
 
         let client = build_async_client(server.uri())?;
+        let store = CredentialStore::system();
+        let nexus = CredentialSpec::username_password("nexus", "ci", SecretString::new("p<w&d"))
+            .description("Nexus upload")
+            .scope(CredentialScope::System);
+        assert!(!format!("{nexus:?}").contains("p<w&d"));
 
-        let xml = client.users().get_config_xml("alice").await?;
-        assert_eq!(String::from_utf8_lossy(&xml), "<u/>");
-
-        client.users().update_config_xml("alice", xml).await?;
+        client.credentials().create(&store, &nexus).await?;
+        client
+            .credentials()
+            .update(
+                &store,
+                &CredentialSpec::secret_text("api-token", SecretString::new("s3cr3t")),
+            )
+            .await?;
+        client.credentials().delete(&store, "nexus").await?;
 
         server.verify().await;
         Ok(())
     }
 }
 
-#[cfg(feature = "blocking")]
-mod blocking_core {
+#[cfg(feature = "async")]
+mod async_jobs {
     use std::time::Duration;
 
+    use std::sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+    };
+
     use anyhow::Result;
-    use jenkins_sdk::{BlockingClient, RetryConfig};
+    use futures_util::TryStreamExt;
+    use jenkins_sdk::{
+        BuildOutcome, BuildQuery, BuildResult, ConsoleFollowConfig, DownloadConfig, Error,
+        FileParameter, JobKind, JobWalkConfig, ParameterType, TestStatus, WaitConfig,
+    };
     use serde_json::json;
     use wiremock::{
         Mock, MockServer, ResponseTemplate,
-        matchers::{method, path},
+        matchers::{body_string_contains, header, header_regex, method, path, query_param},
     };
 
-    use crate::fixture::{
-        TEST_TOKEN, TEST_USER, build_blocking_authed_client, mock_crumb, mock_post_with_auth,
-        run_blocking,
-    };
+    use crate::fixture::{build_async_client, mock_get};
+
+    const HELLO_WORLD_MD5: &str = "5eb63bbbe01eeed093cb22bb8f5acdc3";
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
-    async fn blocking_client_reuses_crumb_with_retry() -> Result<()> {
+    async fn async_jobs_trigger_and_wait_follows_queue_item_to_finished_build() -> Result<()> {
         let server = MockServer::start().await;
 
-        mock_crumb(&server, "token", 1).await;
-
-        mock_post_with_auth(
+        Mock::given(method("POST"))
+            .and(path("/job/demo/build"))
+            .respond_with(ResponseTemplate::new(201).append_header("Location", "/queue/item/7/"))
+            .expect(1)
+            .mount(&server)
+            .await;
+        mock_get(
             &server,
-            "/job/demo/1/stop",
-            Some("token"),
-            None,
-            ResponseTemplate::new(503),
+            "/queue/item/7/api/json",
+            ResponseTemplate::new(200).set_body_json(json!({ "id": 7, "why": "Waiting" })),
             1,
         )
         .await;
-
-        mock_post_with_auth(
+        mock_get(
             &server,
-            "/job/demo/1/stop",
-            Some("token"),
-            None,
-            ResponseTemplate::new(200).set_body_string("stopped"),
+            "/queue/item/7/api/json",
+            ResponseTemplate::new(200).set_body_json(json!({
+                "id": 7,
+                "executable": { "number": 12, "url": "http://jenkins/job/demo/12/" }
+            })),
             1,
         )
         .await;
-
-        let base_url = server.uri();
-        run_blocking(move || -> Result<()> {
-            let client = BlockingClient::builder(base_url)?
-                .no_system_proxy()
+        Mock::given(method("GET"))
+            .and(path("/job/demo/12/api/json"))
+            .and(query_param("tree", "number,building,result"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "number": 12,
+                "building": true
+            })))
+            .expect(1)
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/job/demo/12/api/json"))
+            .and(query_param("tree", "number,building,result"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "number": 12,
+                "building": false,
+                "result": "SUCCESS"
+            })))
+            .expect(1)
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        mock_get(
+            &server,
+            "/job/demo/12/api/json",
+            ResponseTemplate::new(200).set_body_json(json!({
+                "number": 12,
+                "building": false,
+                "result": "SUCCESS",
+                "duration": 42
+            })),
+            1,
+        )
+        .await;
+
+        let client = build_async_client(server.uri())?;
+
+        let outcome = client
+            .jobs()
+            .trigger_and_wait(
+                "demo",
+                WaitConfig::new(Duration::ZERO, Duration::from_secs(5)),
+            )
+            .await?;
+        let info = outcome.build_info().expect("build should complete");
+        assert_eq!(info.number, Some(12));
+        assert_eq!(info.duration, Some(42));
+        assert_eq!(outcome.result(), Some(&BuildResult::Success));
+
+        server.verify().await;
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn async_jobs_wait_for_build_reports_cancelled_queue_item() -> Result<()> {
+        let server = MockServer::start().await;
+
+        mock_get(
+            &server,
+            "/queue/item/9/api/json",
+            ResponseTemplate::new(200).set_body_json(json!({ "id": 9, "cancelled": true })),
+            1,
+        )
+        .await;
+
+        let client = build_async_client(server.uri())?;
+
+        let outcome = client
+            .jobs()
+            .wait_for_build("demo", "9", WaitConfig::default())
+            .await?;
+        assert!(
+            matches!(outcome, BuildOutcome::Cancelled(item) if item.id.as_deref() == Some("9"))
+        );
+
+        server.verify().await;
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn async_jobs_wait_for_build_times_out() -> Result<()> {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/queue/item/3/api/json"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "id": 3 })))
+            .mount(&server)
+            .await;
+
+        let client = build_async_client(server.uri())?;
+
+        let err = client
+            .jobs()
+            .wait_for_build(
+                "demo",
+                "3",
+                WaitConfig::new(Duration::from_millis(10), Duration::from_millis(50)),
+            )
+            .await
+            .expect_err("queue item never starts");
+        assert!(matches!(err, Error::WaitTimeout { .. }));
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn async_jobs_info_uses_tree_query_param() -> Result<()> {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/json"))
+            .and(query_param("tree", "jobs[name,url,color]"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "jobs": []
+            })))
+            .expect(1)
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+
+        let client = build_async_client(server.uri())?;
+
+        let jobs = client.jobs().list().await?;
+        assert!(jobs.jobs.is_empty());
+
+        server.verify().await;
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn async_jobs_progressive_console_text_parses_headers() -> Result<()> {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/job/demo/1/logText/progressiveText"))
+            .and(query_param("start", "0"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .append_header("X-Text-Size", "5")
+                    .append_header("X-More-Data", "true")
+                    .set_body_string("hello"),
+            )
+            .expect(1)
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+
+        let client = build_async_client(server.uri())?;
+
+        let chunk = client
+            .jobs()
+            .progressive_console_text("demo", "1", 0)
+            .await?;
+        assert_eq!(chunk.text, "hello");
+        assert_eq!(chunk.next_start, Some(5));
+        assert!(chunk.more_data);
+
+        server.verify().await;
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn async_jobs_follow_console_lines_tracks_offsets_until_done() -> Result<()> {
+        let server = MockServer::start().await;
+
+        for (start, next, more, body) in [
+            ("0", "9", "true", "one\ntwo-"),
+            ("9", "9", "true", ""),
+            ("9", "17", "false", "half\nend"),
+        ] {
+            Mock::given(method("GET"))
+                .and(path("/job/demo/1/logText/progressiveText"))
+                .and(query_param("start", start))
+                .respond_with(
+                    ResponseTemplate::new(200)
+                        .append_header("X-Text-Size", next)
+                        .append_header("X-More-Data", more)
+                        .set_body_string(body),
+                )
+                .expect(1)
+                .up_to_n_times(1)
+                .mount(&server)
+                .await;
+        }
+
+        let client = build_async_client(server.uri())?;
+
+        let lines: Vec<String> = client
+            .jobs()
+            .follow_console_lines(
+                "demo",
+                "1",
+                ConsoleFollowConfig::new(Duration::from_millis(1), Duration::from_millis(5)),
+            )
+            .try_collect()
+            .await?;
+        assert_eq!(lines, vec!["one", "two-half", "end"]);
+
+        server.verify().await;
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn async_jobs_download_artifact_splits_path_segments() -> Result<()> {
+        let server = MockServer::start().await;
+
+        mock_get(
+            &server,
+            "/job/demo/1/artifact/a/b%20c.txt",
+            ResponseTemplate::new(200).set_body_bytes(vec![1, 2, 3]),
+            1,
+        )
+        .await;
+
+        let client = build_async_client(server.uri())?;
+
+        let bytes = client
+            .jobs()
+            .download_artifact("demo", "1", "a/b c.txt")
+            .await?;
+        assert_eq!(bytes, vec![1, 2, 3]);
+
+        server.verify().await;
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn async_jobs_download_artifact_to_writer_verifies_fingerprint() -> Result<()> {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/job/demo/7/api/json"))
+            .and(query_param("tree", "fingerprint[fileName,hash]"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "fingerprint": [
+                    { "fileName": "other.txt", "hash": "00000000000000000000000000000000" },
+                    { "fileName": "app.txt", "hash": HELLO_WORLD_MD5 }
+                ]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        mock_get(
+            &server,
+            "/job/demo/7/artifact/out/app.txt",
+            ResponseTemplate::new(200).set_body_string("hello world"),
+            1,
+        )
+        .await;
+
+        let client = build_async_client(server.uri())?;
+        let progress = Arc::new(AtomicU64::new(0));
+        let seen = progress.clone();
+        let config = DownloadConfig::new()
+            .verify_fingerprint()
+            .on_progress(move |p| seen.store(p.downloaded, Ordering::SeqCst));
+
+        let mut out = Vec::new();
+        let summary = client
+            .jobs()
+            .download_artifact_to_writer("demo", "7", "out/app.txt", &mut out, config)
+            .await?;
+
+        assert_eq!(out, b"hello world");
+        assert_eq!(summary.bytes_written, 11);
+        assert_eq!(summary.total_bytes, 11);
+        assert_eq!(summary.md5.as_deref(), Some(HELLO_WORLD_MD5));
+        assert_eq!(progress.load(Ordering::SeqCst), 11);
+
+        server.verify().await;
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn async_jobs_download_artifact_to_path_resumes_with_range() -> Result<()> {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/job/demo/7/artifact/app.txt"))
+            .and(header("Range", "bytes=6-"))
+            .respond_with(
+                ResponseTemplate::new(206)
+                    .insert_header("Content-Range", "bytes 6-10/11")
+                    .set_body_string("world"),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let dest = std::env::temp_dir().join(format!("jenkins-sdk-resume-{}", std::process::id()));
+        std::fs::write(&dest, "hello stale tail")?;
+
+        let client = build_async_client(server.uri())?;
+        let summary = client
+            .jobs()
+            .download_artifact_to_path(
+                "demo",
+                "7",
+                "app.txt",
+                &dest,
+                DownloadConfig::new()
+                    .resume_from(6)
+                    .expected_md5(HELLO_WORLD_MD5),
+            )
+            .await?;
+        let written = std::fs::read_to_string(&dest)?;
+        std::fs::remove_file(&dest)?;
+
+        assert_eq!(written, "hello world");
+        assert_eq!(summary.resumed_from, 6);
+        assert_eq!(summary.bytes_written, 5);
+        assert_eq!(summary.total_bytes, 11);
+
+        server.verify().await;
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn async_jobs_download_artifact_to_writer_refuses_unverifiable_resume() -> Result<()> {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/job/demo/7/artifact/app.txt"))
+            .and(header("Range", "bytes=6-"))
+            .respond_with(
+                ResponseTemplate::new(206)
+                    .insert_header("Content-Range", "bytes 6-10/11")
+                    .set_body_string("world"),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = build_async_client(server.uri())?;
+        let mut out = Vec::new();
+        let err = client
+            .jobs()
+            .download_artifact_to_writer(
+                "demo",
+                "7",
+                "app.txt",
+                &mut out,
+                DownloadConfig::new()
+                    .resume_from(6)
+                    .expected_md5(HELLO_WORLD_MD5),
+            )
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, Error::InvalidConfig { .. }), "{err:?}");
+        assert!(out.is_empty());
+
+        server.verify().await;
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn async_jobs_download_artifact_reports_checksum_mismatch() -> Result<()> {
+        let server = MockServer::start().await;
+
+        mock_get(
+            &server,
+            "/job/demo/7/artifact/app.txt",
+            ResponseTemplate::new(200).set_body_string("tampered"),
+            1,
+        )
+        .await;
+
+        let client = build_async_client(server.uri())?;
+        let mut out = Vec::new();
+        let err = client
+            .jobs()
+            .download_artifact_to_writer(
+                "demo",
+                "7",
+                "app.txt",
+                &mut out,
+                DownloadConfig::new().expected_md5(HELLO_WORLD_MD5),
+            )
+            .await
+            .unwrap_err();
+
+        match err {
+            Error::ChecksumMismatch { expected, .. } => assert_eq!(&*expected, HELLO_WORLD_MD5),
+            other => panic!("expected checksum mismatch, got {other:?}"),
+        }
+
+        server.verify().await;
+        Ok(())
+    }
+
+    fn test_report_body(b_status: &str) -> serde_json::Value {
+        json!({
+            "failCount": 1,
+            "passCount": 1,
+            "skipCount": 0,
+            "suites": [{
+                "name": "unit",
+                "cases": [
+                    { "className": "pkg.T", "name": "a", "status": "PASSED", "duration": 0.5 },
+                    {
+                        "className": "pkg.T",
+                        "name": "b",
+                        "status": b_status,
+                        "errorDetails": "expected 1 got 2",
+                        "stdout": "log line"
+                    }
+                ]
+            }]
+        })
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn async_jobs_test_report_diff_compares_two_builds() -> Result<()> {
+        let server = MockServer::start().await;
+
+        mock_get(
+            &server,
+            "/job/demo/1/testReport/api/json",
+            ResponseTemplate::new(200).set_body_json(test_report_body("PASSED")),
+            1,
+        )
+        .await;
+        mock_get(
+            &server,
+            "/job/demo/2/testReport/api/json",
+            ResponseTemplate::new(200).set_body_json(test_report_body("REGRESSION")),
+            1,
+        )
+        .await;
+
+        let client = build_async_client(server.uri())?;
+        let diff = client.jobs().test_report_diff("demo", "1", "2").await?;
+
+        assert_eq!(diff.newly_failing.len(), 1);
+        let failing = &diff.newly_failing[0];
+        assert_eq!(failing.full_name(), "pkg.T.b");
+        assert_eq!(failing.status, Some(TestStatus::Regression));
+        assert_eq!(failing.error_details.as_deref(), Some("expected 1 got 2"));
+        assert!(diff.fixed.is_empty());
+
+        server.verify().await;
+        Ok(())
+    }
+
+    fn artifacts_body() -> serde_json::Value {
+        json!({
+            "artifacts": [
+                { "fileName": "app.jar", "relativePath": "target/app.jar", "displayPath": "app.jar" },
+                { "fileName": "notes.txt", "relativePath": "notes.txt", "displayPath": "notes.txt" },
+                { "fileName": "lib.jar", "relativePath": "target/libs/lib.jar", "displayPath": "lib.jar" }
+            ]
+        })
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn async_jobs_list_artifacts_matching_filters_by_glob() -> Result<()> {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/job/demo/7/api/json"))
+            .and(query_param(
+                "tree",
+                "artifacts[fileName,relativePath,displayPath]",
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(artifacts_body()))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = build_async_client(server.uri())?;
+        let jars = client
+            .jobs()
+            .list_artifacts_matching("demo", "7", &["**/*.jar"])
+            .await?;
+
+        let paths: Vec<_> = jars.iter().map(|a| a.relative_path.as_str()).collect();
+        assert_eq!(paths, ["target/app.jar", "target/libs/lib.jar"]);
+        assert_eq!(jars[0].path().as_str(), "target/app.jar");
+
+        server.verify().await;
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn async_jobs_mirror_artifacts_writes_relative_paths() -> Result<()> {
+        let server = MockServer::start().await;
+
+        mock_get(
+            &server,
+            "/job/demo/7/api/json",
+            ResponseTemplate::new(200).set_body_json(artifacts_body()),
+            1,
+        )
+        .await;
+        mock_get(
+            &server,
+            "/job/demo/7/artifact/target/app.jar",
+            ResponseTemplate::new(200).set_body_string("app"),
+            1,
+        )
+        .await;
+        mock_get(
+            &server,
+            "/job/demo/7/artifact/target/libs/lib.jar",
+            ResponseTemplate::new(200).set_body_string("lib"),
+            1,
+        )
+        .await;
+
+        let dest = std::env::temp_dir().join(format!("jenkins-sdk-mirror-{}", std::process::id()));
+        let client = build_async_client(server.uri())?;
+        let mirrored = client
+            .jobs()
+            .mirror_artifacts("demo", "7", &dest, &["target/**"], DownloadConfig::new())
+            .await?;
+
+        let app = std::fs::read_to_string(dest.join("target/app.jar"))?;
+        let lib = std::fs::read_to_string(dest.join("target/libs/lib.jar"))?;
+        std::fs::remove_dir_all(&dest)?;
+
+        assert_eq!(mirrored.len(), 2);
+        assert_eq!(app, "app");
+        assert_eq!(lib, "lib");
+
+        server.verify().await;
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn async_jobs_mirror_artifacts_verifies_same_named_files() -> Result<()> {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/job/demo/8/api/json"))
+            .and(query_param(
+                "tree",
+                "artifacts[fileName,relativePath,displayPath]",
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "artifacts": [
+                    { "fileName": "app.tar.gz", "relativePath": "dist/linux/app.tar.gz" },
+                    { "fileName": "app.tar.gz", "relativePath": "dist/mac/app.tar.gz" }
+                ]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/job/demo/8/api/json"))
+            .and(query_param("tree", "fingerprint[fileName,hash]"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "fingerprint": [
+                    { "fileName": "dist/linux/app.tar.gz", "hash": "e206a54e97690cce50cc872dd70ee896" },
+                    { "fileName": "dist/mac/app.tar.gz", "hash": "140c1f12feeb2c52dfbeb2da6066a73a" }
+                ]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        for os in ["linux", "mac"] {
+            mock_get(
+                &server,
+                &format!("/job/demo/8/artifact/dist/{os}/app.tar.gz"),
+                ResponseTemplate::new(200).set_body_string(os),
+                1,
+            )
+            .await;
+        }
+
+        let dest = std::env::temp_dir().join(format!(
+            "jenkins-sdk-mirror-fingerprints-{}",
+            std::process::id()
+        ));
+        let client = build_async_client(server.uri())?;
+        let mirrored = client
+            .jobs()
+            .mirror_artifacts(
+                "demo",
+                "8",
+                &dest,
+                &[] as &[&str],
+                DownloadConfig::new().verify_fingerprint(),
+            )
+            .await?;
+
+        let linux = std::fs::read_to_string(dest.join("dist/linux/app.tar.gz"))?;
+        let mac = std::fs::read_to_string(dest.join("dist/mac/app.tar.gz"))?;
+        std::fs::remove_dir_all(&dest)?;
+
+        assert_eq!(mirrored.len(), 2);
+        assert_eq!((linux.as_str(), mac.as_str()), ("linux", "mac"));
+
+        server.verify().await;
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn async_validated_trigger_rejects_typos_and_bad_choices() -> Result<()> {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/job/deploy/api/json"))
+            .and(query_param(
+                "tree",
+                "property[parameterDefinitions[name,type,description,choices,defaultParameterValue[name,value]]]",
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "property": [{
+                    "_class": "hudson.model.ParametersDefinitionProperty",
+                    "parameterDefinitions": [
+                        {
+                            "name": "TARGET",
+                            "type": "ChoiceParameterDefinition",
+                            "choices": ["staging", "prod"],
+                            "defaultParameterValue": { "name": "TARGET", "value": "staging" }
+                        },
+                        {
+                            "name": "VERSION",
+                            "type": "StringParameterDefinition",
+                            "defaultParameterValue": { "name": "VERSION", "value": "" }
+                        }
+                    ]
+                }]
+            })))
+            .expect(3)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/job/deploy/buildWithParameters"))
+            .and(body_string_contains("TARGET=prod"))
+            .respond_with(ResponseTemplate::new(201).append_header("Location", "/queue/item/4/"))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = build_async_client(server.uri())?;
+        let definitions = client.jobs().parameter_definitions("deploy").await?;
+        assert_eq!(definitions[0].parameter_type, Some(ParameterType::Choice));
+        assert_eq!(definitions[0].choices, ["staging", "prod"]);
+
+        let err = client
+            .jobs()
+            .build_with_validated_parameters(
+                "deploy",
+                [("TARGET", "production"), ("VERISON", "1.2")],
+            )
+            .await
+            .expect_err("invalid parameters");
+        assert!(matches!(err, Error::InvalidConfig { .. }));
+        let message = err.to_string();
+        assert!(message.contains("`production` is not one of the choices"));
+        assert!(message.contains("unknown parameter `VERISON`"));
+
+        let triggered = client
+            .jobs()
+            .build_with_validated_parameters("deploy", [("TARGET", "prod"), ("VERSION", "1.2")])
+            .await?;
+        assert_eq!(
+            triggered.queue_item_id.as_ref().map(|id| id.as_str()),
+            Some("4")
+        );
+
+        server.verify().await;
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn async_build_with_files_sends_multipart_form() -> Result<()> {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/job/release/buildWithParameters"))
+            .and(header_regex(
+                "content-type",
+                "^multipart/form-data; boundary=jenkins-sdk-",
+            ))
+            .respond_with(ResponseTemplate::new(201).append_header("Location", "/queue/item/5/"))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let bundle =
+            std::env::temp_dir().join(format!("jenkins-sdk-bundle-{}.sig", std::process::id()));
+        tokio::fs::write(&bundle, b"signature").await?;
+
+        let client = build_async_client(server.uri())?;
+        let triggered = client
+            .jobs()
+            .build_with_files(
+                "release",
+                [("VERSION", "1.2.3")],
+                [
+                    FileParameter::bytes("BUNDLE", "bundle.tar", b"tarball".to_vec()),
+                    FileParameter::path("SIGNATURE", &bundle),
+                ],
+            )
+            .await?;
+        tokio::fs::remove_file(&bundle).await?;
+        assert_eq!(
+            triggered.queue_item_id.as_ref().map(|id| id.as_str()),
+            Some("5")
+        );
+
+        let requests = server.received_requests().await.unwrap_or_default();
+        let body = String::from_utf8(requests[0].body.clone())?;
+        assert!(body.contains("Content-Disposition: form-data; name=\"VERSION\"\r\n\r\n1.2.3\r\n"));
+        assert!(body.contains("name=\"BUNDLE\"; filename=\"bundle.tar\""));
+        assert!(body.contains("\r\n\r\ntarball\r\n"));
+        let file_name = bundle
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or_default();
+        assert!(body.contains(&format!("name=\"SIGNATURE\"; filename=\"{file_name}\"")));
+        assert!(body.contains("\r\n\r\nsignature\r\n"));
+
+        let missing = client
+            .jobs()
+            .build_with_files(
+                "release",
+                Vec::<(String, String)>::new(),
+                [FileParameter::path("SIGNATURE", "/nonexistent/bundle.sig")],
+            )
+            .await
+            .expect_err("missing file");
+        assert!(matches!(missing, Error::Io { .. }));
+
+        server.verify().await;
+        Ok(())
+    }
+
+    const BUILDS_TREE: &str = "number,url,result,building,displayName,fullDisplayName,timestamp,duration,actions[parameters[name,value]]";
+
+    fn build_entry(number: u64, result: &str, target: &str) -> serde_json::Value {
+        json!({
+            "number": number,
+            "result": result,
+            "timestamp": 1_700_000_000_000u64 + number * 60_000,
+            "actions": [{ "parameters": [{ "name": "TARGET", "value": target }] }]
+        })
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn async_builds_pages_and_filters_history() -> Result<()> {
+        let server = MockServer::start().await;
+
+        for (range, builds) in [
+            (
+                "{0,2}",
+                json!([
+                    build_entry(5, "FAILURE", "prod"),
+                    build_entry(4, "SUCCESS", "prod")
+                ]),
+            ),
+            (
+                "{2,4}",
+                json!([
+                    build_entry(3, "FAILURE", "staging"),
+                    build_entry(2, "FAILURE", "prod")
+                ]),
+            ),
+        ] {
+            Mock::given(method("GET"))
+                .and(path("/job/app/api/json"))
+                .and(query_param(
+                    "tree",
+                    format!("allBuilds[{BUILDS_TREE}]{range}"),
+                ))
+                .respond_with(
+                    ResponseTemplate::new(200).set_body_json(json!({ "allBuilds": builds })),
+                )
+                .expect(1)
+                .mount(&server)
+                .await;
+        }
+
+        let client = build_async_client(server.uri())?;
+        let query = BuildQuery::new()
+            .page_size(2)
+            .limit(2)
+            .result(BuildResult::Failure)
+            .parameter("TARGET", "prod");
+        let builds: Vec<_> = client.jobs().builds("app", query).try_collect().await?;
+
+        let numbers: Vec<_> = builds.iter().filter_map(|b| b.number).collect();
+        assert_eq!(numbers, [5, 2]);
+        assert_eq!(
+            builds[1].parameters()[0].form_value().as_deref(),
+            Some("prod")
+        );
+
+        server.verify().await;
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn async_walk_jobs_descends_folders_and_multibranch() -> Result<()> {
+        let server = MockServer::start().await;
+
+        let two_levels = "jobs[name,url,color,jobs[name,url,color]]";
+        Mock::given(method("GET"))
+            .and(path("/api/json"))
+            .and(query_param("tree", two_levels))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "jobs": [
+                    { "_class": "hudson.model.FreeStyleProject", "name": "legacy", "color": "blue" },
+                    {
+                        "_class": "com.cloudbees.hudson.plugins.folder.Folder",
+                        "name": "archive",
+                        "jobs": [{ "_class": "hudson.model.FreeStyleProject", "name": "old" }]
+                    },
+                    {
+                        "_class": "com.cloudbees.hudson.plugins.folder.Folder",
+                        "name": "team",
+                        "jobs": [
+                            {
+                                "_class": "org.jenkinsci.plugins.workflow.multibranch.WorkflowMultiBranchProject",
+                                "name": "svc"
+                            },
+                            { "_class": "hudson.model.FreeStyleProject", "name": "job1", "color": "red" }
+                        ]
+                    }
+                ]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/job/team/job/svc/api/json"))
+            .and(query_param("tree", two_levels))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "jobs": [
+                    { "_class": "org.jenkinsci.plugins.workflow.job.WorkflowJob", "name": "main", "color": "blue" },
+                    { "_class": "org.jenkinsci.plugins.workflow.job.WorkflowJob", "name": "PR-1", "color": "red_anime" }
+                ]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = build_async_client(server.uri())?;
+        let config = JobWalkConfig::new()
+            .levels_per_request(2)
+            .include("team/**")
+            .exclude("archive");
+        let jobs: Vec<_> = client.jobs().walk_jobs(config).try_collect().await?;
+
+        let paths: Vec<_> = jobs.iter().map(|(path, _)| path.as_str()).collect();
+        assert_eq!(
+            paths,
+            ["team/svc", "team/job1", "team/svc/main", "team/svc/PR-1"]
+        );
+        assert_eq!(jobs[0].1.kind(), JobKind::Multibranch);
+        assert_eq!(jobs[2].1.kind(), JobKind::Pipeline);
+        assert!(jobs[3].1.is_building());
+
+        server.verify().await;
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn async_folders_create_copy_and_move() -> Result<()> {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/job/team/createItem"))
+            .and(query_param("name", "svc"))
+            .and(header("content-type", "application/xml"))
+            .and(body_string_contains(
+                "<com.cloudbees.hudson.plugins.folder.Folder>",
+            ))
+            .and(body_string_contains(
+                "<description>Tools &amp; services</description>",
+            ))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/job/team/job/svc/createItem"))
+            .and(query_param("name", "app"))
+            .and(body_string_contains("<flow-definition/>"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/job/team/createItem"))
+            .and(query_param("name", "app-copy"))
+            .and(query_param("mode", "copy"))
+            .and(query_param("from", "/templates/app"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/job/team/job/svc/job/app/move/move"))
+            .and(body_string_contains("destination=%2Farchive%2F2024"))
+            .respond_with(ResponseTemplate::new(302))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = build_async_client(server.uri())?;
+        let jobs = client.jobs();
+        jobs.create_folder("team", "svc", Some("Tools & services"))
+            .await?;
+        jobs.create_from_xml("team/svc/app", "<flow-definition/>")
+            .await?;
+        jobs.copy("templates/app", "team/app-copy").await?;
+        let moved = jobs.move_job("team/svc/app", "archive/2024").await?;
+        assert_eq!(moved.as_str(), "archive/2024/app");
+
+        server.verify().await;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "async")]
+mod async_multibranch {
+    use std::time::Duration;
+
+    use anyhow::Result;
+    use jenkins_sdk::{BranchKind, BranchState, BuildResult, WaitConfig};
+    use serde_json::json;
+    use wiremock::{
+        Mock, MockServer, ResponseTemplate,
+        matchers::{method, path, query_param},
+    };
+
+    use crate::fixture::{build_async_authed_client, build_async_client, mock_crumb};
+
+    const BRANCHES_TREE: &str = "disabled,jobs[name,displayName,url,color,buildable,lastBuild[number,url,result,building,timestamp,duration]],views[name,jobs[name]]";
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn async_multibranch_lists_branches_and_pull_requests() -> Result<()> {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/job/org/job/app/api/json"))
+            .and(query_param("tree", BRANCHES_TREE))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "jobs": [
+                    {
+                        "name": "main",
+                        "color": "blue",
+                        "buildable": true,
+                        "lastBuild": { "number": 7, "result": "SUCCESS", "building": false }
+                    },
+                    {
+                        "name": "PR-42",
+                        "displayName": "PR-42",
+                        "color": "red_anime",
+                        "buildable": true,
+                        "lastBuild": { "number": 3, "result": null, "building": true }
+                    },
+                    { "name": "v1.0", "color": "notbuilt", "buildable": true }
+                ],
+                "views": [
+                    { "name": "change-requests", "jobs": [{ "name": "PR-42" }] },
+                    { "name": "default", "jobs": [{ "name": "main" }] },
+                    { "name": "tags", "jobs": [{ "name": "v1.0" }] }
+                ]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = build_async_client(server.uri())?;
+        let branches = client.multibranch().branches("org/app").await?;
+
+        let kinds: Vec<_> = branches.iter().map(|b| b.kind.clone()).collect();
+        assert_eq!(
+            kinds,
+            [BranchKind::Branch, BranchKind::PullRequest, BranchKind::Tag]
+        );
+        assert_eq!(branches[0].last_result(), Some(&BuildResult::Success));
+        assert!(branches[1].is_pull_request());
+        assert!(branches[1].is_building());
+        assert_eq!(branches[1].last_result(), None);
+        assert!(branches.iter().all(|b| b.state() == BranchState::Active));
+
+        server.verify().await;
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn async_multibranch_scan_then_wait_for_branch() -> Result<()> {
+        let server = MockServer::start().await;
+
+        mock_crumb(&server, "crumb-mb", 1).await;
+        Mock::given(method("POST"))
+            .and(path("/job/app/build"))
+            .and(query_param("delay", "0"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/job/app/api/json"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "jobs": [{ "name": "main", "color": "blue" }],
+                "views": []
+            })))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/job/app/api/json"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "jobs": [
+                    { "name": "main", "color": "blue" },
+                    { "name": "feature%2Flogin", "displayName": "feature/login", "color": "notbuilt" }
+                ],
+                "views": []
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = build_async_authed_client(server.uri(), Duration::from_secs(60))?;
+        let multibranch = client.multibranch();
+        multibranch.scan("app").await?;
+        let branch = multibranch
+            .wait_for_branch(
+                "app",
+                "feature/login",
+                WaitConfig::new(Duration::ZERO, Duration::from_secs(5)),
+            )
+            .await?;
+
+        assert_eq!(branch.name, "feature%2Flogin");
+        assert_eq!(branch.kind, BranchKind::Branch);
+
+        server.verify().await;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "async")]
+mod async_pipeline {
+    use std::time::Duration;
+
+    use anyhow::Result;
+    use jenkins_sdk::StageStatus;
+    use serde_json::json;
+    use wiremock::{
+        Mock, MockServer, ResponseTemplate,
+        matchers::{method, path},
+    };
+
+    use crate::fixture::{
+        build_async_authed_client, build_async_client, mock_crumb, mock_get, mock_post_with_auth,
+    };
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn async_pipeline_describe_reports_failed_stage() -> Result<()> {
+        let server = MockServer::start().await;
+
+        mock_get(
+            &server,
+            "/job/folder/job/app/12/wfapi/describe",
+            ResponseTemplate::new(200).set_body_json(json!({
+                "id": "12",
+                "name": "#12",
+                "status": "FAILED",
+                "durationMillis": 420000,
+                "stages": [
+                    { "id": "6", "name": "Build", "status": "SUCCESS", "durationMillis": 60000 },
+                    {
+                        "id": "15",
+                        "name": "Deploy",
+                        "status": "FAILED",
+                        "durationMillis": 180000,
+                        "error": { "message": "script returned exit code 1", "type": "hudson.AbortException" }
+                    }
+                ]
+            })),
+            1,
+        )
+        .await;
+
+        let client = build_async_client(server.uri())?;
+        let run = client.pipeline().describe("folder/app", "12").await?;
+
+        assert_eq!(run.status, Some(StageStatus::Failed));
+        let failed = run.failed_stage().expect("failed stage");
+        assert_eq!(failed.name.as_deref(), Some("Deploy"));
+        assert_eq!(failed.duration(), Some(Duration::from_secs(180)));
+        assert_eq!(
+            failed.error.as_ref().and_then(|e| e.message.as_deref()),
+            Some("script returned exit code 1")
+        );
+
+        server.verify().await;
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn async_pipeline_describe_node_and_log() -> Result<()> {
+        let server = MockServer::start().await;
+
+        mock_get(
+            &server,
+            "/job/app/12/execution/node/15/wfapi/describe",
+            ResponseTemplate::new(200).set_body_json(json!({
+                "id": "15",
+                "name": "Deploy",
+                "status": "FAILED",
+                "stageFlowNodes": [{
+                    "id": "17",
+                    "name": "Shell Script",
+                    "status": "FAILED",
+                    "parameterDescription": "./deploy.sh",
+                    "parentNodes": ["15"]
+                }]
+            })),
+            1,
+        )
+        .await;
+        mock_get(
+            &server,
+            "/job/app/12/execution/node/17/wfapi/log",
+            ResponseTemplate::new(200).set_body_json(json!({
+                "nodeId": "17",
+                "nodeStatus": "FAILED",
+                "length": 12,
+                "hasMore": false,
+                "text": "deploy fail\n"
+            })),
+            1,
+        )
+        .await;
+
+        let client = build_async_client(server.uri())?;
+        let stage = client.pipeline().describe_node("app", "12", "15").await?;
+        let step = &stage.stage_flow_nodes[0];
+        assert_eq!(step.parameter_description.as_deref(), Some("./deploy.sh"));
+
+        let step_id = step.id.clone().expect("step id");
+        let log = client.pipeline().node_log("app", "12", step_id).await?;
+        assert_eq!(log.text, "deploy fail\n");
+        assert_eq!(log.node_status, Some(StageStatus::Failed));
+        assert!(!log.has_more);
+
+        server.verify().await;
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn async_pipeline_pending_input_submit_and_abort_with_crumb() -> Result<()> {
+        let server = MockServer::start().await;
+
+        mock_crumb(&server, "crumb-1", 1).await;
+        mock_get(
+            &server,
+            "/job/app/13/wfapi/pendingInputActions",
+            ResponseTemplate::new(200).set_body_json(json!([{
+                "id": "Deploy",
+                "proceedText": "Ship it",
+                "message": "Deploy to production?",
+                "inputs": [{
+                    "type": "StringParameterDefinition",
+                    "name": "TARGET",
+                    "description": "cluster"
+                }],
+                "proceedUrl": "/job/app/13/wfapi/inputSubmit?inputId=Deploy",
+                "abortUrl": "/job/app/13/input/Deploy/abort"
+            }])),
+            1,
+        )
+        .await;
+        mock_post_with_auth(
+            &server,
+            "/job/app/13/input/Deploy/submit",
+            Some("crumb-1"),
+            Some("proceed=Proceed"),
+            ResponseTemplate::new(302),
+            1,
+        )
+        .await;
+        mock_post_with_auth(
+            &server,
+            "/job/app/13/input/Deploy/abort",
+            Some("crumb-1"),
+            None,
+            ResponseTemplate::new(200),
+            1,
+        )
+        .await;
+
+        let client = build_async_authed_client(server.uri(), Duration::from_secs(300))?;
+        let pending = client.pipeline().pending_inputs("app", "13").await?;
+        assert_eq!(pending.len(), 1);
+        let input = &pending[0];
+        assert_eq!(input.message.as_deref(), Some("Deploy to production?"));
+        assert_eq!(input.inputs[0].name, "TARGET");
+        assert_eq!(
+            input.inputs[0].parameter_type.as_deref(),
+            Some("StringParameterDefinition")
+        );
+
+        client
+            .pipeline()
+            .proceed_input("app", "13", input.id.clone(), [("TARGET", "prod")])
+            .await?;
+        client
+            .pipeline()
+            .abort_input("app", "13", input.id.clone())
+            .await?;
+
+        let requests = server.received_requests().await.unwrap_or_default();
+        let submit = requests
+            .iter()
+            .find(|r| r.url.path().ends_with("/submit"))
+            .expect("submit request");
+        let form: Vec<(String, String)> = url::form_urlencoded::parse(&submit.body)
+            .into_owned()
+            .collect();
+        let payload = form
+            .iter()
+            .find(|(k, _)| k == "json")
+            .map(|(_, v)| serde_json::from_str::<serde_json::Value>(v))
+            .expect("json field")?;
+        assert_eq!(
+            payload,
+            json!({ "parameter": [{ "name": "TARGET", "value": "prod" }] })
+        );
+
+        server.verify().await;
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn async_pipeline_replay_build_resubmits_edited_scripts() -> Result<()> {
+        let server = MockServer::start().await;
+
+        mock_get(
+            &server,
+            "/job/app/12/replay/",
+            ResponseTemplate::new(200).set_body_string(
+                r#"<form action="run" method="post">
+<textarea name="_.mainScript" class="workflow-editor">
+node { sh &quot;./build.sh &amp;&amp; ./test.sh&quot; }</textarea>
+<textarea name="_.Script1">def deploy() { echo &#39;deploy&#39; }</textarea>
+</form>"#,
+            ),
+            2,
+        )
+        .await;
+        Mock::given(method("POST"))
+            .and(path("/job/app/12/replay/run"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = build_async_client(server.uri())?;
+        let scripts = client.pipeline().replay_scripts("app", "12").await?;
+        assert_eq!(
+            scripts.main_script,
+            r#"node { sh "./build.sh && ./test.sh" }"#
+        );
+        assert_eq!(
+            scripts.loaded_scripts.get("Script1").map(String::as_str),
+            Some("def deploy() { echo 'deploy' }")
+        );
+
+        client
+            .pipeline()
+            .replay_build("app", "12", |scripts| {
+                scripts.main_script = scripts.main_script.replace("./test.sh", "./test.sh -v");
+            })
+            .await?;
+
+        let requests = server.received_requests().await.unwrap_or_default();
+        let run = requests
+            .iter()
+            .find(|r| r.url.path().ends_with("/replay/run"))
+            .expect("replay run request");
+        let payload = url::form_urlencoded::parse(&run.body)
+            .find(|(k, _)| k == "json")
+            .map(|(_, v)| serde_json::from_str::<serde_json::Value>(&v))
+            .expect("json field")?;
+        assert_eq!(
+            payload,
+            json!({
+                "mainScript": "node { sh \"./build.sh && ./test.sh -v\" }",
+                "Script1": "def deploy() { echo 'deploy' }"
+            })
+        );
+
+        server.verify().await;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "async")]
+mod async_plugins {
+    use std::time::Duration;
+
+    use anyhow::Result;
+    use jenkins_sdk::{
+        PluginAction, PluginCatalog, PluginInstallStatus, PluginManifest, PluginPlan, WaitConfig,
+    };
+    use serde_json::json;
+    use wiremock::{
+        Mock, MockServer, ResponseTemplate,
+        matchers::{body_string, header, method, path},
+    };
+
+    use crate::fixture::{build_async_client, mock_get};
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn async_plugins_list_typed_with_dependencies() -> Result<()> {
+        let server = MockServer::start().await;
+        mock_get(
+            &server,
+            "/pluginManager/api/json",
+            ResponseTemplate::new(200).set_body_json(json!({
+                "plugins": [{
+                    "shortName": "git",
+                    "longName": "Git plugin",
+                    "version": "5.2.0",
+                    "enabled": true,
+                    "active": true,
+                    "hasUpdate": true,
+                    "dependencies": [
+                        {"shortName": "scm-api", "version": "676.v886669a_199a_a_", "optional": false},
+                        {"shortName": "credentials-binding", "version": "1.27", "optional": true}
+                    ]
+                }]
+            })),
+            2,
+        )
+        .await;
+
+        let client = build_async_client(server.uri())?;
+        let git = client
+            .plugins()
+            .get("git")
+            .await?
+            .expect("git is installed");
+        assert!(git.is_active() && git.has_update());
+        let required: Vec<_> = git
+            .required_dependencies()
+            .map(|dep| dep.short_name.as_str())
+            .collect();
+        assert_eq!(required, ["scm-api"]);
+        assert!(
+            client
+                .plugins()
+                .get("docker")
+                .await
+                .is_ok_and(|p| p.is_none())
+        );
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn async_plugins_install_and_wait_for_update_center() -> Result<()> {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/pluginManager/installNecessaryPlugins"))
+            .and(header("Content-Type", "text/xml"))
+            .and(body_string(
+                "<jenkins>\n  <install plugin=\"git@latest\"/>\n  <install plugin=\"job-dsl@1.87\"/>\n</jenkins>\n",
+            ))
+            .respond_with(ResponseTemplate::new(302))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let job = |status: &str| json!({"id": 3, "type": "InstallationJob", "name": "git", "status": {"type": status}});
+        Mock::given(method("GET"))
+            .and(path("/updateCenter/api/json"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(json!({"jobs": [job("Installing")]})),
+            )
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        mock_get(
+            &server,
+            "/updateCenter/api/json",
+            ResponseTemplate::new(200).set_body_json(json!({
+                "jobs": [job("SuccessButRequiresRestart")],
+                "restartRequiredForCompletion": false
+            })),
+            1,
+        )
+        .await;
+
+        let client = build_async_client(server.uri())?;
+        client.plugins().install(["git", "job-dsl@1.87"]).await?;
+        let status = client
+            .plugins()
+            .wait_for_installations(WaitConfig::new(
+                Duration::from_millis(1),
+                Duration::from_secs(5),
+            ))
+            .await?;
+        assert_eq!(
+            status.jobs[0].install_status(),
+            Some(PluginInstallStatus::SuccessButRequiresRestart)
+        );
+        assert!(status.restart_required());
+        assert_eq!(status.failed_installations().count(), 0);
+
+        server.verify().await;
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn async_plugins_plan_and_apply_manifest() -> Result<()> {
+        let server = MockServer::start().await;
+        mock_get(
+            &server,
+            "/pluginManager/api/json",
+            ResponseTemplate::new(200).set_body_json(json!({"plugins": [
+                {"shortName": "scm-api", "version": "690.v1"},
+                {"shortName": "ant", "version": "1.0"}
+            ]})),
+            1,
+        )
+        .await;
+        Mock::given(method("POST"))
+            .and(path("/pluginManager/installNecessaryPlugins"))
+            .and(body_string(
+                "<jenkins>\n  <install plugin=\"git@5.2.0\"/>\n</jenkins>\n",
+            ))
+            .respond_with(ResponseTemplate::new(302))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/pluginManager/plugin/ant/doUninstall"))
+            .respond_with(ResponseTemplate::new(302))
+            .expect(1)
+            .mount(&server)
+            .await;
+        mock_get(
+            &server,
+            "/updateCenter/api/json",
+            ResponseTemplate::new(200).set_body_json(json!({"jobs": []})),
+            1,
+        )
+        .await;
+
+        let catalog = PluginCatalog::from_update_center_json(
+            &json!({"plugins": {
+                "git": {"name": "git", "version": "5.2.0",
+                        "dependencies": [{"name": "scm-api", "version": "600", "optional": false}]},
+                "scm-api": {"name": "scm-api", "version": "690.v1"}
+            }})
+            .to_string(),
+        )?;
+        let manifest = PluginManifest::from_plugins_txt("git:5.2.0\n")?;
+        let client = build_async_client(server.uri())?;
+        let plan = client.plugins().plan(&manifest, &catalog).await?;
+        assert_eq!(plan.to_string(), "install git 5.2.0\nremove ant 1.0\n");
+
+        let wait = WaitConfig::new(Duration::from_millis(1), Duration::from_secs(5));
+        client
+            .plugins()
+            .apply(&plan, &catalog, wait.clone())
+            .await?;
+
+        // The update center would deploy 5.2.0 for `git@5.1.0`, so older pins are refused.
+        let mut stale_pin = PluginPlan::default();
+        stale_pin.actions.push(PluginAction::Install {
+            name: "git".to_owned(),
+            version: "5.1.0".to_owned(),
+        });
+        let err = client
+            .plugins()
+            .apply(&stale_pin, &catalog, wait.clone())
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("would install 5.2.0"), "{err}");
+
+        let mut downgrade = PluginPlan::default();
+        downgrade.actions.push(PluginAction::Downgrade {
+            name: "git".to_owned(),
+            from: "5.2.0".to_owned(),
+            to: "5.0.0".to_owned(),
+        });
+        assert!(
+            client
+                .plugins()
+                .apply(&downgrade, &catalog, wait)
+                .await
+                .is_err()
+        );
+
+        server.verify().await;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "async")]
+mod async_system {
+    use anyhow::Result;
+    use http::StatusCode;
+    use jenkins_sdk::{Error, GroovyScript};
+    use wiremock::{
+        Mock, MockServer, ResponseTemplate,
+        matchers::{body_string_contains, method, path},
+    };
+
+    use crate::fixture::{build_async_client, mock_get};
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn async_system_downloads_jnlp_jars() -> Result<()> {
+        let server = MockServer::start().await;
+
+        mock_get(
+            &server,
+            "/jnlpJars/agent.jar",
+            ResponseTemplate::new(200).set_body_bytes(vec![1]),
+            1,
+        )
+        .await;
+        mock_get(
+            &server,
+            "/jnlpJars/jenkins-cli.jar",
+            ResponseTemplate::new(200).set_body_bytes(vec![2]),
+            1,
+        )
+        .await;
+
+        let client = build_async_client(server.uri())?;
+
+        assert_eq!(client.system().agent_jar().await?, vec![1]);
+        assert_eq!(client.system().cli_jar().await?, vec![2]);
+
+        server.verify().await;
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn async_run_script_binds_arguments_and_maps_forbidden() -> Result<()> {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/scriptText"))
+            .and(body_string_contains(
+                "script=def+name+%3D+%27it%5C%27s%27%0Aprintln%28name%29",
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_string("it's\n"))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/computer/agent-1/scriptText"))
+            .respond_with(
+                ResponseTemplate::new(403)
+                    .set_body_string("anonymous is missing the Overall/RunScripts permission"),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = build_async_client(server.uri())?;
+        let script = GroovyScript::new("println(name)").bind("name", "it's")?;
+        assert_eq!(client.system().run_script(script).await?, "it's\n");
+
+        let err = client
+            .computers()
+            .run_script("agent-1", "println(1)")
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(&err, Error::MissingPermission { permission, .. } if &**permission == "Overall/RunScripts")
+        );
+        assert_eq!(err.status(), Some(StatusCode::FORBIDDEN));
+        assert!(err.is_auth_error());
+
+        server.verify().await;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "async")]
+mod async_users {
+    use anyhow::Result;
+    use wiremock::{
+        Mock, MockServer, ResponseTemplate,
+        matchers::{body_string_contains, header, method, path},
+    };
+
+    use crate::fixture::{build_async_client, mock_get};
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn async_users_config_xml_supports_get_and_update() -> Result<()> {
+        let server = MockServer::start().await;
+
+        mock_get(
+            &server,
+            "/user/alice/config.xml",
+            ResponseTemplate::new(200).set_body_string("<u/>"),
+            1,
+        )
+        .await;
+
+        Mock::given(method("POST"))
+            .and(path("/user/alice/config.xml"))
+            .and(header("Content-Type", "application/xml"))
+            .and(body_string_contains("<u/>"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+
+        let client = build_async_client(server.uri())?;
+
+        let xml = client.users().get_config_xml("alice").await?;
+        assert_eq!(String::from_utf8_lossy(&xml), "<u/>");
+
+        client.users().update_config_xml("alice", xml).await?;
+
+        server.verify().await;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "blocking")]
+mod blocking_core {
+    use std::time::Duration;
+
+    use anyhow::Result;
+    use http::StatusCode;
+    use jenkins_sdk::{BlockingClient, CassetteConfig, RetryConfig};
+    use serde_json::json;
+    use wiremock::{
+        Mock, MockServer, ResponseTemplate,
+        matchers::{method, path},
+    };
+
+    use crate::fixture::{
+        TEST_TOKEN, TEST_USER, build_blocking_authed_client, mock_crumb, mock_post_with_auth,
+        run_blocking,
+    };
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn blocking_client_reuses_crumb_with_retry() -> Result<()> {
+        let server = MockServer::start().await;
+
+        mock_crumb(&server, "token", 1).await;
+
+        mock_post_with_auth(
+            &server,
+            "/job/demo/1/stop",
+            Some("token"),
+            None,
+            ResponseTemplate::new(503),
+            1,
+        )
+        .await;
+
+        mock_post_with_auth(
+            &server,
+            "/job/demo/1/stop",
+            Some("token"),
+            None,
+            ResponseTemplate::new(200).set_body_string("stopped"),
+            1,
+        )
+        .await;
+
+        let base_url = server.uri();
+        run_blocking(move || -> Result<()> {
+            let client = BlockingClient::builder(base_url)?
+                .no_system_proxy()
                 .auth_basic(TEST_USER, TEST_TOKEN)
                 .retry_config(RetryConfig {
                     max_retries: 3,
@@ -683,7 +2667,401 @@ mod blocking_core {
                 .with_crumb(Duration::from_secs(300))
                 .build()?;
 
-            client.jobs().stop_build("demo", "1")?;
+            client.jobs().stop_build("demo", "1")?;
+            Ok(())
+        })
+        .await?;
+
+        server.verify().await;
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn blocking_client_supports_base_path_with_crumb() -> Result<()> {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/jenkins/crumbIssuer/api/json"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "crumbRequestField": "Jenkins-Crumb",
+                "crumb": "token"
+            })))
+            .expect(1)
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+
+        mock_post_with_auth(
+            &server,
+            "/jenkins/job/demo/1/stop",
+            Some("token"),
+            None,
+            ResponseTemplate::new(200).set_body_string("stopped"),
+            1,
+        )
+        .await;
+
+        let base_url = format!("{}/jenkins", server.uri());
+        run_blocking(move || -> Result<()> {
+            let client = build_blocking_authed_client(base_url, Duration::from_secs(300))?;
+            client.jobs().stop_build("demo", "1")?;
+            Ok(())
+        })
+        .await?;
+
+        server.verify().await;
+        Ok(())
+    }
+
+    /// Answers like Jenkins would and records `<method> <path> crumb=<..> hook=<..>`.
+    #[derive(Default)]
+    struct ScriptedTransport {
+        seen: std::sync::Mutex<Vec<String>>,
+    }
+
+    impl jenkins_sdk::BlockingTransport for ScriptedTransport {
+        fn send(
+            &self,
+            req: jenkins_sdk::TransportRequest,
+        ) -> std::result::Result<jenkins_sdk::TransportResponse, jenkins_sdk::Error> {
+            let header = |name: &str| {
+                req.headers
+                    .get(name)
+                    .and_then(|value| value.to_str().ok())
+                    .unwrap_or("-")
+                    .to_owned()
+            };
+            let mut seen = self.seen.lock().unwrap();
+            seen.push(format!(
+                "{} {} crumb={} hook={}",
+                req.method,
+                req.url.path(),
+                header("Jenkins-Crumb"),
+                header("X-Hook")
+            ));
+            let attempt = seen.len();
+
+            let mut headers = http::HeaderMap::new();
+            let (status, body) = match req.url.path() {
+                "/crumbIssuer/api/json" => (
+                    StatusCode::OK,
+                    json!({ "crumbRequestField": "Jenkins-Crumb", "crumb": "c-1" }).to_string(),
+                ),
+                // The first trigger attempt hits a busy controller.
+                "/job/app/build" if attempt == 2 => {
+                    (StatusCode::SERVICE_UNAVAILABLE, String::new())
+                }
+                "/job/app/build" => {
+                    headers.insert(
+                        http::header::LOCATION,
+                        http::HeaderValue::from_static("http://jenkins.local/queue/item/5/"),
+                    );
+                    (StatusCode::CREATED, String::new())
+                }
+                _ => (StatusCode::NOT_FOUND, String::new()),
+            };
+            Ok(jenkins_sdk::TransportResponse::new(status, headers, body))
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn blocking_custom_transport_keeps_middleware_stack() -> Result<()> {
+        let transport = std::sync::Arc::new(ScriptedTransport::default());
+        let recorded = transport.clone();
+        run_blocking(move || -> Result<()> {
+            let mut retry = RetryConfig::new(1, Duration::ZERO);
+            retry.retry_non_idempotent = true;
+            let client = BlockingClient::builder("http://jenkins.local")?
+                .auth_basic(TEST_USER, "token")
+                .request_hook(|ctx| {
+                    ctx.headers
+                        .insert("X-Hook", http::HeaderValue::from_static("on"));
+                    Ok(())
+                })
+                .retry_config(retry)
+                .with_crumb(Duration::from_secs(60))
+                .transport(transport)
+                .build()?;
+
+            let triggered = client.jobs().build("app")?;
+            assert_eq!(
+                triggered.queue_item_id.as_ref().map(|id| id.as_str()),
+                Some("5")
+            );
+
+            Ok(())
+        })
+        .await?;
+
+        assert_eq!(
+            *recorded.seen.lock().unwrap(),
+            [
+                "GET /crumbIssuer/api/json crumb=- hook=on",
+                "POST /job/app/build crumb=c-1 hook=on",
+                "POST /job/app/build crumb=c-1 hook=on",
+            ]
+        );
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn blocking_cassette_replays_without_network() -> Result<()> {
+        let cassette = std::env::temp_dir().join(format!(
+            "jenkins-sdk-blocking-cassette-{}.json",
+            std::process::id()
+        ));
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/jenkins/queue/api/json"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({"items": []})))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let base_url = format!("{}/jenkins", server.uri());
+        let client = |config: CassetteConfig| -> Result<BlockingClient> {
+            Ok(BlockingClient::builder(base_url.as_str())?
+                .no_system_proxy()
+                .cassette(config)
+                .build()?)
+        };
+        let recording = client(CassetteConfig::record(&cassette))?;
+        run_blocking(move || Ok(recording.queue().list(None).map(drop)?)).await?;
+        server.verify().await;
+        drop(server);
+
+        let missing = client(CassetteConfig::replay(cassette.with_extension("missing")));
+        assert!(missing.is_err());
+
+        let replaying = client(CassetteConfig::replay(&cassette))?;
+        let queue = run_blocking(move || Ok(replaying.queue().list(None)?)).await?;
+        assert!(queue.items.is_empty());
+        std::fs::remove_file(&cassette)?;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "blocking")]
+mod blocking_jobs {
+    use std::time::Duration;
+
+    use anyhow::Result;
+    use jenkins_sdk::{
+        BuildQuery, BuildResult, ConsoleFollowConfig, DownloadConfig, FileParameter, JobKind,
+        JobWalkConfig, WaitConfig,
+    };
+    use serde_json::json;
+    use wiremock::{
+        Mock, MockServer, ResponseTemplate,
+        matchers::{body_string_contains, header, method, path, query_param},
+    };
+
+    use crate::fixture::{build_blocking_client, run_blocking};
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn blocking_jobs_trigger_with_parameters_and_wait_returns_final_build() -> Result<()> {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/job/demo/buildWithParameters"))
+            .respond_with(ResponseTemplate::new(201).append_header("Location", "/queue/item/4/"))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/queue/item/4/api/json"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "id": 4,
+                "executable": { "number": 5 }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/job/demo/5/api/json"))
+            .and(query_param("tree", "number,building,result"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "number": 5,
+                "building": false
+            })))
+            .expect(1)
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/job/demo/5/api/json"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "number": 5,
+                "building": false,
+                "result": "FAILURE"
+            })))
+            .expect(1)
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+
+        let base_url = server.uri();
+        run_blocking(move || -> Result<()> {
+            let client = build_blocking_client(base_url)?;
+
+            let outcome = client.jobs().trigger_with_parameters_and_wait(
+                "demo",
+                [("foo", "bar")],
+                WaitConfig::new(Duration::ZERO, Duration::from_secs(5)),
+            )?;
+            let info = outcome.build_info().expect("build should complete");
+            assert_eq!(info.number, Some(5));
+            assert_eq!(info.result, Some(BuildResult::Failure));
+
+            Ok(())
+        })
+        .await?;
+
+        server.verify().await;
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn blocking_jobs_progressive_console_text_parses_headers() -> Result<()> {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/job/demo/1/logText/progressiveText"))
+            .and(query_param("start", "0"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .append_header("X-Text-Size", "5")
+                    .append_header("X-More-Data", "true")
+                    .set_body_string("hello"),
+            )
+            .expect(1)
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+
+        let base_url = server.uri();
+        run_blocking(move || -> Result<()> {
+            let client = build_blocking_client(base_url)?;
+
+            let chunk = client.jobs().progressive_console_text("demo", "1", 0)?;
+            assert_eq!(chunk.text, "hello");
+            assert_eq!(chunk.next_start, Some(5));
+            assert!(chunk.more_data);
+
+            Ok(())
+        })
+        .await?;
+
+        server.verify().await;
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn blocking_jobs_follow_console_yields_chunks_until_done() -> Result<()> {
+        let server = MockServer::start().await;
+
+        for (start, next, more, body) in [("0", "3", "true", "ab\n"), ("3", "6", "false", "cd\n")] {
+            Mock::given(method("GET"))
+                .and(path("/job/demo/1/logText/progressiveText"))
+                .and(query_param("start", start))
+                .respond_with(
+                    ResponseTemplate::new(200)
+                        .append_header("X-Text-Size", next)
+                        .append_header("X-More-Data", more)
+                        .set_body_string(body),
+                )
+                .expect(1)
+                .up_to_n_times(1)
+                .mount(&server)
+                .await;
+        }
+
+        let base_url = server.uri();
+        run_blocking(move || -> Result<()> {
+            let client = build_blocking_client(base_url)?;
+
+            let config = ConsoleFollowConfig::new(Duration::ZERO, Duration::ZERO);
+            let chunks = client
+                .jobs()
+                .follow_console("demo", "1", config)
+                .collect::<Result<Vec<_>, _>>()?;
+            assert_eq!(chunks, vec!["ab\n", "cd\n"]);
+
+            Ok(())
+        })
+        .await?;
+
+        server.verify().await;
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn blocking_jobs_download_artifact_splits_path_segments() -> Result<()> {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/job/demo/1/artifact/a/b%20c.txt"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(vec![1, 2, 3]))
+            .expect(1)
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+
+        let base_url = server.uri();
+        run_blocking(move || -> Result<()> {
+            let client = build_blocking_client(base_url)?;
+
+            let bytes = client.jobs().download_artifact("demo", "1", "a/b c.txt")?;
+            assert_eq!(bytes, vec![1, 2, 3]);
+
+            Ok(())
+        })
+        .await?;
+
+        server.verify().await;
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn blocking_jobs_download_artifact_to_path_skips_prefix_when_range_is_ignored()
+    -> Result<()> {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/job/demo/7/artifact/app.txt"))
+            .and(header("Range", "bytes=6-"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("hello world"))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let base_url = server.uri();
+        run_blocking(move || -> Result<()> {
+            let client = build_blocking_client(base_url)?;
+            let dest = std::env::temp_dir().join(format!(
+                "jenkins-sdk-blocking-resume-{}",
+                std::process::id()
+            ));
+            std::fs::write(&dest, "hello ")?;
+
+            let summary = client.jobs().download_artifact_to_path(
+                "demo",
+                "7",
+                "app.txt",
+                &dest,
+                DownloadConfig::new().resume_from(6),
+            )?;
+            let written = std::fs::read_to_string(&dest)?;
+            std::fs::remove_file(&dest)?;
+
+            assert_eq!(written, "hello world");
+            assert_eq!(summary.resumed_from, 0);
+            assert_eq!(summary.bytes_written, 5);
+            assert_eq!(
+                summary.md5.as_deref(),
+                Some("5eb63bbbe01eeed093cb22bb8f5acdc3")
+            );
+
             Ok(())
         })
         .await?;
@@ -693,34 +3071,242 @@ mod blocking_core {
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
-    async fn blocking_client_supports_base_path_with_crumb() -> Result<()> {
+    async fn blocking_jobs_last_completed_test_report_decodes_cases() -> Result<()> {
         let server = MockServer::start().await;
 
         Mock::given(method("GET"))
-            .and(path("/jenkins/crumbIssuer/api/json"))
+            .and(path("/job/demo/lastCompletedBuild/testReport/api/json"))
             .respond_with(ResponseTemplate::new(200).set_body_json(json!({
-                "crumbRequestField": "Jenkins-Crumb",
-                "crumb": "token"
+                "failCount": 1,
+                "skipCount": 0,
+                "suites": [{
+                    "cases": [{ "className": "pkg.T", "name": "b", "status": "FAILED" }]
+                }]
             })))
             .expect(1)
-            .up_to_n_times(1)
             .mount(&server)
             .await;
 
-        mock_post_with_auth(
-            &server,
-            "/jenkins/job/demo/1/stop",
-            Some("token"),
-            None,
-            ResponseTemplate::new(200).set_body_string("stopped"),
-            1,
-        )
-        .await;
+        let base_url = server.uri();
+        run_blocking(move || -> Result<()> {
+            let client = build_blocking_client(base_url)?;
 
-        let base_url = format!("{}/jenkins", server.uri());
+            let report = client.jobs().last_completed_test_report("demo", None)?;
+            assert_eq!(report.fail_count, 1);
+            let failures: Vec<_> = report.failures().map(|case| case.full_name()).collect();
+            assert_eq!(failures, ["pkg.T.b"]);
+
+            Ok(())
+        })
+        .await?;
+
+        server.verify().await;
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn blocking_jobs_download_artifacts_zip_streams_archive() -> Result<()> {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/job/demo/7/artifact/*zip*/archive.zip"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(b"PK\x03\x04".to_vec()))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let base_url = server.uri();
         run_blocking(move || -> Result<()> {
-            let client = build_blocking_authed_client(base_url, Duration::from_secs(300))?;
-            client.jobs().stop_build("demo", "1")?;
+            let client = build_blocking_client(base_url)?;
+
+            let mut zip = Vec::new();
+            let summary = client.jobs().download_artifacts_zip_to_writer(
+                "demo",
+                "7",
+                &mut zip,
+                DownloadConfig::new(),
+            )?;
+            assert_eq!(zip, b"PK\x03\x04");
+            assert_eq!(summary.bytes_written, 4);
+
+            Ok(())
+        })
+        .await?;
+
+        server.verify().await;
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn blocking_rebuild_resubmits_recorded_parameters() -> Result<()> {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/job/app/7/api/json"))
+            .and(query_param("tree", "actions[parameters[name,value]]"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "actions": [
+                    { "_class": "hudson.model.CauseAction" },
+                    {
+                        "_class": "hudson.model.ParametersAction",
+                        "parameters": [
+                            { "_class": "hudson.model.StringParameterValue", "name": "TARGET", "value": "prod" },
+                            { "_class": "hudson.model.BooleanParameterValue", "name": "DRY_RUN", "value": false },
+                            {
+                                "_class": "hudson.model.RunParameterValue",
+                                "name": "UPSTREAM",
+                                "value": { "jobName": "libs/core", "number": 42 }
+                            }
+                        ]
+                    }
+                ]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/job/app/8/api/json"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "actions": [{
+                    "_class": "hudson.model.ParametersAction",
+                    "parameters": [{ "_class": "hudson.model.PasswordParameterValue", "name": "TOKEN" }]
+                }]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/job/app/buildWithParameters"))
+            .and(body_string_contains("TARGET=prod"))
+            .and(body_string_contains("DRY_RUN=false"))
+            .and(body_string_contains("UPSTREAM=libs%2Fcore%2342"))
+            .respond_with(ResponseTemplate::new(201).append_header("Location", "/queue/item/9/"))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let base_url = server.uri();
+        run_blocking(move || -> Result<()> {
+            let client = build_blocking_client(base_url)?;
+
+            let triggered = client.jobs().rebuild("app", "7")?;
+            assert_eq!(
+                triggered.queue_item_id.as_ref().map(|id| id.as_str()),
+                Some("9")
+            );
+
+            let err = client
+                .jobs()
+                .rebuild("app", "8")
+                .expect_err("password parameter");
+            assert!(err.to_string().contains("`TOKEN`"));
+
+            Ok(())
+        })
+        .await?;
+
+        server.verify().await;
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn blocking_build_with_files_uploads_reader_content() -> Result<()> {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/job/release/buildWithParameters"))
+            .and(body_string_contains("filename=\"notes.txt\""))
+            .and(body_string_contains("release notes"))
+            .respond_with(ResponseTemplate::new(201).append_header("Location", "/queue/item/6/"))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let base_url = server.uri();
+        run_blocking(move || -> Result<()> {
+            let client = build_blocking_client(base_url)?;
+            let notes = FileParameter::reader("NOTES", "notes.txt", &b"release notes"[..])?;
+            client
+                .jobs()
+                .build_with_files("release", [("VERSION", "1.2.3")], [notes])?;
+            Ok(())
+        })
+        .await?;
+
+        server.verify().await;
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn blocking_builds_stop_at_time_window() -> Result<()> {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/job/app/api/json"))
+            .and(query_param("tree", "allBuilds[number,url,result,building,displayName,fullDisplayName,timestamp,duration,actions[parameters[name,value]]]{0,100}"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "allBuilds": [
+                    { "number": 12, "building": true, "timestamp": 1_700_000_300_000u64 },
+                    { "number": 11, "result": "SUCCESS", "timestamp": 1_700_000_200_000u64 },
+                    { "number": 10, "result": "SUCCESS", "timestamp": 1_700_000_100_000u64 },
+                    { "number": 9, "result": "SUCCESS", "timestamp": 1_699_999_000_000u64 }
+                ]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let base_url = server.uri();
+        run_blocking(move || -> Result<()> {
+            let client = build_blocking_client(base_url)?;
+            let epoch = std::time::UNIX_EPOCH;
+            let query = BuildQuery::new()
+                .since(epoch + Duration::from_millis(1_700_000_000_000))
+                .until(epoch + Duration::from_millis(1_700_000_250_000));
+
+            let numbers = client
+                .jobs()
+                .builds("app", query)
+                .map(|build| build.map(|b| b.number))
+                .collect::<Result<Vec<_>, _>>()?;
+            assert_eq!(numbers, [Some(11), Some(10)]);
+            Ok(())
+        })
+        .await?;
+
+        server.verify().await;
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn blocking_walk_jobs_respects_max_depth() -> Result<()> {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/job/team/api/json"))
+            .and(query_param("tree", "jobs[name,url,color]"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "jobs": [
+                    { "_class": "com.cloudbees.hudson.plugins.folder.Folder", "name": "nested" },
+                    { "_class": "hudson.model.FreeStyleProject", "name": "build" }
+                ]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let base_url = server.uri();
+        run_blocking(move || -> Result<()> {
+            let client = build_blocking_client(base_url)?;
+            let jobs = client
+                .jobs()
+                .walk_jobs(JobWalkConfig::new().root("team").max_depth(1))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            assert_eq!(jobs.len(), 2);
+            assert_eq!(jobs[0].0.as_str(), "team/nested");
+            assert_eq!(jobs[0].1.kind(), JobKind::Folder);
+            assert_eq!(jobs[1].0.as_str(), "team/build");
             Ok(())
         })
         .await?;
@@ -731,42 +3317,93 @@ mod blocking_core {
 }
 
 #[cfg(feature = "blocking")]
-mod blocking_jobs {
+mod blocking_multibranch {
     use anyhow::Result;
+    use jenkins_sdk::BranchState;
+    use serde_json::json;
     use wiremock::{
         Mock, MockServer, ResponseTemplate,
-        matchers::{method, path, query_param},
+        matchers::{method, path},
     };
 
     use crate::fixture::{build_blocking_client, run_blocking};
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
-    async fn blocking_jobs_progressive_console_text_parses_headers() -> Result<()> {
+    async fn blocking_multibranch_reads_indexing_log_and_branch_states() -> Result<()> {
         let server = MockServer::start().await;
 
         Mock::given(method("GET"))
-            .and(path("/job/demo/1/logText/progressiveText"))
-            .and(query_param("start", "0"))
+            .and(path("/job/app/indexing/consoleText"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                "Checking branches...\n  Checking branch main\nFinished: SUCCESS\n",
+            ))
+            .expect(1)
+            .mount(&server)
+            .await;
+        // Shape of branch-api's export: a branch removed from the repository stays as a
+        // disabled, unbuildable job until the orphaned item strategy deletes it.
+        let branch = |name: &str, color: &str, buildable: bool| {
+            json!({
+                "_class": "org.jenkinsci.plugins.workflow.job.WorkflowJob",
+                "name": name,
+                "displayName": name,
+                "url": format!("http://jenkins/job/app/job/{name}/"),
+                "color": color,
+                "buildable": buildable,
+                "lastBuild": null
+            })
+        };
+        let project = |disabled: bool, jobs: serde_json::Value| {
+            json!({
+                "_class": "org.jenkinsci.plugins.workflow.multibranch.WorkflowMultiBranchProject",
+                "disabled": disabled,
+                "jobs": jobs,
+                "views": [{
+                    "_class": "jenkins.branch.MultiBranchProjectViewHolder$ViewImpl",
+                    "name": "default",
+                    "jobs": []
+                }]
+            })
+        };
+        Mock::given(method("GET"))
+            .and(path("/job/app/api/json"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(project(
+                false,
+                json!([
+                    branch("main", "blue", true),
+                    branch("gone", "disabled", false)
+                ]),
+            )))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/job/archived/api/json"))
             .respond_with(
                 ResponseTemplate::new(200)
-                    .append_header("X-Text-Size", "5")
-                    .append_header("X-More-Data", "true")
-                    .set_body_string("hello"),
+                    .set_body_json(project(true, json!([branch("main", "disabled", false)]))),
             )
             .expect(1)
-            .up_to_n_times(1)
             .mount(&server)
             .await;
 
         let base_url = server.uri();
         run_blocking(move || -> Result<()> {
             let client = build_blocking_client(base_url)?;
-
-            let chunk = client.jobs().progressive_console_text("demo", "1", 0)?;
-            assert_eq!(chunk.text, "hello");
-            assert_eq!(chunk.next_start, Some(5));
-            assert!(chunk.more_data);
-
+            let multibranch = client.multibranch();
+
+            let log = multibranch.indexing_log("app")?;
+            assert!(log.ends_with("Finished: SUCCESS\n"));
+
+            let states = |project: &str| -> Result<Vec<BranchState>> {
+                Ok(multibranch
+                    .branches(project)?
+                    .iter()
+                    .map(|branch| branch.state())
+                    .collect())
+            };
+            assert_eq!(states("app")?, [BranchState::Active, BranchState::Orphaned]);
+            assert_eq!(states("archived")?, [BranchState::Disabled]);
             Ok(())
         })
         .await?;
@@ -774,16 +3411,40 @@ mod blocking_jobs {
         server.verify().await;
         Ok(())
     }
+}
+
+#[cfg(feature = "blocking")]
+mod blocking_pipeline {
+    use std::time::Duration;
+
+    use anyhow::Result;
+    use jenkins_sdk::StageStatus;
+    use serde_json::json;
+    use wiremock::{
+        Mock, MockServer, ResponseTemplate,
+        matchers::{method, path},
+    };
+
+    use crate::fixture::{
+        build_blocking_authed_client, build_blocking_client, mock_crumb, mock_post_with_auth,
+        run_blocking,
+    };
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
-    async fn blocking_jobs_download_artifact_splits_path_segments() -> Result<()> {
+    async fn blocking_pipeline_runs_decodes_stage_view() -> Result<()> {
         let server = MockServer::start().await;
 
         Mock::given(method("GET"))
-            .and(path("/job/demo/1/artifact/a/b%20c.txt"))
-            .respond_with(ResponseTemplate::new(200).set_body_bytes(vec![1, 2, 3]))
+            .and(path("/job/app/wfapi/runs"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+                {
+                    "id": "13",
+                    "status": "PAUSED_PENDING_INPUT",
+                    "stages": [{ "id": "6", "name": "Approve", "status": "PAUSED_PENDING_INPUT" }]
+                },
+                { "id": "12", "status": "SUCCESS", "stages": [] }
+            ])))
             .expect(1)
-            .up_to_n_times(1)
             .mount(&server)
             .await;
 
@@ -791,9 +3452,44 @@ mod blocking_jobs {
         run_blocking(move || -> Result<()> {
             let client = build_blocking_client(base_url)?;
 
-            let bytes = client.jobs().download_artifact("demo", "1", "a/b c.txt")?;
-            assert_eq!(bytes, vec![1, 2, 3]);
+            let runs = client.pipeline().runs("app")?;
+            assert_eq!(runs.len(), 2);
+            assert!(runs[0].status.as_ref().is_some_and(StageStatus::is_running));
+            assert_eq!(runs[0].stages[0].name.as_deref(), Some("Approve"));
+            assert_eq!(runs[1].status, Some(StageStatus::Success));
+
+            Ok(())
+        })
+        .await?;
+
+        server.verify().await;
+        Ok(())
+    }
 
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn blocking_pipeline_proceed_without_parameters_uses_proceed_empty() -> Result<()> {
+        let server = MockServer::start().await;
+
+        mock_crumb(&server, "crumb-2", 1).await;
+        mock_post_with_auth(
+            &server,
+            "/job/app/13/input/Approve/proceedEmpty",
+            Some("crumb-2"),
+            None,
+            ResponseTemplate::new(200),
+            1,
+        )
+        .await;
+
+        let base_url = server.uri();
+        run_blocking(move || -> Result<()> {
+            let client = build_blocking_authed_client(base_url, Duration::from_secs(300))?;
+            client.pipeline().proceed_input(
+                "app",
+                "13",
+                "Approve",
+                Vec::<(String, String)>::new(),
+            )?;
             Ok(())
         })
         .await?;
@@ -802,3 +3498,47 @@ mod blocking_jobs {
         Ok(())
     }
 }
+
+#[cfg(feature = "blocking")]
+mod blocking_plugins {
+    use anyhow::Result;
+    use serde_json::json;
+    use wiremock::{
+        Mock, MockServer, ResponseTemplate,
+        matchers::{method, path},
+    };
+
+    use crate::fixture::{build_blocking_client, run_blocking};
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn blocking_plugins_disable_and_restart_flag() -> Result<()> {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/pluginManager/plugin/git/makeDisabled"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/updateCenter/api/json"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!({"jobs": [], "restartRequiredForCompletion": true})),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let base_url = server.uri();
+        let restart_required = run_blocking(move || {
+            let client = build_blocking_client(base_url)?;
+            client.plugins().disable("git")?;
+            Ok(client.plugins().restart_required()?)
+        })
+        .await?;
+        assert!(restart_required);
+
+        server.verify().await;
+        Ok(())
+    }
+}